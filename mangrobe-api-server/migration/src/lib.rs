@@ -1,12 +1,36 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20251103_033827_initialize;
+mod m20261019_010000_add_file_lock_owner;
+mod m20261019_020000_create_table_grants;
+mod m20261019_030000_create_audit_logs;
+mod m20261019_040000_add_user_table_dropped_at;
+mod m20261019_050000_create_streams;
+mod m20261019_060000_add_stream_last_commit;
+mod m20261019_070000_create_table_aliases;
+mod m20261019_080000_add_user_table_properties;
+mod m20261019_090000_add_file_partition_values;
+mod m20261019_100000_add_audit_log_file_lock_key;
+mod m20261019_110000_create_garbage_files;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20251103_033827_initialize::Migration)]
+        vec![
+            Box::new(m20251103_033827_initialize::Migration),
+            Box::new(m20261019_010000_add_file_lock_owner::Migration),
+            Box::new(m20261019_020000_create_table_grants::Migration),
+            Box::new(m20261019_030000_create_audit_logs::Migration),
+            Box::new(m20261019_040000_add_user_table_dropped_at::Migration),
+            Box::new(m20261019_050000_create_streams::Migration),
+            Box::new(m20261019_060000_add_stream_last_commit::Migration),
+            Box::new(m20261019_070000_create_table_aliases::Migration),
+            Box::new(m20261019_080000_add_user_table_properties::Migration),
+            Box::new(m20261019_090000_add_file_partition_values::Migration),
+            Box::new(m20261019_100000_add_audit_log_file_lock_key::Migration),
+            Box::new(m20261019_110000_create_garbage_files::Migration),
+        ]
     }
 }
@@ -0,0 +1,39 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FileLock::Table)
+                    .add_column(text_null(FileLock::OwnerId))
+                    .add_column(text_null(FileLock::Reason))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FileLock::Table)
+                    .drop_column(FileLock::OwnerId)
+                    .drop_column(FileLock::Reason)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum FileLock {
+    #[sea_orm(iden = "file_locks")]
+    Table,
+    OwnerId,
+    Reason,
+}
@@ -0,0 +1,92 @@
+use crate::sea_orm::Statement;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TableGrant::Table)
+                    .if_not_exists()
+                    .col(
+                        big_integer(TableGrant::Id)
+                            .auto_increment()
+                            .primary_key()
+                            .take(),
+                    )
+                    .col(text(TableGrant::PrincipalId))
+                    // NULL grants the permission on every table.
+                    .col(text_null(TableGrant::TableName))
+                    .col(integer(TableGrant::Permission))
+                    .col(big_integer_null(TableGrant::StreamIdMin))
+                    .col(big_integer_null(TableGrant::StreamIdMax))
+                    .col(
+                        timestamp_with_time_zone(TableGrant::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp_with_time_zone(TableGrant::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                manager.get_database_backend(),
+                format!(
+                    r#"
+                CREATE TRIGGER trigger_update_updated_at
+                BEFORE UPDATE ON {}
+                FOR EACH ROW
+                EXECUTE FUNCTION update_timestamp();
+                "#,
+                    TableGrant::Table.to_string()
+                )
+                .to_owned(),
+            ))
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(format!(
+                        "idx_{}_{}_{}",
+                        TableGrant::Table.to_string(),
+                        TableGrant::PrincipalId.to_string(),
+                        TableGrant::TableName.to_string()
+                    ))
+                    .table(TableGrant::Table)
+                    .col(TableGrant::PrincipalId)
+                    .col(TableGrant::TableName)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TableGrant::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TableGrant {
+    #[sea_orm(iden = "table_grants")]
+    Table,
+    Id,
+    PrincipalId,
+    TableName,
+    Permission,
+    StreamIdMin,
+    StreamIdMax,
+    CreatedAt,
+    UpdatedAt,
+}
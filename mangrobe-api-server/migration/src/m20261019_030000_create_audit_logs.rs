@@ -0,0 +1,151 @@
+use crate::sea_orm::Statement;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditLog::Table)
+                    .if_not_exists()
+                    .col(
+                        big_integer(AuditLog::Id)
+                            .auto_increment()
+                            .primary_key()
+                            .take(),
+                    )
+                    .col(integer(AuditLog::Operation))
+                    .col(text(AuditLog::PrincipalId))
+                    .col(integer(AuditLog::PrincipalKind))
+                    .col(text_null(AuditLog::ClientAddress))
+                    .col(text_null(AuditLog::RequestId))
+                    .col(text_null(AuditLog::TableName))
+                    .col(big_integer_null(AuditLog::StreamId))
+                    .col(integer(AuditLog::Outcome))
+                    .col(text_null(AuditLog::ErrorMessage))
+                    .col(
+                        timestamp_with_time_zone(AuditLog::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Audit logs are append-only.
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                manager.get_database_backend(),
+                r#"
+                CREATE OR REPLACE FUNCTION reject_audit_log_change()
+                RETURNS TRIGGER AS $$
+                BEGIN
+                  RAISE EXCEPTION 'audit logs are append-only';
+                END;
+                $$ language 'plpgsql';
+                "#
+                .to_owned(),
+            ))
+            .await?;
+
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                manager.get_database_backend(),
+                format!(
+                    r#"
+                CREATE TRIGGER trigger_reject_audit_log_change
+                BEFORE UPDATE OR DELETE ON {}
+                FOR EACH ROW
+                EXECUTE FUNCTION reject_audit_log_change();
+                "#,
+                    AuditLog::Table.to_string()
+                )
+                .to_owned(),
+            ))
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(format!(
+                        "idx_{}_{}_{}",
+                        AuditLog::Table.to_string(),
+                        AuditLog::TableName.to_string(),
+                        AuditLog::Id.to_string()
+                    ))
+                    .table(AuditLog::Table)
+                    .col(AuditLog::TableName)
+                    .col(AuditLog::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(format!(
+                        "idx_{}_{}_{}",
+                        AuditLog::Table.to_string(),
+                        AuditLog::PrincipalId.to_string(),
+                        AuditLog::Id.to_string()
+                    ))
+                    .table(AuditLog::Table)
+                    .col(AuditLog::PrincipalId)
+                    .col(AuditLog::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(format!(
+                        "idx_{}_{}",
+                        AuditLog::Table.to_string(),
+                        AuditLog::CreatedAt.to_string()
+                    ))
+                    .table(AuditLog::Table)
+                    .col(AuditLog::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditLog::Table).to_owned())
+            .await?;
+
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                manager.get_database_backend(),
+                "DROP FUNCTION IF EXISTS reject_audit_log_change();".to_owned(),
+            ))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuditLog {
+    #[sea_orm(iden = "audit_logs")]
+    Table,
+    Id,
+    Operation,
+    PrincipalId,
+    PrincipalKind,
+    ClientAddress,
+    RequestId,
+    TableName,
+    StreamId,
+    Outcome,
+    ErrorMessage,
+    CreatedAt,
+}
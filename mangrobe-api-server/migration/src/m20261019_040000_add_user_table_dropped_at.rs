@@ -0,0 +1,49 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserTable::Table)
+                    .add_column(timestamp_with_time_zone_null(UserTable::DroppedAt))
+                    .add_column(timestamp_with_time_zone_null(UserTable::PurgeAfter))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_tables_purge_after")
+                    .table(UserTable::Table)
+                    .col(UserTable::PurgeAfter)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserTable::Table)
+                    .drop_column(UserTable::DroppedAt)
+                    .drop_column(UserTable::PurgeAfter)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserTable {
+    #[sea_orm(iden = "user_tables")]
+    Table,
+    DroppedAt,
+    PurgeAfter,
+}
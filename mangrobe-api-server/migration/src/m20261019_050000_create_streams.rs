@@ -0,0 +1,132 @@
+use crate::sea_orm::Statement;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Streams::Table)
+                    .if_not_exists()
+                    .col(
+                        big_integer(Streams::Id)
+                            .auto_increment()
+                            .primary_key()
+                            .take(),
+                    )
+                    .col(big_integer(Streams::UserTableId))
+                    .col(big_integer(Streams::StreamId))
+                    .col(text_null(Streams::TenantName))
+                    .col(json_binary(Streams::Labels).default(Expr::cust("'{}'::jsonb")))
+                    .col(integer(Streams::State).default(0))
+                    .col(timestamp_with_time_zone_null(Streams::SealedAt))
+                    .col(
+                        timestamp_with_time_zone(Streams::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp_with_time_zone(Streams::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                manager.get_database_backend(),
+                format!(
+                    r#"
+                CREATE TRIGGER trigger_update_updated_at
+                BEFORE UPDATE ON {}
+                FOR EACH ROW
+                EXECUTE FUNCTION update_timestamp();
+                "#,
+                    Streams::Table.to_string()
+                )
+                .to_owned(),
+            ))
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(format!(
+                        "fk_{}_{}",
+                        Streams::Table.to_string(),
+                        UserTable::Table.to_string()
+                    ))
+                    .from(Streams::Table, Streams::UserTableId)
+                    .to(UserTable::Table, UserTable::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(format!(
+                        "idx_{}_{}_{}",
+                        Streams::Table.to_string(),
+                        Streams::UserTableId.to_string(),
+                        Streams::StreamId.to_string(),
+                    ))
+                    .table(Streams::Table)
+                    .col(Streams::UserTableId)
+                    .col(Streams::StreamId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Streams were implicitly created by their first commit.
+        manager
+            .get_connection()
+            .execute(Statement::from_string(
+                manager.get_database_backend(),
+                r#"
+                INSERT INTO streams (user_table_id, stream_id, created_at)
+                SELECT user_table_id, stream_id, MIN(committed_at)
+                FROM commits
+                GROUP BY user_table_id, stream_id;
+                "#
+                .to_owned(),
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Streams::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserTable {
+    #[sea_orm(iden = "user_tables")]
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Streams {
+    #[sea_orm(iden = "streams")]
+    Table,
+    Id,
+    UserTableId,
+    StreamId,
+    TenantName,
+    Labels,
+    State,
+    SealedAt,
+    CreatedAt,
+    UpdatedAt,
+}
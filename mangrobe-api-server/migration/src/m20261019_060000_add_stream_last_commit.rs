@@ -0,0 +1,93 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Streams::Table)
+                    .add_column(big_integer_null(Streams::LastCommitId))
+                    .add_column(timestamp_with_time_zone_null(Streams::LastCommittedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // updated_at is set to the last commit instead of the time of this migration.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                ALTER TABLE streams DISABLE TRIGGER trigger_update_updated_at;
+                UPDATE streams
+                SET last_commit_id = latest.id,
+                    last_committed_at = latest.committed_at,
+                    updated_at = GREATEST(streams.updated_at, latest.committed_at)
+                FROM (
+                    SELECT DISTINCT ON (user_table_id, stream_id) user_table_id, stream_id, id, committed_at
+                    FROM commits
+                    ORDER BY user_table_id, stream_id, id DESC
+                ) AS latest
+                WHERE streams.user_table_id = latest.user_table_id
+                  AND streams.stream_id = latest.stream_id;
+                ALTER TABLE streams ENABLE TRIGGER trigger_update_updated_at;
+                "#,
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(format!(
+                        "idx_{}_{}_{}",
+                        Streams::Table.to_string(),
+                        Streams::UserTableId.to_string(),
+                        Streams::UpdatedAt.to_string(),
+                    ))
+                    .table(Streams::Table)
+                    .col(Streams::UserTableId)
+                    .col(Streams::UpdatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(format!(
+                        "idx_{}_{}_{}",
+                        Streams::Table.to_string(),
+                        Streams::UserTableId.to_string(),
+                        Streams::UpdatedAt.to_string(),
+                    ))
+                    .table(Streams::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Streams::Table)
+                    .drop_column(Streams::LastCommitId)
+                    .drop_column(Streams::LastCommittedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Streams {
+    #[sea_orm(iden = "streams")]
+    Table,
+    UserTableId,
+    LastCommitId,
+    LastCommittedAt,
+    UpdatedAt,
+}
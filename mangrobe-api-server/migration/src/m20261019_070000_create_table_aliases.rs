@@ -0,0 +1,83 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TableAliases::Table)
+                    .if_not_exists()
+                    .col(
+                        big_integer(TableAliases::Id)
+                            .auto_increment()
+                            .primary_key()
+                            .take(),
+                    )
+                    .col(text(TableAliases::Name).unique_key())
+                    .col(big_integer(TableAliases::UserTableId))
+                    .col(
+                        timestamp_with_time_zone(TableAliases::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(format!(
+                        "fk_{}_{}",
+                        TableAliases::Table.to_string(),
+                        UserTable::Table.to_string()
+                    ))
+                    .from(TableAliases::Table, TableAliases::UserTableId)
+                    .to(UserTable::Table, UserTable::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(format!(
+                        "idx_{}_{}",
+                        TableAliases::Table.to_string(),
+                        TableAliases::UserTableId.to_string(),
+                    ))
+                    .table(TableAliases::Table)
+                    .col(TableAliases::UserTableId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TableAliases::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserTable {
+    #[sea_orm(iden = "user_tables")]
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum TableAliases {
+    #[sea_orm(iden = "table_aliases")]
+    Table,
+    Id,
+    Name,
+    UserTableId,
+    CreatedAt,
+}
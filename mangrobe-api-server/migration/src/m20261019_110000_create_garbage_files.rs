@@ -0,0 +1,48 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Object paths of deleted files. A garbage collector deletes the objects and then the rows.
+        manager
+            .create_table(
+                Table::create()
+                    .table(GarbageFiles::Table)
+                    .if_not_exists()
+                    .col(
+                        big_integer(GarbageFiles::Id)
+                            .auto_increment()
+                            .primary_key()
+                            .take(),
+                    )
+                    .col(big_integer(GarbageFiles::UserTableId))
+                    .col(big_integer(GarbageFiles::StreamId))
+                    .col(text(GarbageFiles::Path))
+                    .col(
+                        timestamp_with_time_zone(GarbageFiles::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GarbageFiles::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum GarbageFiles {
+    Table,
+    Id,
+    UserTableId,
+    StreamId,
+    Path,
+    CreatedAt,
+}
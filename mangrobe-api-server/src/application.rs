@@ -1,5 +1,7 @@
+pub mod access_control;
 pub mod data_definition;
 pub mod data_manipulation;
 pub mod information_schema;
 pub mod lock_control;
+pub mod monitoring;
 mod util;
@@ -0,0 +1,4 @@
+pub mod access_control_use_case;
+pub mod grant_table_permission_param;
+pub mod list_table_permissions_param;
+pub mod revoke_table_permission_param;
@@ -0,0 +1,87 @@
+use crate::application::access_control::grant_table_permission_param::GrantTablePermissionParam;
+use crate::application::access_control::list_table_permissions_param::ListTablePermissionsParam;
+use crate::application::access_control::revoke_table_permission_param::RevokeTablePermissionParam;
+use crate::domain::model::permission::Permission;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::table_grant::TableGrant;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::domain::service::authorization_service::AuthorizationService;
+use sea_orm::DatabaseConnection;
+use tracing::instrument;
+
+pub struct AccessControlUseCase {
+    authorization_service: AuthorizationService,
+}
+
+impl AccessControlUseCase {
+    pub fn new(connection: DatabaseConnection) -> Self {
+        Self {
+            authorization_service: AuthorizationService::new(&connection),
+        }
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn grant(
+        &self,
+        param: GrantTablePermissionParam,
+    ) -> Result<TableGrant, anyhow::Error> {
+        self.authorize_admin(&param.principal, param.table_name.as_ref())
+            .await?;
+
+        self.authorization_service
+            .grant(
+                &param.principal_id,
+                param.table_name.as_ref(),
+                param.permission,
+                &param.stream_id_range,
+            )
+            .await
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn revoke(&self, param: RevokeTablePermissionParam) -> Result<bool, anyhow::Error> {
+        let Some(grant) = self
+            .authorization_service
+            .find_grant(&param.grant_id)
+            .await?
+        else {
+            return Ok(false);
+        };
+        self.authorize_admin(&param.principal, grant.table_name.as_ref())
+            .await?;
+
+        self.authorization_service.revoke(&grant.id).await
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn list(
+        &self,
+        param: ListTablePermissionsParam,
+    ) -> Result<Vec<TableGrant>, anyhow::Error> {
+        self.authorize_admin(&param.principal, param.table_name.as_ref())
+            .await?;
+
+        self.authorization_service
+            .list_grants(param.principal_id.as_deref(), param.table_name.as_ref())
+            .await
+    }
+
+    async fn authorize_admin(
+        &self,
+        principal: &Principal,
+        table_name: Option<&UserTableName>,
+    ) -> Result<(), anyhow::Error> {
+        match table_name {
+            Some(table_name) => {
+                self.authorization_service
+                    .authorize(principal, table_name, None, Permission::Admin)
+                    .await
+            }
+            None => {
+                self.authorization_service
+                    .authorize_global(principal, Permission::Admin)
+                    .await
+            }
+        }
+    }
+}
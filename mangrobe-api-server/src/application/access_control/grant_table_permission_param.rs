@@ -0,0 +1,12 @@
+use crate::domain::model::permission::Permission;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::stream_id_range::StreamIdRange;
+use crate::domain::model::user_table_name::UserTableName;
+
+pub struct GrantTablePermissionParam {
+    pub principal_id: String,
+    pub table_name: Option<UserTableName>,
+    pub permission: Permission,
+    pub stream_id_range: StreamIdRange,
+    pub principal: Principal,
+}
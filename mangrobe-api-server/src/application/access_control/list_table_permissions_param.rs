@@ -0,0 +1,8 @@
+use crate::domain::model::principal::Principal;
+use crate::domain::model::user_table_name::UserTableName;
+
+pub struct ListTablePermissionsParam {
+    pub table_name: Option<UserTableName>,
+    pub principal_id: Option<String>,
+    pub principal: Principal,
+}
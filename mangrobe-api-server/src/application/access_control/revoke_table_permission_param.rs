@@ -0,0 +1,7 @@
+use crate::domain::model::principal::Principal;
+use crate::domain::model::table_grant_id::TableGrantId;
+
+pub struct RevokeTablePermissionParam {
+    pub grant_id: TableGrantId,
+    pub principal: Principal,
+}
@@ -1,4 +1,27 @@
+mod alter_table_properties_param;
+mod create_table_alias_param;
 mod create_table_param;
 pub mod data_definition_use_case;
+mod delete_stream_param;
+mod describe_table_param;
+mod drop_table_alias_param;
+mod drop_table_param;
+mod list_tables_param;
+mod register_stream_param;
+mod rename_table_param;
+mod seal_stream_param;
+pub mod table_purger;
+mod undrop_table_param;
 
+pub use alter_table_properties_param::AlterTablePropertiesParam;
+pub use create_table_alias_param::CreateTableAliasParam;
 pub use create_table_param::CreateTableParam;
+pub use delete_stream_param::DeleteStreamParam;
+pub use describe_table_param::DescribeTableParam;
+pub use drop_table_alias_param::DropTableAliasParam;
+pub use drop_table_param::DropTableParam;
+pub use list_tables_param::ListTablesParam;
+pub use register_stream_param::RegisterStreamParam;
+pub use rename_table_param::RenameTableParam;
+pub use seal_stream_param::SealStreamParam;
+pub use undrop_table_param::UndropTableParam;
@@ -0,0 +1,12 @@
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::user_table_name::UserTableName;
+use std::collections::BTreeMap;
+
+pub struct AlterTablePropertiesParam {
+    pub table_name: UserTableName,
+    pub set: BTreeMap<String, String>,
+    pub remove: Vec<String>,
+    pub principal: Principal,
+    pub client: ClientInfo,
+}
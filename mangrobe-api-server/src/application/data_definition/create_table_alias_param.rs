@@ -0,0 +1,10 @@
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::user_table_name::UserTableName;
+
+pub struct CreateTableAliasParam {
+    pub table_name: UserTableName,
+    pub alias: UserTableName,
+    pub principal: Principal,
+    pub client: ClientInfo,
+}
@@ -1,6 +1,12 @@
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::table_properties::TableProperties;
 use crate::domain::model::user_table_name::UserTableName;
 
 pub struct CreateTableParam {
     pub table_name: UserTableName,
+    pub properties: TableProperties,
     pub skip_if_exists: bool,
+    pub principal: Principal,
+    pub client: ClientInfo,
 }
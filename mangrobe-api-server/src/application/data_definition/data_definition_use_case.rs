@@ -1,40 +1,482 @@
-use crate::application::data_definition::CreateTableParam;
+use crate::application::data_definition::{
+    AlterTablePropertiesParam, CreateTableAliasParam, CreateTableParam, DeleteStreamParam,
+    DescribeTableParam, DropTableAliasParam, DropTableParam, ListTablesParam, RegisterStreamParam,
+    RenameTableParam, SealStreamParam, UndropTableParam,
+};
+use crate::application::util::user_table::find_table;
+use crate::domain::model::audit_log::{AuditEvent, AuditOperation};
+use crate::domain::model::permission::Permission;
+use crate::domain::model::stream::Stream;
 use crate::domain::model::user_table::UserTable;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::domain::model::user_table_stream::UserTablStream;
+use crate::domain::service::audit_service::AuditService;
+use crate::domain::service::authorization_service::AuthorizationService;
+use crate::domain::service::stream_service::StreamService;
 use crate::domain::service::user_table_service::UserTableService;
+use crate::infrastructure::db::repository::table_alias_repository::TableAliasRepositoryError;
 use crate::infrastructure::db::repository::user_table_repository::UserTableRepositoryError;
 use crate::util::error::UserError;
 use anyhow::bail;
+use chrono::Duration;
 use sea_orm::DatabaseConnection;
+use tracing::instrument;
 
 pub struct DataDefinitionUseCase {
     user_table_service: UserTableService,
+    authorization_service: AuthorizationService,
+    audit_service: AuditService,
+    stream_service: StreamService,
+    drop_grace_period: Duration,
 }
 
 impl DataDefinitionUseCase {
-    pub fn new(connection: DatabaseConnection) -> Self {
+    pub fn new(connection: DatabaseConnection, drop_grace_period: Duration) -> Self {
         Self {
             user_table_service: UserTableService::new(&connection),
+            authorization_service: AuthorizationService::new(&connection),
+            audit_service: AuditService::new(&connection),
+            stream_service: StreamService::new(&connection),
+            drop_grace_period,
         }
     }
 
+    #[instrument(skip_all, fields(principal = %param.principal))]
     pub async fn create_table(&self, param: CreateTableParam) -> Result<UserTable, anyhow::Error> {
-        let res = self
+        let event = AuditEvent::new(AuditOperation::CreateTable, &param.principal, &param.client)
+            .with_table(&param.table_name);
+        let result = self.create_table_inner(param).await;
+        self.audit_service.record(&event, &result).await;
+        result
+    }
+
+    async fn create_table_inner(
+        &self,
+        param: CreateTableParam,
+    ) -> Result<UserTable, anyhow::Error> {
+        self.authorization_service
+            .authorize(&param.principal, &param.table_name, None, Permission::Admin)
+            .await?;
+
+        let table = self
             .user_table_service
-            .create(&param.table_name, param.skip_if_exists)
-            .await;
-
-        match res {
-            Ok(table) => Ok(table),
-            Err(err) => {
-                if let Some(e) = err.downcast_ref::<UserTableRepositoryError>() {
-                    match e {
-                        UserTableRepositoryError::AlreadyExists => {
-                            bail!(UserError::AlreadyExistsMessage(param.table_name.val()));
-                        }
-                    }
-                }
-                bail!(err)
-            }
+            .create(&param.table_name, &param.properties, param.skip_if_exists)
+            .await
+            .map_err(|err| map_name_conflict(err, &param.table_name))?;
+
+        if table.is_dropped() {
+            bail!(UserError::FailedPrecondition(format!(
+                "table '{}' is dropped. Undrop it or wait until it is purged",
+                param.table_name.val()
+            )));
         }
+        Ok(table)
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn list_tables(
+        &self,
+        param: &ListTablesParam,
+        limit: u64,
+    ) -> Result<Vec<UserTable>, anyhow::Error> {
+        let names = self
+            .authorization_service
+            .find_visible_table_names(&param.principal)
+            .await?;
+
+        self.user_table_service
+            .find_all(
+                names.as_deref(),
+                param.table_name_after.as_ref(),
+                param.include_dropped,
+                limit,
+            )
+            .await
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn drop_table(&self, param: DropTableParam) -> Result<UserTable, anyhow::Error> {
+        let mut event = AuditEvent::new(AuditOperation::DropTable, &param.principal, &param.client)
+            .with_table(&param.table_name);
+        let result = self.drop_table_inner(&param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        result
+    }
+
+    async fn drop_table_inner(
+        &self,
+        param: &DropTableParam,
+        event: &mut AuditEvent,
+    ) -> Result<UserTable, anyhow::Error> {
+        self.authorization_service
+            .authorize(&param.principal, &param.table_name, None, Permission::Admin)
+            .await?;
+
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        let table = self
+            .user_table_service
+            .drop_table(&table.id, self.drop_grace_period)
+            .await?;
+
+        let Some(table) = table else {
+            bail!(UserError::InvalidParameterMessage(format!(
+                "table_name '{}' not found",
+                param.table_name.val()
+            )));
+        };
+        Ok(table)
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn undrop_table(&self, param: UndropTableParam) -> Result<UserTable, anyhow::Error> {
+        let event = AuditEvent::new(AuditOperation::UndropTable, &param.principal, &param.client)
+            .with_table(&param.table_name);
+        let result = self.undrop_table_inner(&param).await;
+        self.audit_service.record(&event, &result).await;
+        result
+    }
+
+    async fn undrop_table_inner(
+        &self,
+        param: &UndropTableParam,
+    ) -> Result<UserTable, anyhow::Error> {
+        self.authorization_service
+            .authorize(&param.principal, &param.table_name, None, Permission::Admin)
+            .await?;
+
+        let table = self
+            .user_table_service
+            .find_by_name(&param.table_name)
+            .await?;
+        let Some(table) = table else {
+            bail!(UserError::InvalidParameterMessage(format!(
+                "table_name '{}' not found",
+                param.table_name.val()
+            )));
+        };
+        if !table.is_dropped() {
+            bail!(UserError::FailedPrecondition(format!(
+                "table '{}' is not dropped",
+                param.table_name.val()
+            )));
+        }
+
+        let restored = self.user_table_service.undrop_table(&table.id).await?;
+        let Some(restored) = restored else {
+            bail!(UserError::FailedPrecondition(format!(
+                "grace period of table '{}' has passed",
+                param.table_name.val()
+            )));
+        };
+        Ok(restored)
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn rename_table(&self, param: RenameTableParam) -> Result<UserTable, anyhow::Error> {
+        let mut event =
+            AuditEvent::new(AuditOperation::RenameTable, &param.principal, &param.client)
+                .with_table(&param.table_name);
+        let result = self.rename_table_inner(&param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        result
+    }
+
+    async fn rename_table_inner(
+        &self,
+        param: &RenameTableParam,
+        event: &mut AuditEvent,
+    ) -> Result<UserTable, anyhow::Error> {
+        self.authorization_service
+            .authorize(&param.principal, &param.table_name, None, Permission::Admin)
+            .await?;
+        self.authorization_service
+            .authorize(
+                &param.principal,
+                &param.new_table_name,
+                None,
+                Permission::Admin,
+            )
+            .await?;
+
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        if table.name == param.new_table_name {
+            bail!(UserError::FailedPrecondition(format!(
+                "table is already named '{}'",
+                param.new_table_name.val()
+            )));
+        }
+
+        self.user_table_service
+            .rename(&table, &param.new_table_name, param.keep_alias)
+            .await
+            .map_err(|err| map_name_conflict(err, &param.new_table_name))
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn create_table_alias(
+        &self,
+        param: CreateTableAliasParam,
+    ) -> Result<UserTable, anyhow::Error> {
+        let mut event = AuditEvent::new(
+            AuditOperation::CreateTableAlias,
+            &param.principal,
+            &param.client,
+        )
+        .with_table(&param.table_name);
+        let result = self.create_table_alias_inner(&param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        result
+    }
+
+    async fn create_table_alias_inner(
+        &self,
+        param: &CreateTableAliasParam,
+        event: &mut AuditEvent,
+    ) -> Result<UserTable, anyhow::Error> {
+        self.authorization_service
+            .authorize(&param.principal, &param.table_name, None, Permission::Admin)
+            .await?;
+        self.authorization_service
+            .authorize(&param.principal, &param.alias, None, Permission::Admin)
+            .await?;
+
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        self.user_table_service
+            .create_alias(table, &param.alias)
+            .await
+            .map_err(|err| map_name_conflict(err, &param.alias))
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn drop_table_alias(
+        &self,
+        param: DropTableAliasParam,
+    ) -> Result<UserTable, anyhow::Error> {
+        let event = AuditEvent::new(
+            AuditOperation::DropTableAlias,
+            &param.principal,
+            &param.client,
+        )
+        .with_table(&param.alias);
+        let result = self.drop_table_alias_inner(&param).await;
+        self.audit_service.record(&event, &result).await;
+        result
+    }
+
+    async fn drop_table_alias_inner(
+        &self,
+        param: &DropTableAliasParam,
+    ) -> Result<UserTable, anyhow::Error> {
+        self.authorization_service
+            .authorize(&param.principal, &param.alias, None, Permission::Admin)
+            .await?;
+
+        let table = self.user_table_service.drop_alias(&param.alias).await?;
+        let Some(table) = table else {
+            bail!(UserError::InvalidParameterMessage(format!(
+                "alias '{}' not found",
+                param.alias.val()
+            )));
+        };
+        Ok(table)
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn describe_table(
+        &self,
+        param: &DescribeTableParam,
+    ) -> Result<UserTable, anyhow::Error> {
+        self.authorization_service
+            .authorize(&param.principal, &param.table_name, None, Permission::Read)
+            .await?;
+
+        find_table(&self.user_table_service, &param.table_name).await
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn alter_table_properties(
+        &self,
+        param: AlterTablePropertiesParam,
+    ) -> Result<UserTable, anyhow::Error> {
+        let mut event = AuditEvent::new(
+            AuditOperation::AlterTableProperties,
+            &param.principal,
+            &param.client,
+        )
+        .with_table(&param.table_name);
+        let result = self.alter_table_properties_inner(&param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        result
+    }
+
+    async fn alter_table_properties_inner(
+        &self,
+        param: &AlterTablePropertiesParam,
+        event: &mut AuditEvent,
+    ) -> Result<UserTable, anyhow::Error> {
+        self.authorization_service
+            .authorize(&param.principal, &param.table_name, None, Permission::Admin)
+            .await?;
+
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        self.user_table_service
+            .alter_properties(&table, &param.set, &param.remove)
+            .await
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn register_stream(
+        &self,
+        param: RegisterStreamParam,
+    ) -> Result<Stream, anyhow::Error> {
+        let mut event = AuditEvent::new(
+            AuditOperation::RegisterStream,
+            &param.principal,
+            &param.client,
+        )
+        .with_stream(&param.table_name, &param.stream_id);
+        let result = self.register_stream_inner(&param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        result
+    }
+
+    async fn register_stream_inner(
+        &self,
+        param: &RegisterStreamParam,
+        event: &mut AuditEvent,
+    ) -> Result<Stream, anyhow::Error> {
+        self.authorization_service
+            .authorize(
+                &param.principal,
+                &param.table_name,
+                Some(&param.stream_id),
+                Permission::Write,
+            )
+            .await?;
+
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        let stream = UserTablStream::new(table.id, param.stream_id.clone());
+        let registered = self
+            .stream_service
+            .register(&stream, &param.metadata, param.skip_if_exists)
+            .await?;
+
+        let Some(registered) = registered else {
+            bail!(UserError::AlreadyExistsMessage(format!(
+                "stream_id={}",
+                param.stream_id.val()
+            )));
+        };
+        Ok(registered)
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn seal_stream(&self, param: SealStreamParam) -> Result<Stream, anyhow::Error> {
+        let mut event =
+            AuditEvent::new(AuditOperation::SealStream, &param.principal, &param.client)
+                .with_stream(&param.table_name, &param.stream_id);
+        let result = self.seal_stream_inner(&param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        result
+    }
+
+    async fn seal_stream_inner(
+        &self,
+        param: &SealStreamParam,
+        event: &mut AuditEvent,
+    ) -> Result<Stream, anyhow::Error> {
+        self.authorization_service
+            .authorize(
+                &param.principal,
+                &param.table_name,
+                Some(&param.stream_id),
+                Permission::Admin,
+            )
+            .await?;
+
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        let stream = UserTablStream::new(table.id, param.stream_id.clone());
+        let Some(current) = self.stream_service.find(&stream).await? else {
+            bail!(UserError::InvalidParameterMessage(format!(
+                "stream_id '{}' not found",
+                param.stream_id.val()
+            )));
+        };
+        if current.is_sealed() {
+            bail!(UserError::FailedPrecondition(format!(
+                "stream_id '{}' is already sealed",
+                param.stream_id.val()
+            )));
+        }
+
+        let sealed = self.stream_service.seal(&stream).await?;
+        let Some(sealed) = sealed else {
+            bail!(UserError::FailedPrecondition(format!(
+                "stream_id '{}' was sealed or deleted concurrently",
+                param.stream_id.val()
+            )));
+        };
+        Ok(sealed)
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn delete_stream(&self, param: DeleteStreamParam) -> Result<(), anyhow::Error> {
+        let mut event = AuditEvent::new(
+            AuditOperation::DeleteStream,
+            &param.principal,
+            &param.client,
+        )
+        .with_stream(&param.table_name, &param.stream_id);
+        let result = self.delete_stream_inner(&param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        result
+    }
+
+    async fn delete_stream_inner(
+        &self,
+        param: &DeleteStreamParam,
+        event: &mut AuditEvent,
+    ) -> Result<(), anyhow::Error> {
+        self.authorization_service
+            .authorize(
+                &param.principal,
+                &param.table_name,
+                Some(&param.stream_id),
+                Permission::Admin,
+            )
+            .await?;
+
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        let stream = UserTablStream::new(table.id, param.stream_id.clone());
+        let deleted = self.stream_service.delete(&stream).await?;
+        if !deleted {
+            bail!(UserError::InvalidParameterMessage(format!(
+                "stream_id '{}' not found",
+                param.stream_id.val()
+            )));
+        }
+        Ok(())
+    }
+}
+
+// Table names and aliases share one namespace.
+fn map_name_conflict(err: anyhow::Error, name: &UserTableName) -> anyhow::Error {
+    let conflicts = matches!(
+        err.downcast_ref::<UserTableRepositoryError>(),
+        Some(UserTableRepositoryError::AlreadyExists)
+    ) || matches!(
+        err.downcast_ref::<TableAliasRepositoryError>(),
+        Some(TableAliasRepositoryError::AlreadyExists)
+    );
+    if conflicts {
+        return UserError::AlreadyExistsMessage(name.val()).into();
     }
+    err
 }
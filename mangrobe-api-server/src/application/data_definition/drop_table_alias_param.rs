@@ -0,0 +1,9 @@
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::user_table_name::UserTableName;
+
+pub struct DropTableAliasParam {
+    pub alias: UserTableName,
+    pub principal: Principal,
+    pub client: ClientInfo,
+}
@@ -0,0 +1,8 @@
+use crate::domain::model::principal::Principal;
+use crate::domain::model::user_table_name::UserTableName;
+
+pub struct ListTablesParam {
+    pub table_name_after: Option<UserTableName>,
+    pub include_dropped: bool,
+    pub principal: Principal,
+}
@@ -0,0 +1,14 @@
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::stream::StreamMetadata;
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_name::UserTableName;
+
+pub struct RegisterStreamParam {
+    pub table_name: UserTableName,
+    pub stream_id: StreamId,
+    pub metadata: StreamMetadata,
+    pub skip_if_exists: bool,
+    pub principal: Principal,
+    pub client: ClientInfo,
+}
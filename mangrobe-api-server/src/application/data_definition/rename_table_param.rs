@@ -0,0 +1,11 @@
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::user_table_name::UserTableName;
+
+pub struct RenameTableParam {
+    pub table_name: UserTableName,
+    pub new_table_name: UserTableName,
+    pub keep_alias: bool,
+    pub principal: Principal,
+    pub client: ClientInfo,
+}
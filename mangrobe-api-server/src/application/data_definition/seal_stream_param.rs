@@ -0,0 +1,11 @@
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_name::UserTableName;
+
+pub struct SealStreamParam {
+    pub table_name: UserTableName,
+    pub stream_id: StreamId,
+    pub principal: Principal,
+    pub client: ClientInfo,
+}
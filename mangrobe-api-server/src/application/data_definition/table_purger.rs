@@ -0,0 +1,53 @@
+use crate::domain::model::audit_log::{AuditEvent, AuditOperation};
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::principal::Principal;
+use crate::domain::service::audit_service::AuditService;
+use crate::domain::service::user_table_service::UserTableService;
+use sea_orm::DatabaseConnection;
+use std::time::Duration;
+use tracing::{error, info};
+
+// Periodically deletes the dropped tables whose grace period has passed.
+pub struct TablePurger {
+    user_table_service: UserTableService,
+    audit_service: AuditService,
+}
+
+impl TablePurger {
+    pub fn new(connection: &DatabaseConnection) -> Self {
+        Self {
+            user_table_service: UserTableService::new(connection),
+            audit_service: AuditService::new(connection),
+        }
+    }
+
+    pub async fn run(&self, interval: Duration) {
+        loop {
+            if let Err(e) = self.purge_dropped().await {
+                error!(error = ?e, "failed to purge dropped tables");
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    async fn purge_dropped(&self) -> Result<(), anyhow::Error> {
+        let tables = self.user_table_service.find_purgeable().await?;
+
+        for table in tables {
+            let event = AuditEvent::new(
+                AuditOperation::PurgeTable,
+                &Principal::system(),
+                &ClientInfo::default(),
+            )
+            .with_table(&table.name);
+            let result = self.user_table_service.purge(&table).await;
+            self.audit_service.record(&event, &result).await;
+
+            let garbage_count = result?;
+            info!(table_name = %table.name.val(), garbage_count, "Purged dropped table");
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
-use crate::domain::model::change_request_raw_file_entry::ChangeRequestRawCompactFilesEntry;
+use crate::domain::model::change_request_raw_file_entry::ChangeRequestRawCompactFileInfoEntry;
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::commit_id::CommitId;
 use crate::domain::model::file_lock_key::FileLockKey;
+use crate::domain::model::principal::Principal;
 use crate::domain::model::stream_id::StreamId;
 use crate::domain::model::user_table_name::UserTableName;
 
@@ -7,5 +10,8 @@ pub struct CompactFilesParam {
     pub file_lock_key: FileLockKey,
     pub table_name: UserTableName,
     pub stream_id: StreamId,
-    pub entries: Vec<ChangeRequestRawCompactFilesEntry>,
+    pub entries: Vec<ChangeRequestRawCompactFileInfoEntry>,
+    pub expected_commit_id: Option<CommitId>,
+    pub principal: Principal,
+    pub client: ClientInfo,
 }
@@ -4,22 +4,39 @@ use crate::application::data_manipulation::compact_files_param::CompactFilesPara
 use crate::application::data_manipulation::get_changes_param::GetChangesParam;
 use crate::application::data_manipulation::get_current_state_param::GetCurrentStateParam;
 use crate::application::data_manipulation::get_file_info_param::GetFileInfoParam;
-use crate::application::util::user_table::find_table_id;
+use crate::application::util::partition_time::align_partition_time;
+use crate::application::util::partition_values::{
+    transform_partition_filter, transform_partition_values,
+};
+use crate::application::util::user_table::{find_table, find_table_id};
+use crate::domain::model::audit_log::{AuditEvent, AuditOperation};
 use crate::domain::model::change_request::ChangeRequestType;
+use crate::domain::model::change_request_raw_file_entry::ChangeRequestRawAddFileEntry;
 use crate::domain::model::commit_id::CommitId;
 use crate::domain::model::committed_change_request::CommittedStreamChange;
 use crate::domain::model::file_with_statistics::FileWithStatistics;
+use crate::domain::model::permission::Permission;
+use crate::domain::model::quota::{CommitUsage, QuotaPolicy};
 use crate::domain::model::snapshot::Snapshot;
+use crate::domain::model::table_properties::TableProperties;
 use crate::domain::model::user_table_stream::UserTablStream;
+use crate::domain::service::audit_service::AuditService;
+use crate::domain::service::authorization_service::AuthorizationService;
 use crate::domain::service::change_request_service::ChangeRequestService;
 use crate::domain::service::committed_change_request_service::CommittedChangeRequestService;
 use crate::domain::service::file_lock_key_service::FileLockService;
 use crate::domain::service::file_service::FileService;
+use crate::domain::service::quota_service::QuotaService;
 use crate::domain::service::snapshot_service::SnapshotService;
+use crate::domain::service::stream_service::StreamService;
 use crate::domain::service::user_table_service::UserTableService;
-use crate::util::error::UserError;
+use crate::util::error::{CommitError, LockError, MangrobeError, UserError};
+use crate::util::metrics::COMMITS_TOTAL;
 use anyhow::bail;
+use chrono::Utc;
 use sea_orm::DatabaseConnection;
+use std::collections::HashSet;
+use tracing::instrument;
 
 pub struct DataManipulationUseCase {
     snapshot_service: SnapshotService,
@@ -28,10 +45,14 @@ pub struct DataManipulationUseCase {
     file_lock_service: FileLockService,
     file_service: FileService,
     user_table_service: UserTableService,
+    authorization_service: AuthorizationService,
+    quota_service: QuotaService,
+    audit_service: AuditService,
+    stream_service: StreamService,
 }
 
 impl DataManipulationUseCase {
-    pub fn new(connection: DatabaseConnection) -> Self {
+    pub fn new(connection: DatabaseConnection, quota_policy: QuotaPolicy) -> Self {
         Self {
             snapshot_service: SnapshotService::new(&connection),
             change_request_service: ChangeRequestService::new(&connection),
@@ -39,24 +60,50 @@ impl DataManipulationUseCase {
             file_lock_service: FileLockService::new(&connection),
             file_service: FileService::new(&connection),
             user_table_service: UserTableService::new(&connection),
+            authorization_service: AuthorizationService::new(&connection),
+            quota_service: QuotaService::new(&connection, quota_policy),
+            audit_service: AuditService::new(&connection),
+            stream_service: StreamService::new(&connection),
         }
     }
 
+    #[instrument(skip_all, fields(principal = %param.principal))]
     pub async fn get_current_state(
         &self,
         param: GetCurrentStateParam,
     ) -> Result<Snapshot, anyhow::Error> {
-        let table_id = find_table_id(&self.user_table_service, &param.table_name).await?;
+        self.authorization_service
+            .authorize(
+                &param.principal,
+                &param.table_name,
+                Some(&param.stream_id),
+                Permission::Read,
+            )
+            .await?;
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        let partition_filter =
+            transform_partition_filter(&table.properties, &param.partition_filter)?;
 
-        let stream = UserTablStream::new(table_id, param.stream_id);
-        self.snapshot_service.get_current(&stream).await
+        let stream = UserTablStream::new(table.id, param.stream_id);
+        self.snapshot_service
+            .get_current(&stream, &partition_filter)
+            .await
     }
 
+    #[instrument(skip_all, fields(principal = %param.principal))]
     pub async fn get_changes(
         &self,
         param: &GetChangesParam,
         limit_per_stream: u64,
     ) -> Result<CommittedStreamChange, anyhow::Error> {
+        self.authorization_service
+            .authorize(
+                &param.principal,
+                &param.table_name,
+                Some(&param.stream_id),
+                Permission::Read,
+            )
+            .await?;
         let table_id = find_table_id(&self.user_table_service, &param.table_name).await?;
 
         let changes = self
@@ -74,25 +121,114 @@ impl DataManipulationUseCase {
         }
     }
 
+    #[instrument(skip_all, fields(principal = %param.principal))]
     pub async fn get_file_with_stat(
         &self,
         param: GetFileInfoParam,
     ) -> Result<Vec<FileWithStatistics>, anyhow::Error> {
-        self.file_service
+        let files = self
+            .file_service
             .get_files_with_stat(
                 &param.file_ids,
                 param.metadata_types.includes_parquet_metadata,
             )
-            .await
+            .await?;
+
+        // Files are specified by id, so the tables are known only after fetching them.
+        if param.principal.requires_authorization() {
+            let streams: HashSet<_> = files.iter().map(|f| f.file.stream.clone()).collect();
+            let table_ids: Vec<_> = streams.iter().map(|s| s.user_table_id.clone()).collect();
+            let tables = self.user_table_service.find_all_by_ids(&table_ids).await?;
+            for stream in &streams {
+                let Some(table) = tables.iter().find(|t| t.id == stream.user_table_id) else {
+                    bail!(MangrobeError::UnexpectedState(format!(
+                        "table not found. id={}",
+                        stream.user_table_id.val()
+                    )));
+                };
+                self.authorization_service
+                    .authorize(
+                        &param.principal,
+                        &table.name,
+                        Some(&stream.stream_id),
+                        Permission::Read,
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(files)
     }
 
+    #[instrument(skip_all, fields(principal = %param.principal))]
     pub async fn add_files(&self, param: AddFilesParam) -> Result<CommitId, anyhow::Error> {
-        let table_id = find_table_id(&self.user_table_service, &param.table_name).await?;
-        let stream = UserTablStream::new(table_id, param.stream_id);
-        let change_request = self
+        let mut event = AuditEvent::new(AuditOperation::AddFiles, &param.principal, &param.client)
+            .with_stream(&param.table_name, &param.stream_id);
+        let result = self.add_files_inner(param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        count_commit(&event, &result);
+        result
+    }
+
+    // The table name of the event is replaced with the canonical name once the table is found,
+    // so that quotas, metrics and audit logs are not split by aliases.
+    async fn add_files_inner(
+        &self,
+        mut param: AddFilesParam,
+        event: &mut AuditEvent,
+    ) -> Result<CommitId, anyhow::Error> {
+        self.authorization_service
+            .authorize(
+                &param.principal,
+                &param.table_name,
+                Some(&param.stream_id),
+                Permission::Write,
+            )
+            .await?;
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        for entry in param.entries.iter_mut() {
+            entry.partition_time = align_partition_time(&table.properties, entry.partition_time)?;
+            for file in entry.files_to_add.iter_mut() {
+                file.partition_values =
+                    transform_partition_values(&table.properties, &file.partition_values)?;
+            }
+        }
+        check_add_entries(&table.properties, &param.entries)?;
+        let stream = UserTablStream::new(table.id, param.stream_id);
+        // Retries of an applied request return its result without being charged again. New keys
+        // are checked before the change request is created, so that rejected requests leave
+        // nothing behind.
+        let existing = self
             .change_request_service
-            .find_or_create(&param.idempotency_key, &stream, ChangeRequestType::AddFiles)
+            .find(&param.idempotency_key)
             .await?;
+        if existing.as_ref().is_none_or(|c| c.is_new()) {
+            // Checked again at commit while holding the commit lock.
+            if let Some(current) = self.stream_service.find(&stream).await?
+                && current.is_sealed()
+            {
+                bail!(CommitError::StreamSealed(format!(
+                    "stream_id={}",
+                    current.id.val()
+                )));
+            }
+            self.quota_service
+                .check_commit(
+                    &table.name,
+                    &stream,
+                    &CommitUsage::from_add_entries(&param.entries),
+                )
+                .await?;
+        }
+        let change_request = match existing {
+            Some(change_request) => change_request,
+            None => {
+                self.change_request_service
+                    .find_or_create(&param.idempotency_key, &stream, ChangeRequestType::AddFiles)
+                    .await?
+            }
+        };
 
         let mut change_request_with_entry = self
             .change_request_service
@@ -100,21 +236,59 @@ impl DataManipulationUseCase {
             .await?;
 
         self.change_request_service
-            .commit_add_only_change_request(&mut change_request_with_entry)
+            .commit_add_only_change_request(
+                &mut change_request_with_entry,
+                param.expected_commit_id.as_ref(),
+            )
             .await
     }
 
+    #[instrument(skip_all, fields(principal = %param.principal))]
     pub async fn change_files(&self, param: ChangeFilesParam) -> Result<CommitId, anyhow::Error> {
+        let mut event =
+            AuditEvent::new(AuditOperation::ChangeFiles, &param.principal, &param.client)
+                .with_stream(&param.table_name, &param.stream_id)
+                .with_file_lock_key(&param.file_lock_key);
+        let result = self.change_files_inner(param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        count_commit(&event, &result);
+        result
+    }
+
+    async fn change_files_inner(
+        &self,
+        param: ChangeFilesParam,
+        event: &mut AuditEvent,
+    ) -> Result<CommitId, anyhow::Error> {
+        self.authorization_service
+            .authorize(
+                &param.principal,
+                &param.table_name,
+                Some(&param.stream_id),
+                Permission::Write,
+            )
+            .await?;
         let lock_exists = self
             .file_lock_service
             .check_existence(&param.file_lock_key)
             .await?;
         if !lock_exists {
-            bail!(UserError::InvalidLockMessage("not acquired".into()))
+            bail!(LockError::NotAcquired(
+                "the key is unknown or has expired".into()
+            ))
         }
 
-        let table_id = find_table_id(&self.user_table_service, &param.table_name).await?;
-        let stream = UserTablStream::new(table_id, param.stream_id);
+        // Files to delete are looked up by the partition_time they were added with, so it is not aligned.
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        let stream = UserTablStream::new(table.id, param.stream_id);
+        self.quota_service
+            .check_commit(
+                &table.name,
+                &stream,
+                &CommitUsage::from_change_entries(&param.entries),
+            )
+            .await?;
         let change_request = self
             .change_request_service
             .create(&stream, ChangeRequestType::Compact)
@@ -131,21 +305,69 @@ impl DataManipulationUseCase {
                 &param.file_lock_key,
                 &mut change_request_with_entry.base,
                 &changeset,
+                param.expected_commit_id.as_ref(),
             )
             .await
     }
 
+    #[instrument(skip_all, fields(principal = %param.principal))]
     pub async fn compact_files(&self, param: CompactFilesParam) -> Result<CommitId, anyhow::Error> {
+        let mut event = AuditEvent::new(
+            AuditOperation::CompactFiles,
+            &param.principal,
+            &param.client,
+        )
+        .with_stream(&param.table_name, &param.stream_id)
+        .with_file_lock_key(&param.file_lock_key);
+        let result = self.compact_files_inner(param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        count_commit(&event, &result);
+        result
+    }
+
+    async fn compact_files_inner(
+        &self,
+        mut param: CompactFilesParam,
+        event: &mut AuditEvent,
+    ) -> Result<CommitId, anyhow::Error> {
+        self.authorization_service
+            .authorize(
+                &param.principal,
+                &param.table_name,
+                Some(&param.stream_id),
+                Permission::Compact,
+            )
+            .await?;
         let lock_exists = self
             .file_lock_service
             .check_existence(&param.file_lock_key)
             .await?;
         if !lock_exists {
-            bail!(UserError::InvalidLockMessage("not acquired".into()))
+            bail!(LockError::NotAcquired(
+                "the key is unknown or has expired".into()
+            ))
         }
 
-        let table_id = find_table_id(&self.user_table_service, &param.table_name).await?;
-        let stream = UserTablStream::new(table_id, param.stream_id);
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        // Source files are looked up by the partition_time they were added with, so only the
+        // destination files are aligned.
+        for entry in param.entries.iter_mut() {
+            for dst_file in entry.dst_files.iter_mut() {
+                dst_file.partition_time =
+                    align_partition_time(&table.properties, dst_file.partition_time)?;
+                dst_file.file.partition_values =
+                    transform_partition_values(&table.properties, &dst_file.file.partition_values)?;
+            }
+        }
+        let stream = UserTablStream::new(table.id, param.stream_id);
+        self.quota_service
+            .check_commit(
+                &table.name,
+                &stream,
+                &CommitUsage::from_compact_entries(&param.entries),
+            )
+            .await?;
         let change_request = self
             .change_request_service
             .create(&stream, ChangeRequestType::Compact)
@@ -162,7 +384,51 @@ impl DataManipulationUseCase {
                 &param.file_lock_key,
                 &mut change_request_with_entry.base,
                 &changeset,
+                param.expected_commit_id.as_ref(),
             )
             .await
     }
 }
+
+fn count_commit(event: &AuditEvent, result: &Result<CommitId, anyhow::Error>) {
+    if let (Ok(_), Some(table_name)) = (result, &event.table_name) {
+        COMMITS_TOTAL
+            .with_label_values(&[table_name.val(), event.operation.to_string()])
+            .inc();
+    }
+}
+
+fn check_add_entries(
+    properties: &TableProperties,
+    entries: &[ChangeRequestRawAddFileEntry],
+) -> Result<(), anyhow::Error> {
+    if let Some(limit) = properties.max_files_per_add() {
+        let files = entries
+            .iter()
+            .map(|e| e.files_to_add.len() as u64)
+            .sum::<u64>();
+        if files > limit {
+            bail!(UserError::InvalidParameterMessage(format!(
+                "too many files for the table: files={}, add_files.max_files={}",
+                files, limit
+            )));
+        }
+    }
+
+    if let Some(retention) = properties.retention() {
+        let Some(oldest) = Utc::now().checked_sub_signed(retention) else {
+            bail!(UserError::InvalidParameterMessage(format!(
+                "retention.sec of the table is out of range: {}s",
+                retention.num_seconds()
+            )));
+        };
+        if let Some(entry) = entries.iter().find(|e| e.partition_time < oldest) {
+            bail!(UserError::InvalidParameterMessage(format!(
+                "partition_time {} is older than the retention of the table",
+                entry.partition_time.to_rfc3339()
+            )));
+        }
+    }
+
+    Ok(())
+}
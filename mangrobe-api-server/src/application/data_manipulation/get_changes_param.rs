@@ -1,4 +1,5 @@
 use crate::domain::model::commit_id::CommitId;
+use crate::domain::model::principal::Principal;
 use crate::domain::model::stream_id::StreamId;
 use crate::domain::model::user_table_name::UserTableName;
 
@@ -6,4 +7,5 @@ pub struct GetChangesParam {
     pub table_name: UserTableName,
     pub stream_id: StreamId,
     pub commit_id_after: CommitId,
+    pub principal: Principal,
 }
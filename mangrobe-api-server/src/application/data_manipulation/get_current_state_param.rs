@@ -1,7 +1,11 @@
+use crate::domain::model::principal::Principal;
 use crate::domain::model::stream_id::StreamId;
 use crate::domain::model::user_table_name::UserTableName;
+use std::collections::BTreeMap;
 
 pub struct GetCurrentStateParam {
     pub table_name: UserTableName,
     pub stream_id: StreamId,
+    pub partition_filter: BTreeMap<String, String>,
+    pub principal: Principal,
 }
@@ -1,4 +1,5 @@
 use crate::domain::model::file_id::FileId;
+use crate::domain::model::principal::Principal;
 
 #[derive(Debug, Clone)]
 pub struct FileStatisticsSelection {
@@ -33,4 +34,5 @@ pub struct GetFileInfoParam {
     pub file_ids: Vec<FileId>,
     pub stat_types: FileStatisticsSelection,
     pub metadata_types: FileMetadataSelection,
+    pub principal: Principal,
 }
@@ -1,2 +1,5 @@
+pub mod get_stream_stats_param;
+pub mod get_table_stats_param;
 pub mod information_schema_use_case;
+pub mod list_audit_logs_param;
 pub mod list_streams_param;
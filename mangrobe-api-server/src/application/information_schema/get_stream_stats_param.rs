@@ -0,0 +1,9 @@
+use crate::domain::model::principal::Principal;
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_name::UserTableName;
+
+pub struct GetStreamStatsParam {
+    pub table_name: UserTableName,
+    pub stream_id: StreamId,
+    pub principal: Principal,
+}
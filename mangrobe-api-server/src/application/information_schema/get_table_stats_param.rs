@@ -0,0 +1,7 @@
+use crate::domain::model::principal::Principal;
+use crate::domain::model::user_table_name::UserTableName;
+
+pub struct GetTableStatsParam {
+    pub table_name: UserTableName,
+    pub principal: Principal,
+}
@@ -1,13 +1,29 @@
+use crate::application::information_schema::get_stream_stats_param::GetStreamStatsParam;
+use crate::application::information_schema::get_table_stats_param::GetTableStatsParam;
+use crate::application::information_schema::list_audit_logs_param::ListAuditLogsParam;
 use crate::application::information_schema::list_streams_param::ListStreamsParam;
 use crate::application::util::user_table::find_table_id;
+use crate::domain::model::audit_log::AuditLog;
+use crate::domain::model::file_stats::FileStats;
+use crate::domain::model::permission::Permission;
 use crate::domain::model::stream::Stream;
+use crate::domain::model::user_table_stream::UserTablStream;
+use crate::domain::service::audit_service::AuditService;
+use crate::domain::service::authorization_service::AuthorizationService;
+use crate::domain::service::file_service::FileService;
 use crate::domain::service::stream_service::StreamService;
 use crate::domain::service::user_table_service::UserTableService;
+use crate::util::error::UserError;
+use anyhow::bail;
 use sea_orm::DatabaseConnection;
+use tracing::instrument;
 
 pub struct InformationSchemaUseCase {
     stream_service: StreamService,
     user_table_service: UserTableService,
+    authorization_service: AuthorizationService,
+    audit_service: AuditService,
+    file_service: FileService,
 }
 
 impl InformationSchemaUseCase {
@@ -15,17 +31,96 @@ impl InformationSchemaUseCase {
         Self {
             stream_service: StreamService::new(&connection),
             user_table_service: UserTableService::new(&connection),
+            authorization_service: AuthorizationService::new(&connection),
+            audit_service: AuditService::new(&connection),
+            file_service: FileService::new(&connection),
         }
     }
 
+    #[instrument(skip_all, fields(principal = %param.principal))]
     pub async fn list_streams(
         &self,
         param: &ListStreamsParam,
         limit: u64,
     ) -> Result<Vec<Stream>, anyhow::Error> {
+        self.authorization_service
+            .authorize(&param.principal, &param.table_name, None, Permission::Read)
+            .await?;
+
         let table_id = find_table_id(&self.user_table_service, &param.table_name).await?;
         self.stream_service
-            .find_streams_after(&table_id, &param.stream_id_after, limit)
+            .find_all(
+                &table_id,
+                &param.filter,
+                param.stream_id_after.as_ref(),
+                param.descending,
+                limit,
+            )
+            .await
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn list_audit_logs(
+        &self,
+        param: &ListAuditLogsParam,
+        limit: u64,
+    ) -> Result<Vec<AuditLog>, anyhow::Error> {
+        match &param.filter.table_name {
+            Some(table_name) => {
+                self.authorization_service
+                    .authorize(&param.principal, table_name, None, Permission::Admin)
+                    .await?
+            }
+            None => {
+                self.authorization_service
+                    .authorize_global(&param.principal, Permission::Admin)
+                    .await?
+            }
+        }
+
+        self.audit_service
+            .find_all(&param.filter, param.audit_log_id_before.as_ref(), limit)
+            .await
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn get_table_stats(
+        &self,
+        param: &GetTableStatsParam,
+    ) -> Result<FileStats, anyhow::Error> {
+        self.authorization_service
+            .authorize(&param.principal, &param.table_name, None, Permission::Read)
+            .await?;
+
+        let table_id = find_table_id(&self.user_table_service, &param.table_name).await?;
+        self.file_service.get_file_stats(&table_id, None).await
+    }
+
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn get_stream_stats(
+        &self,
+        param: &GetStreamStatsParam,
+    ) -> Result<FileStats, anyhow::Error> {
+        self.authorization_service
+            .authorize(
+                &param.principal,
+                &param.table_name,
+                Some(&param.stream_id),
+                Permission::Read,
+            )
+            .await?;
+
+        let table_id = find_table_id(&self.user_table_service, &param.table_name).await?;
+        let stream = UserTablStream::new(table_id.clone(), param.stream_id.clone());
+        if self.stream_service.find(&stream).await?.is_none() {
+            bail!(UserError::NotFoundMessage(format!(
+                "stream_id '{}' not found",
+                param.stream_id.val()
+            )));
+        }
+
+        self.file_service
+            .get_file_stats(&table_id, Some(&param.stream_id))
             .await
     }
 }
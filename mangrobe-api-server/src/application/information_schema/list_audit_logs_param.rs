@@ -0,0 +1,9 @@
+use crate::domain::model::audit_log::AuditLogFilter;
+use crate::domain::model::audit_log_id::AuditLogId;
+use crate::domain::model::principal::Principal;
+
+pub struct ListAuditLogsParam {
+    pub filter: AuditLogFilter,
+    pub audit_log_id_before: Option<AuditLogId>,
+    pub principal: Principal,
+}
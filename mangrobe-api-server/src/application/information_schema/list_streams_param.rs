@@ -1,7 +1,13 @@
+use crate::domain::model::principal::Principal;
+use crate::domain::model::stream::StreamFilter;
 use crate::domain::model::stream_id::StreamId;
 use crate::domain::model::user_table_name::UserTableName;
 
 pub struct ListStreamsParam {
     pub table_name: UserTableName,
+    pub filter: StreamFilter,
+    pub descending: bool,
+    // The last stream_id of the previous page, in the requested order.
     pub stream_id_after: Option<StreamId>,
+    pub principal: Principal,
 }
@@ -1,2 +1,3 @@
 pub mod acquire_file_lock_param;
 pub mod lock_control_use_case;
+pub mod plan_compaction_param;
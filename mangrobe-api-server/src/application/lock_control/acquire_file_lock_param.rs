@@ -1,13 +1,23 @@
+use crate::domain::model::client_info::ClientInfo;
 use crate::domain::model::file_lock_key::FileLockKey;
+use crate::domain::model::file_lock_owner::FileLockOwner;
 use crate::domain::model::lock_raw_file_entry::LockFileRawAcquireEntry;
+use crate::domain::model::principal::Principal;
 use crate::domain::model::stream_id::StreamId;
 use crate::domain::model::user_table_name::UserTableName;
 use chrono::Duration;
+use std::collections::BTreeMap;
 
 pub struct AcquireFileLockParam {
     pub file_lock_key: FileLockKey,
     pub table_name: UserTableName,
     pub stream_id: StreamId,
-    pub ttl: Duration,
+    // None falls back to the lock.default_ttl_sec property of the table.
+    pub ttl: Option<Duration>,
+    pub owner: FileLockOwner,
     pub entries: Vec<LockFileRawAcquireEntry>,
+    // Locks the current files matching these column values instead of entries.
+    pub partition_filter: BTreeMap<String, String>,
+    pub principal: Principal,
+    pub client: ClientInfo,
 }
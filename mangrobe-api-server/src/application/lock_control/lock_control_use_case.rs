@@ -1,41 +1,249 @@
 use crate::application::lock_control::acquire_file_lock_param::AcquireFileLockParam;
-use crate::application::util::user_table::find_table_id;
+use crate::application::lock_control::plan_compaction_param::PlanCompactionParam;
+use crate::application::util::partition_values::transform_partition_filter;
+use crate::application::util::user_table::find_table;
+use crate::domain::model::audit_log::{AuditEvent, AuditOperation};
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::compaction_plan::CompactionGroup;
 use crate::domain::model::file::FileWithId;
 use crate::domain::model::file_lock_key::FileLockKey;
+use crate::domain::model::file_lock_owner::FileLockOwner;
+use crate::domain::model::permission::Permission;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::table_properties::TableProperties;
 use crate::domain::model::user_table_stream::UserTablStream;
+use crate::domain::service::audit_service::AuditService;
+use crate::domain::service::authorization_service::AuthorizationService;
+use crate::domain::service::compaction_plan_service::CompactionPlanService;
 use crate::domain::service::file_lock_key_service::FileLockService;
 use crate::domain::service::user_table_service::UserTableService;
+use crate::util::error::{LockError, MangrobeError};
+use crate::util::metrics::FILE_LOCK_ACQUISITIONS_TOTAL;
+use anyhow::bail;
+use chrono::Duration;
 use sea_orm::DatabaseConnection;
+use tracing::instrument;
 
 pub struct LockControlUseCase {
     file_lock_service: FileLockService,
+    compaction_plan_service: CompactionPlanService,
     user_table_service: UserTableService,
+    authorization_service: AuthorizationService,
+    audit_service: AuditService,
 }
 
 impl LockControlUseCase {
     pub fn new(connection: DatabaseConnection) -> Self {
         Self {
             file_lock_service: FileLockService::new(&connection),
+            compaction_plan_service: CompactionPlanService::new(&connection),
             user_table_service: UserTableService::new(&connection),
+            authorization_service: AuthorizationService::new(&connection),
+            audit_service: AuditService::new(&connection),
         }
     }
 
+    #[instrument(skip_all, fields(principal = %param.principal))]
     pub async fn acquire_lock(
         &self,
         param: AcquireFileLockParam,
     ) -> Result<Vec<FileWithId>, anyhow::Error> {
-        let table_id = find_table_id(&self.user_table_service, &param.table_name).await?;
-        let stream = UserTablStream::new(table_id, param.stream_id);
+        let mut event = AuditEvent::new(
+            AuditOperation::AcquireFileLock,
+            &param.principal,
+            &param.client,
+        )
+        .with_stream(&param.table_name, &param.stream_id)
+        .with_file_lock_key(&param.file_lock_key);
+        let result = self.acquire_lock_inner(param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        count_acquisition(&event, &result);
+        result
+    }
+
+    // The table name of the event is replaced with the canonical name once the table is found.
+    async fn acquire_lock_inner(
+        &self,
+        param: AcquireFileLockParam,
+        event: &mut AuditEvent,
+    ) -> Result<Vec<FileWithId>, anyhow::Error> {
+        // Locks are taken before both ChangeFiles and CompactFiles.
+        self.authorization_service
+            .authorize_any(
+                &param.principal,
+                &param.table_name,
+                Some(&param.stream_id),
+                &[Permission::Write, Permission::Compact],
+            )
+            .await?;
+
+        // Files are looked up by the partition_time they were added with, so it is not aligned.
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        let partition_filter =
+            transform_partition_filter(&table.properties, &param.partition_filter)?;
+        let ttl = lock_ttl(param.ttl, &table.properties);
+        let stream = UserTablStream::new(table.id, param.stream_id);
+        let owner = lock_owner(param.owner, &param.principal);
+
         let locked_files = self
             .file_lock_service
-            .acquire(&param.file_lock_key, &stream, param.ttl, &param.entries)
+            .acquire(
+                &param.file_lock_key,
+                &stream,
+                ttl,
+                &owner,
+                &param.entries,
+                &partition_filter,
+            )
             .await?;
 
         Ok(locked_files)
     }
 
-    pub async fn release_lock(&self, file_lock_key: FileLockKey) -> Result<bool, anyhow::Error> {
-        let deleted = self.file_lock_service.release(&file_lock_key).await?;
-        Ok(deleted)
+    // Planning alone is not audited since it changes nothing. Planning with a lock key is
+    // audited as a lock acquisition.
+    #[instrument(skip_all, fields(principal = %param.principal))]
+    pub async fn plan_compaction(
+        &self,
+        param: PlanCompactionParam,
+    ) -> Result<Vec<CompactionGroup>, anyhow::Error> {
+        let mut event = AuditEvent::new(
+            AuditOperation::AcquireFileLock,
+            &param.principal,
+            &param.client,
+        )
+        .with_stream(&param.table_name, &param.stream_id);
+        let Some(file_lock_key) = &param.file_lock_key else {
+            return self.plan_compaction_inner(param, &mut event).await;
+        };
+
+        event = event.with_file_lock_key(file_lock_key);
+        let result = self.plan_compaction_inner(param, &mut event).await;
+        self.audit_service.record(&event, &result).await;
+        count_acquisition(&event, &result);
+        result
+    }
+
+    async fn plan_compaction_inner(
+        &self,
+        param: PlanCompactionParam,
+        event: &mut AuditEvent,
+    ) -> Result<Vec<CompactionGroup>, anyhow::Error> {
+        self.authorization_service
+            .authorize(
+                &param.principal,
+                &param.table_name,
+                Some(&param.stream_id),
+                Permission::Compact,
+            )
+            .await?;
+
+        let table = find_table(&self.user_table_service, &param.table_name).await?;
+        event.table_name = Some(table.name.clone());
+        let stream = UserTablStream::new(table.id, param.stream_id);
+
+        let Some(file_lock_key) = param.file_lock_key else {
+            return self
+                .compaction_plan_service
+                .plan(&stream, &param.policy, &param.partition_time_range)
+                .await;
+        };
+
+        let ttl = lock_ttl(param.ttl, &table.properties);
+        let owner = lock_owner(param.owner, &param.principal);
+
+        self.compaction_plan_service
+            .plan_and_lock(
+                &file_lock_key,
+                &stream,
+                ttl,
+                &owner,
+                &param.policy,
+                &param.partition_time_range,
+            )
+            .await
+    }
+
+    #[instrument(skip_all, fields(principal = %principal))]
+    pub async fn release_lock(
+        &self,
+        file_lock_key: FileLockKey,
+        principal: &Principal,
+        client: &ClientInfo,
+    ) -> Result<bool, anyhow::Error> {
+        let mut event = AuditEvent::new(AuditOperation::ReleaseFileLock, principal, client)
+            .with_file_lock_key(&file_lock_key);
+        let result = self
+            .release_lock_inner(&file_lock_key, principal, &mut event)
+            .await;
+        self.audit_service.record(&event, &result).await;
+        result
+    }
+
+    // Releasing requires the same permission as acquiring on the stream of the lock.
+    // The stream is recorded to the event once the lock is found.
+    async fn release_lock_inner(
+        &self,
+        file_lock_key: &FileLockKey,
+        principal: &Principal,
+        event: &mut AuditEvent,
+    ) -> Result<bool, anyhow::Error> {
+        let Some(lock) = self.file_lock_service.find(file_lock_key).await? else {
+            return Ok(false);
+        };
+        let tables = self
+            .user_table_service
+            .find_all_by_ids(std::slice::from_ref(&lock.stream.user_table_id))
+            .await?;
+        let Some(table) = tables.first() else {
+            bail!(MangrobeError::UnexpectedState(format!(
+                "table of the lock not found. user_table_id={}",
+                lock.stream.user_table_id.val()
+            )));
+        };
+        event.table_name = Some(table.name.clone());
+        event.stream_id = Some(lock.stream.stream_id.clone());
+
+        self.authorization_service
+            .authorize_any(
+                principal,
+                &table.name,
+                Some(&lock.stream.stream_id),
+                &[Permission::Write, Permission::Compact],
+            )
+            .await?;
+
+        self.file_lock_service.release(file_lock_key).await
+    }
+}
+
+fn lock_ttl(ttl: Option<Duration>, properties: &TableProperties) -> Duration {
+    ttl.or(properties.default_lock_ttl())
+        .unwrap_or(Duration::zero())
+}
+
+// Without an explicit owner, the authenticated principal holds the lock.
+fn lock_owner(mut owner: FileLockOwner, principal: &Principal) -> FileLockOwner {
+    if owner.owner_id.is_none() && !principal.is_anonymous() {
+        owner.owner_id = Some(principal.id.clone());
     }
+    owner
+}
+
+fn count_acquisition<T>(event: &AuditEvent, result: &Result<T, anyhow::Error>) {
+    let Some(table_name) = &event.table_name else {
+        return;
+    };
+    let label = match result {
+        Ok(_) => "acquired",
+        Err(e) => match e.downcast_ref::<LockError>() {
+            Some(LockError::HeldByOther(_)) => "conflicted",
+            _ => "failed",
+        },
+    };
+
+    FILE_LOCK_ACQUISITIONS_TOTAL
+        .with_label_values(&[table_name.val().as_str(), label])
+        .inc();
 }
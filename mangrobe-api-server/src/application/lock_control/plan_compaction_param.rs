@@ -0,0 +1,23 @@
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::compaction_plan::CompactionPolicy;
+use crate::domain::model::file_lock_key::FileLockKey;
+use crate::domain::model::file_lock_owner::FileLockOwner;
+use crate::domain::model::partition_time_range::PartitionTimeRange;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_name::UserTableName;
+use chrono::Duration;
+
+pub struct PlanCompactionParam {
+    pub table_name: UserTableName,
+    pub stream_id: StreamId,
+    pub policy: CompactionPolicy,
+    pub partition_time_range: PartitionTimeRange,
+    // The planned files are locked with this key when set.
+    pub file_lock_key: Option<FileLockKey>,
+    // None falls back to the lock.default_ttl_sec property of the table.
+    pub ttl: Option<Duration>,
+    pub owner: FileLockOwner,
+    pub principal: Principal,
+    pub client: ClientInfo,
+}
@@ -0,0 +1 @@
+pub mod monitoring_use_case;
@@ -0,0 +1,78 @@
+use crate::domain::service::file_service::FileService;
+use crate::infrastructure::db::schema::find_pending_migrations;
+use crate::util::error::MangrobeError;
+use crate::util::metrics::{
+    CURRENT_FILES, CURRENT_FILES_OTHER_TABLES, DB_POOL_CONNECTIONS, DB_POOL_MAX_CONNECTIONS,
+};
+use anyhow::bail;
+use sea_orm::DatabaseConnection;
+use std::time::Duration;
+use tracing::error;
+
+pub struct MonitoringUseCase {
+    connection: DatabaseConnection,
+    file_service: FileService,
+}
+
+impl MonitoringUseCase {
+    pub fn new(connection: DatabaseConnection) -> Self {
+        Self {
+            file_service: FileService::new(&connection),
+            connection,
+        }
+    }
+
+    // Updates gauges that are cheap enough to read on every scrape.
+    pub fn refresh_pool_gauges(&self) {
+        let pool = self.connection.get_postgres_connection_pool();
+        let idle = pool.num_idle() as i64;
+        DB_POOL_CONNECTIONS.with_label_values(&["idle"]).set(idle);
+        DB_POOL_CONNECTIONS
+            .with_label_values(&["in_use"])
+            .set(pool.size() as i64 - idle);
+        DB_POOL_MAX_CONNECTIONS.set(pool.options().get_max_connections() as i64);
+    }
+
+    // Updates gauges that need a scan of current files. Only the max_table_labels tables with
+    // the most files get their own label, so that the number of series stays bounded.
+    pub async fn refresh_table_gauges(&self, max_table_labels: usize) -> Result<(), anyhow::Error> {
+        let counts = self.file_service.count_current_files_by_table().await?;
+        // Drops tables that have no current files anymore.
+        CURRENT_FILES.reset();
+        for (table_name, count) in counts.iter().take(max_table_labels) {
+            CURRENT_FILES
+                .with_label_values(&[table_name.val().as_str()])
+                .set(*count as i64);
+        }
+        let others = counts
+            .iter()
+            .skip(max_table_labels)
+            .map(|(_, count)| *count as i64)
+            .sum();
+        CURRENT_FILES_OTHER_TABLES.set(others);
+
+        Ok(())
+    }
+
+    pub async fn run_table_gauge_refresher(&self, interval: Duration, max_table_labels: usize) {
+        loop {
+            if let Err(e) = self.refresh_table_gauges(max_table_labels).await {
+                error!(error = ?e, "failed to refresh table gauges");
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    // Healthy when the database is reachable and its schema matches this binary.
+    pub async fn check_health(&self) -> Result<(), anyhow::Error> {
+        self.connection.ping().await?;
+
+        let pending = find_pending_migrations(&self.connection).await?;
+        if !pending.is_empty() {
+            bail!(MangrobeError::SchemaOutdated(pending.join(", ")));
+        }
+
+        Ok(())
+    }
+}
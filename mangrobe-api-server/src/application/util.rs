@@ -1 +1,3 @@
+pub mod partition_time;
+pub mod partition_values;
 pub mod user_table;
@@ -0,0 +1,17 @@
+use crate::domain::model::table_properties::TableProperties;
+use crate::util::error::UserError;
+use chrono::{DateTime, Utc};
+
+// Checks partition_time against the partition granularity of the table, truncating it when the
+// table allows.
+pub fn align_partition_time(
+    properties: &TableProperties,
+    partition_time: DateTime<Utc>,
+) -> Result<DateTime<Utc>, anyhow::Error> {
+    let Some(spec) = properties.partition_spec() else {
+        return Ok(partition_time);
+    };
+
+    spec.align(partition_time)
+        .map_err(|e| UserError::InvalidParameterMessage(e).into())
+}
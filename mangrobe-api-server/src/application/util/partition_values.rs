@@ -0,0 +1,25 @@
+use crate::domain::model::table_properties::TableProperties;
+use crate::util::error::UserError;
+use std::collections::BTreeMap;
+
+// Transforms the column values of a file by the partition fields of the table.
+pub fn transform_partition_values(
+    properties: &TableProperties,
+    values: &BTreeMap<String, String>,
+) -> Result<BTreeMap<String, String>, anyhow::Error> {
+    properties
+        .partition_fields()
+        .transform_values(values)
+        .map_err(|e| UserError::InvalidParameterMessage(e).into())
+}
+
+// Same as transform_partition_values, but any subset of the partition fields can be given.
+pub fn transform_partition_filter(
+    properties: &TableProperties,
+    filter: &BTreeMap<String, String>,
+) -> Result<BTreeMap<String, String>, anyhow::Error> {
+    properties
+        .partition_fields()
+        .transform_filter(filter)
+        .map_err(|e| UserError::InvalidParameterMessage(e).into())
+}
@@ -1,3 +1,4 @@
+use crate::domain::model::user_table::UserTable;
 use crate::domain::model::user_table_id::UserTableId;
 use crate::domain::model::user_table_name::UserTableName;
 use crate::domain::service::user_table_service::UserTableService;
@@ -19,3 +20,21 @@ pub async fn find_table_id(
 
     Ok(table_id)
 }
+
+// Resolves aliases. Dropped tables are not found.
+pub async fn find_table(
+    user_table_service: &UserTableService,
+    name: &UserTableName,
+) -> Result<UserTable, anyhow::Error> {
+    let table = user_table_service.find_by_name(name).await?;
+
+    let Some(table) = table.filter(|t| !t.is_dropped()) else {
+        return Err(UserError::InvalidParameterMessage(format!(
+            "table_name '{}' not found",
+            name.val()
+        ))
+        .into());
+    };
+
+    Ok(table)
+}
@@ -0,0 +1,561 @@
+use crate::domain::model::principal::validate_qualified_id;
+use crate::domain::model::quota::{QuotaLimits, QuotaOverride, QuotaPolicy};
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::util::error::ConfigError;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing_subscriber::EnvFilter;
+
+const DEFAULT_ADDR: &str = "[::1]:50051";
+const DEFAULT_DATABASE_URL: &str = "postgres://postgres:@127.0.0.1:5432/mangrobe-development";
+const DEFAULT_LOG_FILTER: &str = "info,sea_orm=debug";
+const DEFAULT_MAX_LOG_FIELD_LENGTH: usize = 4096;
+const DEFAULT_SERVICE_NAME: &str = "mangrobe-api-server";
+// Same as tonic's default.
+const DEFAULT_MAX_DECODING_MESSAGE_SIZE: usize = 4 * 1024 * 1024;
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppConfig {
+    pub server: ServerConfig,
+    pub database: DatabaseConfig,
+    pub limits: LimitsConfig,
+    pub log: LogConfig,
+    pub auth: AuthConfig,
+    pub quotas: QuotaConfig,
+    pub metrics: MetricsConfig,
+    pub telemetry: TelemetryConfig,
+    pub tables: TablesConfig,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub addr: SocketAddr,
+    pub tls: Option<TlsConfig>,
+    // How long to wait for in-flight requests after SIGTERM before exiting anyway.
+    pub shutdown_timeout_sec: u64,
+    pub health_check_interval_sec: u64,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+    // Requires clients to present a certificate signed by this CA (mTLS).
+    pub client_ca_path: Option<PathBuf>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    pub url: String,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub connect_timeout_sec: u64,
+    pub acquire_timeout_sec: u64,
+    pub idle_timeout_sec: u64,
+    pub max_lifetime_sec: u64,
+    pub sqlx_logging: bool,
+    pub migrate: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitsConfig {
+    pub max_decoding_message_size: usize,
+    pub max_encoding_message_size: usize,
+    pub request_timeout_sec: Option<u64>,
+    pub concurrency_limit_per_connection: Option<usize>,
+}
+
+// Authentication is enabled when any token, JWKS or client CA is configured.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthConfig {
+    // Principals allowed every operation regardless of grants.
+    pub admin_principals: Vec<String>,
+    pub tokens: Vec<StaticTokenConfig>,
+    pub jwt: Option<JwtConfig>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct StaticTokenConfig {
+    pub principal: String,
+    pub token: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct JwtConfig {
+    pub jwks_path: PathBuf,
+    pub issuer: Option<String>,
+    pub audience: Option<String>,
+    #[serde(default = "default_principal_claim")]
+    pub principal_claim: String,
+}
+
+// Quotas apply to each stream. Unset limits are unlimited.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct QuotaConfig {
+    pub commits_per_second: Option<u32>,
+    pub files_per_commit: Option<u64>,
+    pub max_current_files: Option<u64>,
+    pub max_current_bytes: Option<u64>,
+    pub overrides: Vec<QuotaOverrideConfig>,
+}
+
+// Applies to the whole table, or to a single stream when stream_id is set.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct QuotaOverrideConfig {
+    pub table_name: String,
+    pub stream_id: Option<i64>,
+    pub commits_per_second: Option<u32>,
+    pub files_per_commit: Option<u64>,
+    pub max_current_files: Option<u64>,
+    pub max_current_bytes: Option<u64>,
+}
+
+// Serves Prometheus metrics over plain HTTP when addr is set.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct MetricsConfig {
+    pub addr: Option<SocketAddr>,
+    // Gauges that need a database scan are refreshed at this interval instead of on every scrape.
+    pub refresh_interval_sec: u64,
+    // Tables with the most current files get their own table_name label. The rest are summed up.
+    pub max_table_labels: usize,
+}
+
+// Exports traces over OTLP/gRPC when otlp_endpoint is set.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct TelemetryConfig {
+    pub otlp_endpoint: Option<String>,
+    pub service_name: String,
+    // Ratio of new traces to sample. Traces started by clients follow their sampling decision.
+    pub sample_ratio: f64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+    // EnvFilter directives, e.g. "info,sea_orm=debug" sets the level per module.
+    pub filter: String,
+    pub format: LogFormat,
+    // Longer field values are truncated, e.g. SQL statements carrying parquet metadata.
+    pub max_field_length: usize,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Pretty,
+    Json,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct TablesConfig {
+    // Dropped tables can be undropped until their data is purged after this period.
+    pub drop_grace_period_sec: u64,
+    pub purge_interval_sec: u64,
+}
+
+fn default_principal_claim() -> String {
+    "sub".into()
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            addr: DEFAULT_ADDR.parse().unwrap(),
+            tls: None,
+            shutdown_timeout_sec: 30,
+            health_check_interval_sec: 5,
+        }
+    }
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            url: DEFAULT_DATABASE_URL.into(),
+            max_connections: 100,
+            min_connections: 5,
+            connect_timeout_sec: 8,
+            acquire_timeout_sec: 8,
+            idle_timeout_sec: 8,
+            max_lifetime_sec: 8,
+            sqlx_logging: true,
+            migrate: false,
+        }
+    }
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_decoding_message_size: DEFAULT_MAX_DECODING_MESSAGE_SIZE,
+            max_encoding_message_size: usize::MAX,
+            request_timeout_sec: None,
+            concurrency_limit_per_connection: None,
+        }
+    }
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            addr: None,
+            refresh_interval_sec: 60,
+            max_table_labels: 100,
+        }
+    }
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            otlp_endpoint: None,
+            service_name: DEFAULT_SERVICE_NAME.into(),
+            sample_ratio: 1.0,
+        }
+    }
+}
+
+impl Default for TablesConfig {
+    fn default() -> Self {
+        Self {
+            drop_grace_period_sec: 7 * 24 * 60 * 60,
+            purge_interval_sec: 5 * 60,
+        }
+    }
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            filter: DEFAULT_LOG_FILTER.into(),
+            format: LogFormat::default(),
+            max_field_length: DEFAULT_MAX_LOG_FIELD_LENGTH,
+        }
+    }
+}
+
+impl AppConfig {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        self.server.validate()?;
+        self.database.validate()?;
+        self.limits.validate()?;
+        self.log.validate()?;
+        self.auth.validate()?;
+        self.quotas.to_policy()?;
+        self.metrics.validate()?;
+        self.telemetry.validate()?;
+        self.tables.validate()?;
+
+        Ok(())
+    }
+}
+
+impl ServerConfig {
+    pub fn client_ca_enabled(&self) -> bool {
+        self.tls
+            .as_ref()
+            .is_some_and(|tls| tls.client_ca_path.is_some())
+    }
+
+    pub fn shutdown_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown_timeout_sec)
+    }
+
+    pub fn health_check_interval(&self) -> Duration {
+        Duration::from_secs(self.health_check_interval_sec)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.health_check_interval_sec == 0 {
+            return Err(invalid(
+                "server.health_check_interval_sec",
+                "must be positive",
+            ));
+        }
+
+        let Some(tls) = &self.tls else {
+            return Ok(());
+        };
+
+        validate_file_path("server.tls.cert_path", &tls.cert_path)?;
+        validate_file_path("server.tls.key_path", &tls.key_path)?;
+        if let Some(client_ca_path) = &tls.client_ca_path {
+            validate_file_path("server.tls.client_ca_path", client_ca_path)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DatabaseConfig {
+    pub fn connect_timeout(&self) -> Duration {
+        Duration::from_secs(self.connect_timeout_sec)
+    }
+
+    pub fn acquire_timeout(&self) -> Duration {
+        Duration::from_secs(self.acquire_timeout_sec)
+    }
+
+    pub fn idle_timeout(&self) -> Duration {
+        Duration::from_secs(self.idle_timeout_sec)
+    }
+
+    pub fn max_lifetime(&self) -> Duration {
+        Duration::from_secs(self.max_lifetime_sec)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.url.is_empty() {
+            return Err(invalid("database.url", "must not be empty"));
+        }
+        if !self.url.starts_with("postgres://") && !self.url.starts_with("postgresql://") {
+            return Err(invalid(
+                "database.url",
+                "must start with postgres:// or postgresql://",
+            ));
+        }
+        if self.max_connections == 0 {
+            return Err(invalid("database.max_connections", "must be positive"));
+        }
+        if self.min_connections > self.max_connections {
+            return Err(invalid(
+                "database.min_connections",
+                "must not exceed database.max_connections",
+            ));
+        }
+        if self.connect_timeout_sec == 0 {
+            return Err(invalid("database.connect_timeout_sec", "must be positive"));
+        }
+        if self.acquire_timeout_sec == 0 {
+            return Err(invalid("database.acquire_timeout_sec", "must be positive"));
+        }
+
+        Ok(())
+    }
+}
+
+impl LimitsConfig {
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout_sec.map(Duration::from_secs)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_decoding_message_size == 0 {
+            return Err(invalid(
+                "limits.max_decoding_message_size",
+                "must be positive",
+            ));
+        }
+        if self.max_encoding_message_size == 0 {
+            return Err(invalid(
+                "limits.max_encoding_message_size",
+                "must be positive",
+            ));
+        }
+        if self.request_timeout_sec == Some(0) {
+            return Err(invalid("limits.request_timeout_sec", "must be positive"));
+        }
+        if self.concurrency_limit_per_connection == Some(0) {
+            return Err(invalid(
+                "limits.concurrency_limit_per_connection",
+                "must be positive",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl AuthConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        for principal in &self.admin_principals {
+            validate_qualified_id(principal).map_err(|e| invalid("auth.admin_principals", &e))?;
+        }
+
+        let mut tokens = HashSet::new();
+        for token in &self.tokens {
+            if token.principal.is_empty() {
+                return Err(invalid("auth.tokens.principal", "must not be empty"));
+            }
+            if token.token.is_empty() {
+                return Err(invalid("auth.tokens.token", "must not be empty"));
+            }
+            if !tokens.insert(&token.token) {
+                return Err(invalid(
+                    "auth.tokens.token",
+                    &format!("duplicated. principal={}", token.principal),
+                ));
+            }
+        }
+
+        if let Some(jwt) = &self.jwt {
+            validate_file_path("auth.jwt.jwks_path", &jwt.jwks_path)?;
+            if jwt.principal_claim.is_empty() {
+                return Err(invalid("auth.jwt.principal_claim", "must not be empty"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl QuotaConfig {
+    pub fn to_policy(&self) -> Result<QuotaPolicy, ConfigError> {
+        let default = validate_quota_limits(
+            "quotas",
+            QuotaLimits {
+                commits_per_second: self.commits_per_second,
+                files_per_commit: self.files_per_commit,
+                max_current_files: self.max_current_files,
+                max_current_bytes: self.max_current_bytes,
+            },
+        )?;
+        let mut overrides = vec![];
+        for o in &self.overrides {
+            let table_name = UserTableName::try_from(o.table_name.clone())
+                .map_err(|e| invalid("quotas.overrides.table_name", &e))?;
+            overrides.push(QuotaOverride {
+                table_name,
+                stream_id: o.stream_id.map(StreamId::from),
+                limits: validate_quota_limits(
+                    "quotas.overrides",
+                    QuotaLimits {
+                        commits_per_second: o.commits_per_second,
+                        files_per_commit: o.files_per_commit,
+                        max_current_files: o.max_current_files,
+                        max_current_bytes: o.max_current_bytes,
+                    },
+                )?,
+            });
+        }
+
+        Ok(QuotaPolicy { default, overrides })
+    }
+}
+
+impl TelemetryConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(endpoint) = &self.otlp_endpoint
+            && !endpoint.starts_with("http://")
+            && !endpoint.starts_with("https://")
+        {
+            return Err(invalid(
+                "telemetry.otlp_endpoint",
+                "must start with http:// or https://",
+            ));
+        }
+        if self.service_name.is_empty() {
+            return Err(invalid("telemetry.service_name", "must not be empty"));
+        }
+        if !(0.0..=1.0).contains(&self.sample_ratio) {
+            return Err(invalid(
+                "telemetry.sample_ratio",
+                "must be between 0.0 and 1.0",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl MetricsConfig {
+    pub fn refresh_interval(&self) -> Duration {
+        Duration::from_secs(self.refresh_interval_sec)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.refresh_interval_sec == 0 {
+            return Err(invalid("metrics.refresh_interval_sec", "must be positive"));
+        }
+
+        Ok(())
+    }
+}
+
+impl TablesConfig {
+    pub fn drop_grace_period(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.drop_grace_period_sec as i64)
+    }
+
+    pub fn purge_interval(&self) -> Duration {
+        Duration::from_secs(self.purge_interval_sec)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.drop_grace_period_sec > i64::MAX as u64 / 1000 {
+            return Err(invalid("tables.drop_grace_period_sec", "too large"));
+        }
+        if self.purge_interval_sec == 0 {
+            return Err(invalid("tables.purge_interval_sec", "must be positive"));
+        }
+
+        Ok(())
+    }
+}
+
+impl LogConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        EnvFilter::try_new(&self.filter).map_err(|e| invalid("log.filter", &e.to_string()))?;
+        if self.max_field_length == 0 {
+            return Err(invalid("log.max_field_length", "must be positive"));
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_quota_limits(prefix: &str, limits: QuotaLimits) -> Result<QuotaLimits, ConfigError> {
+    let values = [
+        (
+            "commits_per_second",
+            limits.commits_per_second.map(u64::from),
+        ),
+        ("files_per_commit", limits.files_per_commit),
+        ("max_current_files", limits.max_current_files),
+        ("max_current_bytes", limits.max_current_bytes),
+    ];
+    for (key, value) in values {
+        if value == Some(0) {
+            return Err(invalid(&format!("{}.{}", prefix, key), "must be positive"));
+        }
+    }
+
+    Ok(limits)
+}
+
+fn validate_file_path(key: &str, path: &Path) -> Result<(), ConfigError> {
+    if path.as_os_str().is_empty() {
+        return Err(invalid(key, "required"));
+    }
+    if !path.is_file() {
+        return Err(invalid(
+            key,
+            &format!("file not found. path={}", path.display()),
+        ));
+    }
+
+    Ok(())
+}
+
+fn invalid(key: &str, message: &str) -> ConfigError {
+    ConfigError::Invalid(key.into(), message.into())
+}
@@ -0,0 +1,182 @@
+use crate::config::app_config::{AppConfig, LogFormat, TlsConfig};
+use crate::util::error::ConfigError;
+use clap::Parser;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+/// Command line flags. Flags take precedence over environment variables,
+/// which take precedence over the config file.
+#[derive(Parser, Debug)]
+#[command(version, about = "Mangrobe API Server")]
+pub struct CliArgs {
+    /// Path to the TOML config file
+    #[arg(long, env = "MANGROBE_CONFIG")]
+    pub config: Option<PathBuf>,
+
+    #[arg(long, env = "MANGROBE_API_ADDR")]
+    pub addr: Option<SocketAddr>,
+
+    #[arg(long, env = "MANGROBE_DATABASE_URL", hide_env_values = true)]
+    pub database_url: Option<String>,
+
+    #[arg(long, env = "MANGROBE_DATABASE_MAX_CONNECTIONS")]
+    pub database_max_connections: Option<u32>,
+
+    #[arg(long, env = "MANGROBE_DATABASE_MIN_CONNECTIONS")]
+    pub database_min_connections: Option<u32>,
+
+    #[arg(long, env = "MANGROBE_DATABASE_CONNECT_TIMEOUT_SEC")]
+    pub database_connect_timeout_sec: Option<u64>,
+
+    #[arg(long, env = "MANGROBE_DATABASE_ACQUIRE_TIMEOUT_SEC")]
+    pub database_acquire_timeout_sec: Option<u64>,
+
+    #[arg(long, env = "MANGROBE_DATABASE_IDLE_TIMEOUT_SEC")]
+    pub database_idle_timeout_sec: Option<u64>,
+
+    #[arg(long, env = "MANGROBE_DATABASE_MAX_LIFETIME_SEC")]
+    pub database_max_lifetime_sec: Option<u64>,
+
+    /// Log every SQL statement, e.g. `--database-sqlx-logging false`
+    #[arg(long, env = "MANGROBE_DATABASE_SQLX_LOGGING")]
+    pub database_sqlx_logging: Option<bool>,
+
+    /// Apply pending migrations at startup instead of refusing to start
+    #[arg(long, env = "MANGROBE_MIGRATE")]
+    pub migrate: bool,
+
+    #[arg(long, env = "MANGROBE_TLS_CERT_PATH")]
+    pub tls_cert_path: Option<PathBuf>,
+
+    #[arg(long, env = "MANGROBE_TLS_KEY_PATH")]
+    pub tls_key_path: Option<PathBuf>,
+
+    #[arg(long, env = "MANGROBE_TLS_CLIENT_CA_PATH")]
+    pub tls_client_ca_path: Option<PathBuf>,
+
+    #[arg(long, env = "MANGROBE_MAX_DECODING_MESSAGE_SIZE")]
+    pub max_decoding_message_size: Option<usize>,
+
+    #[arg(long, env = "MANGROBE_MAX_ENCODING_MESSAGE_SIZE")]
+    pub max_encoding_message_size: Option<usize>,
+
+    #[arg(long, env = "MANGROBE_REQUEST_TIMEOUT_SEC")]
+    pub request_timeout_sec: Option<u64>,
+
+    #[arg(long, env = "MANGROBE_CONCURRENCY_LIMIT_PER_CONNECTION")]
+    pub concurrency_limit_per_connection: Option<usize>,
+
+    #[arg(long, env = "MANGROBE_LOG")]
+    pub log_filter: Option<String>,
+
+    #[arg(long, env = "MANGROBE_LOG_FORMAT", value_enum)]
+    pub log_format: Option<LogFormat>,
+
+    /// Address of the Prometheus /metrics endpoint. Disabled when not set
+    #[arg(long, env = "MANGROBE_METRICS_ADDR")]
+    pub metrics_addr: Option<SocketAddr>,
+
+    /// OTLP/gRPC endpoint to export traces to, e.g. http://localhost:4317. Disabled when not set
+    #[arg(long, env = "MANGROBE_OTLP_ENDPOINT")]
+    pub otlp_endpoint: Option<String>,
+}
+
+impl CliArgs {
+    pub fn load_config(&self) -> Result<AppConfig, ConfigError> {
+        let mut config = match &self.config {
+            Some(path) => read_config_file(path)?,
+            None => AppConfig::default(),
+        };
+
+        self.apply_overrides(&mut config);
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    fn apply_overrides(&self, config: &mut AppConfig) {
+        if let Some(addr) = self.addr {
+            config.server.addr = addr;
+        }
+        if self.tls_cert_path.is_some()
+            || self.tls_key_path.is_some()
+            || self.tls_client_ca_path.is_some()
+        {
+            let tls = config.server.tls.get_or_insert_with(TlsConfig::default);
+            if let Some(cert_path) = &self.tls_cert_path {
+                tls.cert_path = cert_path.clone();
+            }
+            if let Some(key_path) = &self.tls_key_path {
+                tls.key_path = key_path.clone();
+            }
+            if let Some(client_ca_path) = &self.tls_client_ca_path {
+                tls.client_ca_path = Some(client_ca_path.clone());
+            }
+        }
+
+        if let Some(url) = &self.database_url {
+            config.database.url = url.clone();
+        }
+        if let Some(max_connections) = self.database_max_connections {
+            config.database.max_connections = max_connections;
+        }
+        if let Some(min_connections) = self.database_min_connections {
+            config.database.min_connections = min_connections;
+        }
+        if let Some(connect_timeout_sec) = self.database_connect_timeout_sec {
+            config.database.connect_timeout_sec = connect_timeout_sec;
+        }
+        if let Some(acquire_timeout_sec) = self.database_acquire_timeout_sec {
+            config.database.acquire_timeout_sec = acquire_timeout_sec;
+        }
+        if let Some(idle_timeout_sec) = self.database_idle_timeout_sec {
+            config.database.idle_timeout_sec = idle_timeout_sec;
+        }
+        if let Some(max_lifetime_sec) = self.database_max_lifetime_sec {
+            config.database.max_lifetime_sec = max_lifetime_sec;
+        }
+        if let Some(sqlx_logging) = self.database_sqlx_logging {
+            config.database.sqlx_logging = sqlx_logging;
+        }
+
+        if self.migrate {
+            config.database.migrate = true;
+        }
+
+        if let Some(size) = self.max_decoding_message_size {
+            config.limits.max_decoding_message_size = size;
+        }
+        if let Some(size) = self.max_encoding_message_size {
+            config.limits.max_encoding_message_size = size;
+        }
+        if let Some(timeout_sec) = self.request_timeout_sec {
+            config.limits.request_timeout_sec = Some(timeout_sec);
+        }
+        if let Some(limit) = self.concurrency_limit_per_connection {
+            config.limits.concurrency_limit_per_connection = Some(limit);
+        }
+
+        if let Some(filter) = &self.log_filter {
+            config.log.filter = filter.clone();
+        }
+        if let Some(format) = self.log_format {
+            config.log.format = format;
+        }
+
+        if let Some(addr) = self.metrics_addr {
+            config.metrics.addr = Some(addr);
+        }
+        if let Some(endpoint) = &self.otlp_endpoint {
+            config.telemetry.otlp_endpoint = Some(endpoint.clone());
+        }
+    }
+}
+
+fn read_config_file(path: &PathBuf) -> Result<AppConfig, ConfigError> {
+    let path_str = path.display().to_string();
+    let content =
+        fs::read_to_string(path).map_err(|e| ConfigError::Load(path_str.clone(), e.to_string()))?;
+
+    toml::from_str(&content).map_err(|e| ConfigError::Load(path_str, e.to_string()))
+}
@@ -1,24 +1,42 @@
+pub mod audit_log;
+pub mod audit_log_id;
 pub mod change_request;
 pub mod change_request_file_data;
 pub mod change_request_file_entry;
 pub mod change_request_id;
 pub mod change_request_raw_file_entry;
 pub mod changeset;
+pub mod client_info;
 pub mod commit;
 pub mod commit_id;
 pub mod committed_change_request;
+pub mod compaction_plan;
 pub mod current_file;
 pub mod file;
 pub mod file_column_statistics;
 pub mod file_id;
+pub mod file_lock;
 pub mod file_lock_key;
+pub mod file_lock_owner;
 pub mod file_metadata;
+pub mod file_stats;
 pub mod file_with_statistics;
 pub mod idempotency_key;
 pub mod lock_raw_file_entry;
+pub mod partition_field;
+pub mod partition_granularity;
+pub mod partition_time_range;
+pub mod permission;
+pub mod principal;
+pub mod quota;
 pub mod snapshot;
 pub mod stream;
 pub mod stream_id;
+pub mod stream_id_range;
+pub mod table_alias;
+pub mod table_grant;
+pub mod table_grant_id;
+pub mod table_properties;
 pub mod user_table;
 pub mod user_table_id;
 pub mod user_table_name;
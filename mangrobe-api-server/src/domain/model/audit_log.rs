@@ -0,0 +1,100 @@
+use crate::domain::model::audit_log_id::AuditLogId;
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::file_lock_key::FileLockKey;
+use crate::domain::model::principal::{Principal, PrincipalKind};
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_name::UserTableName;
+use chrono::{DateTime, Utc};
+use strum_macros::Display;
+
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum AuditOperation {
+    CreateTable,
+    DropTable,
+    UndropTable,
+    // Recorded by the server when a dropped table is deleted after its grace period.
+    PurgeTable,
+    RenameTable,
+    CreateTableAlias,
+    DropTableAlias,
+    AlterTableProperties,
+    RegisterStream,
+    SealStream,
+    DeleteStream,
+    AddFiles,
+    ChangeFiles,
+    CompactFiles,
+    AcquireFileLock,
+    ReleaseFileLock,
+}
+
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum AuditOutcome {
+    Succeeded,
+    // Rejected by authorization.
+    Denied,
+    Failed,
+}
+
+// A mutating operation to be recorded.
+#[derive(Clone, Debug)]
+pub struct AuditEvent {
+    pub operation: AuditOperation,
+    pub principal_id: String,
+    pub principal_kind: PrincipalKind,
+    pub client: ClientInfo,
+    pub table_name: Option<UserTableName>,
+    pub stream_id: Option<StreamId>,
+    pub file_lock_key: Option<Vec<u8>>,
+}
+
+impl AuditEvent {
+    pub fn new(operation: AuditOperation, principal: &Principal, client: &ClientInfo) -> Self {
+        Self {
+            operation,
+            principal_id: principal.id.clone(),
+            principal_kind: principal.kind.clone(),
+            client: client.clone(),
+            table_name: None,
+            stream_id: None,
+            file_lock_key: None,
+        }
+    }
+
+    pub fn with_table(mut self, table_name: &UserTableName) -> Self {
+        self.table_name = Some(table_name.clone());
+        self
+    }
+
+    pub fn with_stream(mut self, table_name: &UserTableName, stream_id: &StreamId) -> Self {
+        self.table_name = Some(table_name.clone());
+        self.stream_id = Some(stream_id.clone());
+        self
+    }
+
+    pub fn with_file_lock_key(mut self, file_lock_key: &FileLockKey) -> Self {
+        self.file_lock_key = Some(file_lock_key.key.clone());
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AuditLog {
+    pub id: AuditLogId,
+    pub event: AuditEvent,
+    pub outcome: AuditOutcome,
+    pub error_message: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+// Conditions are combined with AND. `since` is inclusive and `until` is exclusive.
+#[derive(Clone, Debug, Default)]
+pub struct AuditLogFilter {
+    pub table_name: Option<UserTableName>,
+    pub stream_id: Option<StreamId>,
+    pub principal_id: Option<String>,
+    pub operation: Option<AuditOperation>,
+    pub outcome: Option<AuditOutcome>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
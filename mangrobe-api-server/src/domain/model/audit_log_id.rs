@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, Hash, PartialEq)]
+#[serde(transparent)]
+pub struct AuditLogId(i64);
+
+impl From<AuditLogId> for i64 {
+    fn from(id: AuditLogId) -> Self {
+        id.0
+    }
+}
+
+impl From<i64> for AuditLogId {
+    fn from(id: i64) -> Self {
+        Self(id)
+    }
+}
+
+impl AuditLogId {
+    pub fn val(&self) -> i64 {
+        self.0
+    }
+}
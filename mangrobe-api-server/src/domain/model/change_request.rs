@@ -40,6 +40,16 @@ pub struct ChangeRequest {
     pub file_entry: Option<ChangeRequestFileEntry>,
 }
 
+impl ChangeRequest {
+    // Retries with the same idempotency key find the request in progress or committed.
+    pub fn is_new(&self) -> bool {
+        !self
+            .base
+            .status
+            .is_completed(ChangeRequestStatus::ChangeInserted)
+    }
+}
+
 impl ChangeRequestTrait for ChangeRequest {
     fn id(&self) -> &ChangeRequestId {
         &self.base.id
@@ -33,7 +33,7 @@ pub struct ChangeRequestCompactFilesData {
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct ChangeRequestCompactFileData {
     pub src_files: Vec<FileData>,
-    pub dst_file: FileData,
+    pub dst_files: Vec<FileData>,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
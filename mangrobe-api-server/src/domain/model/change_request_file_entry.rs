@@ -25,7 +25,29 @@ pub struct ChangeRequestCompactFilesEntry {
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct ChangeRequestCompactFileEntry {
     pub src_file_ids: Vec<FileId>,
-    pub dst_file_id: FileId,
+    // Entries saved before multiple destinations were supported hold a single dst_file_id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dst_file_id: Option<FileId>,
+    #[serde(default)]
+    dst_file_ids: Vec<FileId>,
+}
+
+impl ChangeRequestCompactFileEntry {
+    pub fn new(src_file_ids: Vec<FileId>, dst_file_ids: Vec<FileId>) -> Self {
+        Self {
+            src_file_ids,
+            dst_file_id: None,
+            dst_file_ids,
+        }
+    }
+
+    pub fn dst_file_ids(&self) -> Vec<FileId> {
+        self.dst_file_id
+            .iter()
+            .chain(self.dst_file_ids.iter())
+            .cloned()
+            .collect()
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -52,8 +74,13 @@ impl ChangeRequestFileEntry {
             ChangeRequestFileEntry::Compact { compact } => compact
                 .entries
                 .iter()
-                .flat_map(|entry| entry.src_file_ids.iter().chain([&entry.dst_file_id]))
-                .cloned()
+                .flat_map(|entry| {
+                    entry
+                        .src_file_ids
+                        .iter()
+                        .cloned()
+                        .chain(entry.dst_file_ids())
+                })
                 .collect(),
         }
     }
@@ -80,7 +107,7 @@ impl ChangeRequestFileEntry {
                         .iter()
                         .map(|entry| ChangeRequestCompactFileData {
                             src_files: self.build_file_datas(file_map, &entry.src_file_ids),
-                            dst_file: self.build_file_data(file_map, &entry.dst_file_id),
+                            dst_files: self.build_file_datas(file_map, &entry.dst_file_ids()),
                         })
                         .collect(),
                 },
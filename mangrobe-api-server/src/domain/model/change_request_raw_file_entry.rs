@@ -31,35 +31,49 @@ impl ChangeRequestRawChangeFilesEntry {
     }
 }
 
+// Sources and destinations may each be in a different partition, so that compaction can also
+// repartition files. Several destinations can be written from the same sources.
 #[derive(Debug)]
-pub struct ChangeRequestRawCompactFilesEntry {
+pub struct ChangeRequestRawCompactFileInfoEntry {
+    pub src_files: Vec<PartitionedFilePath>,
+    pub dst_files: Vec<PartitionedFileEntry>,
+}
+
+impl ChangeRequestRawCompactFileInfoEntry {
+    pub fn new(src_files: Vec<PartitionedFilePath>, dst_files: Vec<PartitionedFileEntry>) -> Self {
+        Self {
+            src_files,
+            dst_files,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartitionedFilePath {
     pub partition_time: DateTime<Utc>,
-    pub info_entries: Vec<ChangeRequestRawCompactFileInfoEntry>,
+    pub path: FilePath,
 }
 
-impl ChangeRequestRawCompactFilesEntry {
-    pub fn new(
-        partition_time: DateTime<Utc>,
-        info_entries: Vec<ChangeRequestRawCompactFileInfoEntry>,
-    ) -> Self {
+impl PartitionedFilePath {
+    pub fn new(partition_time: DateTime<Utc>, path: FilePath) -> Self {
         Self {
             partition_time,
-            info_entries,
+            path,
         }
     }
 }
 
 #[derive(Debug)]
-pub struct ChangeRequestRawCompactFileInfoEntry {
-    pub src_file_paths: Vec<FilePath>,
-    pub dst_file: FileEntry,
+pub struct PartitionedFileEntry {
+    pub partition_time: DateTime<Utc>,
+    pub file: FileEntry,
 }
 
-impl ChangeRequestRawCompactFileInfoEntry {
-    pub fn new(src_file_paths: Vec<FilePath>, dst_file: FileEntry) -> Self {
+impl PartitionedFileEntry {
+    pub fn new(partition_time: DateTime<Utc>, file: FileEntry) -> Self {
         Self {
-            src_file_paths,
-            dst_file,
+            partition_time,
+            file,
         }
     }
 }
@@ -23,7 +23,7 @@ impl Changeset {
             add_file_ids: entry
                 .entries
                 .iter()
-                .map(|e| e.dst_file_id.clone())
+                .flat_map(|e| e.dst_file_ids())
                 .collect(),
             delete_file_ids: entry
                 .entries
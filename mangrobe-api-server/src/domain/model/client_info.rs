@@ -0,0 +1,15 @@
+// Where the request came from. Recorded for auditing only, since the client can set the request id freely.
+#[derive(Clone, Debug, Default)]
+pub struct ClientInfo {
+    pub address: Option<String>,
+    pub request_id: Option<String>,
+}
+
+impl ClientInfo {
+    pub fn new(address: Option<String>, request_id: Option<String>) -> Self {
+        Self {
+            address,
+            request_id,
+        }
+    }
+}
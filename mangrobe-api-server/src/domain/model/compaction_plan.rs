@@ -0,0 +1,174 @@
+use crate::domain::model::file::FileWithId;
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+
+// How PlanCompaction chooses files to merge.
+#[derive(Clone, Debug)]
+pub struct CompactionPolicy {
+    // Files of at least this size are left as they are, and groups are filled up to this size.
+    pub target_file_size: i64,
+    // Groups with fewer files are not worth compacting and are dropped.
+    pub min_files: usize,
+    pub max_groups: usize,
+}
+
+// Files of one partition to be merged together.
+#[derive(Clone, Debug)]
+pub struct CompactionGroup {
+    pub partition_time: DateTime<Utc>,
+    pub partition_values: BTreeMap<String, String>,
+    pub files: Vec<FileWithId>,
+}
+
+impl CompactionGroup {
+    pub fn total_size(&self) -> i64 {
+        self.files.iter().map(|f| f.file.size).sum()
+    }
+}
+
+impl CompactionPolicy {
+    // Files are grouped by partition_time and partition values, then packed smallest first
+    // until the next file would exceed the target size.
+    pub fn plan(&self, files: Vec<FileWithId>) -> Vec<CompactionGroup> {
+        let mut partitions: BTreeMap<_, Vec<FileWithId>> = BTreeMap::new();
+        for file in files {
+            if file.file.size >= self.target_file_size {
+                continue;
+            }
+            partitions
+                .entry((file.file.partition_time, file.file.partition_values.clone()))
+                .or_default()
+                .push(file);
+        }
+
+        let mut groups = vec![];
+        for ((partition_time, partition_values), mut files) in partitions {
+            files.sort_by_key(|f| (f.file.size, f.id.val()));
+
+            let mut bins: Vec<Vec<FileWithId>> = vec![];
+            let mut bin: Vec<FileWithId> = vec![];
+            let mut bin_size = 0;
+            for file in files {
+                if !bin.is_empty() && bin_size + file.file.size > self.target_file_size {
+                    bins.push(std::mem::take(&mut bin));
+                    bin_size = 0;
+                }
+                bin_size += file.file.size;
+                bin.push(file);
+            }
+            bins.push(bin);
+
+            for files in bins.into_iter().filter(|b| b.len() >= self.min_files) {
+                if groups.len() >= self.max_groups {
+                    return groups;
+                }
+                groups.push(CompactionGroup {
+                    partition_time,
+                    partition_values: partition_values.clone(),
+                    files,
+                });
+            }
+        }
+
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::user_table_stream::UserTablStream;
+    use chrono::TimeZone;
+
+    const TARGET: i64 = 10;
+
+    fn policy(min_files: usize, max_groups: usize) -> CompactionPolicy {
+        CompactionPolicy {
+            target_file_size: TARGET,
+            min_files,
+            max_groups,
+        }
+    }
+
+    fn file(id: i64, hour: u32, region: &str, size: i64) -> FileWithId {
+        FileWithId::new(
+            id.into(),
+            UserTablStream::new(1.into(), 1.into()),
+            Utc.with_ymd_and_hms(2026, 10, 19, hour, 0, 0).unwrap(),
+            format!("file-{id}").into(),
+            size,
+            BTreeMap::from([("region".to_string(), region.to_string())]),
+        )
+    }
+
+    fn ids(groups: &[CompactionGroup]) -> Vec<Vec<i64>> {
+        groups
+            .iter()
+            .map(|g| g.files.iter().map(|f| f.id.val()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn groups_by_partition_time_and_values() {
+        let files = vec![
+            file(1, 0, "ap", 1),
+            file(2, 1, "ap", 1),
+            file(3, 0, "eu", 1),
+            file(4, 0, "ap", 1),
+            file(5, 1, "ap", 1),
+            file(6, 0, "eu", 1),
+        ];
+
+        let groups = policy(2, 10).plan(files);
+        assert_eq!(ids(&groups), vec![vec![1, 4], vec![3, 6], vec![2, 5]]);
+        assert_eq!(groups[1].partition_values["region"], "eu");
+    }
+
+    #[test]
+    fn packs_smallest_first_up_to_the_target_size() {
+        let files = vec![
+            file(1, 0, "ap", 5),
+            file(2, 0, "ap", 3),
+            file(3, 0, "ap", 4),
+            file(4, 0, "ap", 2),
+            file(5, 0, "ap", TARGET),
+        ];
+
+        let groups = policy(1, 10).plan(files);
+        assert_eq!(ids(&groups), vec![vec![4, 2, 3], vec![1]]);
+        assert!(groups.iter().all(|g| g.total_size() <= TARGET));
+    }
+
+    #[test]
+    fn drops_groups_with_fewer_than_min_files() {
+        let files = vec![
+            file(1, 0, "ap", 6),
+            file(2, 0, "ap", 6),
+            file(3, 1, "ap", 1),
+            file(4, 1, "ap", 1),
+        ];
+
+        assert_eq!(ids(&policy(2, 10).plan(files)), vec![vec![3, 4]]);
+    }
+
+    #[test]
+    fn stops_at_max_groups() {
+        let files = (0..3)
+            .flat_map(|hour| {
+                [
+                    file(hour * 2, hour as u32, "ap", 1),
+                    file(hour * 2 + 1, hour as u32, "ap", 1),
+                ]
+            })
+            .collect();
+
+        assert_eq!(ids(&policy(2, 2).plan(files)), vec![vec![0, 1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn stops_at_max_groups_within_a_partition() {
+        let files = (0..6).map(|id| file(id, 0, "ap", 4)).collect();
+
+        assert_eq!(ids(&policy(2, 2).plan(files)), vec![vec![0, 1], vec![2, 3]]);
+    }
+}
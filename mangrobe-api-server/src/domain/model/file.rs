@@ -4,6 +4,7 @@ use crate::domain::model::file_metadata::FileMetadata;
 use crate::domain::model::user_table_stream::UserTablStream;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use xxhash_rust::xxh3::xxh3_128;
 
 #[derive(Clone, Debug)]
@@ -12,6 +13,8 @@ pub struct File {
     pub partition_time: DateTime<Utc>,
     pub path: FilePath,
     pub size: i64,
+    // Transformed values of the table's partition fields.
+    pub partition_values: BTreeMap<String, String>,
 }
 
 impl File {
@@ -20,12 +23,14 @@ impl File {
         partition_time: DateTime<Utc>,
         path: FilePath,
         size: i64,
+        partition_values: BTreeMap<String, String>,
     ) -> Self {
         Self {
             stream,
             partition_time,
             path,
             size,
+            partition_values,
         }
     }
 }
@@ -43,10 +48,11 @@ impl FileWithId {
         partition_time: DateTime<Utc>,
         path: FilePath,
         size: i64,
+        partition_values: BTreeMap<String, String>,
     ) -> Self {
         Self {
             id,
-            file: File::new(stream, partition_time, path, size),
+            file: File::new(stream, partition_time, path, size, partition_values),
         }
     }
 }
@@ -83,6 +89,8 @@ pub struct FileEntry {
     pub size: i64,
     pub column_statistics: Vec<FileColumnStatistics>,
     pub file_metadata: Option<FileMetadata>,
+    // Column values from the request, replaced with the transformed values before storing.
+    pub partition_values: BTreeMap<String, String>,
 }
 
 impl FileEntry {
@@ -91,16 +99,24 @@ impl FileEntry {
         size: i64,
         column_statistics: Vec<FileColumnStatistics>,
         file_metadata: Option<FileMetadata>,
+        partition_values: BTreeMap<String, String>,
     ) -> Self {
         Self {
             path,
             size,
             column_statistics,
             file_metadata,
+            partition_values,
         }
     }
 
     pub fn to_file(&self, stream: UserTablStream, partition_time: DateTime<Utc>) -> File {
-        File::new(stream, partition_time, self.path.clone(), self.size)
+        File::new(
+            stream,
+            partition_time,
+            self.path.clone(),
+            self.size,
+            self.partition_values.clone(),
+        )
     }
 }
@@ -0,0 +1,42 @@
+use crate::domain::model::file_lock_owner::FileLockOwner;
+use crate::domain::model::user_table_stream::UserTablStream;
+use chrono::{DateTime, Utc};
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Debug)]
+pub struct FileLock {
+    pub key: Vec<u8>,
+    pub stream: UserTablStream,
+    pub owner: FileLockOwner,
+    pub expire_at: DateTime<Utc>,
+}
+
+impl FileLock {
+    pub fn new(
+        key: Vec<u8>,
+        stream: UserTablStream,
+        owner: FileLockOwner,
+        expire_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            key,
+            stream,
+            owner,
+            expire_at,
+        }
+    }
+}
+
+impl Display for FileLock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let key: String = self.key.iter().map(|b| format!("{:02x}", b)).collect();
+        write!(
+            f,
+            "key={}, owner_id='{}', reason='{}', expire_at={}",
+            key,
+            self.owner.owner_id.as_deref().unwrap_or_default(),
+            self.owner.reason.as_deref().unwrap_or_default(),
+            self.expire_at.to_rfc3339(),
+        )
+    }
+}
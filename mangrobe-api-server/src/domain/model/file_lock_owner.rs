@@ -0,0 +1,18 @@
+pub const OWNER_ID_LENGTH_LIMIT: usize = 256;
+pub const REASON_LENGTH_LIMIT: usize = 1024;
+
+// Who holds a file lock and why. Both are optional and only used to describe the lock.
+#[derive(Clone, Debug, Default)]
+pub struct FileLockOwner {
+    pub owner_id: Option<String>,
+    pub reason: Option<String>,
+}
+
+impl FileLockOwner {
+    pub fn new(owner_id: Option<String>, reason: Option<String>) -> Self {
+        Self {
+            owner_id: owner_id.filter(|v| !v.is_empty()),
+            reason: reason.filter(|v| !v.is_empty()),
+        }
+    }
+}
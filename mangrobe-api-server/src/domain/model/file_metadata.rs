@@ -1,4 +1,6 @@
-#[derive(Clone, Debug)]
+use std::fmt;
+
+#[derive(Clone)]
 pub struct FileMetadata {
     pub parquet_metadata: Option<Vec<u8>>,
 }
@@ -8,3 +10,18 @@ impl FileMetadata {
         Self { parquet_metadata }
     }
 }
+
+// Parquet metadata can be large, so only its size is printed.
+impl fmt::Debug for FileMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileMetadata")
+            .field(
+                "parquet_metadata",
+                &self
+                    .parquet_metadata
+                    .as_ref()
+                    .map(|m| format!("<{} bytes>", m.len())),
+            )
+            .finish()
+    }
+}
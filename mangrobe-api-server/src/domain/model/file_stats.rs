@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+// Upper bounds of the files-per-partition histogram. The last bucket is unbounded.
+pub const FILES_PER_PARTITION_BOUNDS: [u64; 11] = [1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024];
+
+#[derive(Clone, Debug)]
+pub struct FilesPerPartitionBucket {
+    // None for the last, unbounded bucket.
+    pub max_files: Option<u64>,
+    pub partition_count: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct FileStats {
+    pub stream_count: u64,
+    pub file_count: u64,
+    pub total_bytes: u64,
+    pub min_partition_time: Option<DateTime<Utc>>,
+    pub max_partition_time: Option<DateTime<Utc>>,
+    pub partition_count: u64,
+    pub last_committed_at: Option<DateTime<Utc>>,
+    pub files_per_partition: Vec<FilesPerPartitionBucket>,
+}
+
+impl FilesPerPartitionBucket {
+    // Builds every bucket from the number of partitions per bucket index.
+    // Index i counts partitions with at most FILES_PER_PARTITION_BOUNDS[i] files.
+    pub fn histogram(partition_counts: &HashMap<usize, u64>) -> Vec<Self> {
+        FILES_PER_PARTITION_BOUNDS
+            .iter()
+            .map(|max_files| Some(*max_files))
+            .chain([None])
+            .enumerate()
+            .map(|(i, max_files)| FilesPerPartitionBucket {
+                max_files,
+                partition_count: partition_counts.get(&i).copied().unwrap_or(0),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use xxhash_rust::xxh3::xxh3_64;
+
+pub const PARTITION_VALUE_LENGTH_LIMIT: usize = 256;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PartitionTransform {
+    Identity,
+    // xxh3_64 of the value modulo the number of buckets.
+    Bucket(u32),
+}
+
+#[derive(Clone, Debug)]
+pub struct PartitionField {
+    pub column: String,
+    pub transform: PartitionTransform,
+}
+
+impl PartitionField {
+    // Key of the transformed value in files. Bucket numbers are stored under "<column>_bucket",
+    // so that values read from files are never mistaken for column values and bucketed again.
+    pub fn key(&self) -> String {
+        match self.transform {
+            PartitionTransform::Identity => self.column.clone(),
+            PartitionTransform::Bucket(_) => format!("{}_bucket", self.column),
+        }
+    }
+
+    pub fn apply(&self, value: &str) -> String {
+        match self.transform {
+            PartitionTransform::Identity => value.to_string(),
+            PartitionTransform::Bucket(buckets) => {
+                (xxh3_64(value.as_bytes()) % buckets as u64).to_string()
+            }
+        }
+    }
+}
+
+// Partition keys besides partition_time, declared by the `partition.fields` table property
+// such as "region,metric_name:bucket(16)". Clients give column values and files store the
+// transformed values, e.g. {"region": "ap", "metric_name_bucket": "3"}. The stored values can be
+// given back as they are.
+#[derive(Clone, Debug, Default)]
+pub struct PartitionFields {
+    pub fields: Vec<PartitionField>,
+}
+
+impl FromStr for PartitionFields {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut fields: Vec<PartitionField> = vec![];
+        for field in value.split(',').map(|f| f.trim()) {
+            let (column, transform) = match field.split_once(':') {
+                Some((column, transform)) => (column.trim(), parse_transform(transform.trim())?),
+                None => (field, PartitionTransform::Identity),
+            };
+            if column.is_empty() {
+                return Err(format!("column must not be empty: {}", value));
+            }
+            if fields.iter().any(|f| f.column == column) {
+                return Err(format!("column is declared twice: {}", column));
+            }
+            fields.push(PartitionField {
+                column: column.to_string(),
+                transform,
+            });
+        }
+
+        for field in &fields {
+            let key = field.key();
+            if fields
+                .iter()
+                .any(|f| f.column != field.column && (f.key() == key || f.column == key))
+            {
+                return Err(format!("partition key is used by two columns: {}", key));
+            }
+        }
+
+        Ok(Self { fields })
+    }
+}
+
+fn parse_transform(transform: &str) -> Result<PartitionTransform, String> {
+    if transform == "identity" {
+        return Ok(PartitionTransform::Identity);
+    }
+
+    let buckets = transform
+        .strip_prefix("bucket(")
+        .and_then(|t| t.strip_suffix(')'))
+        .and_then(|n| n.trim().parse::<u32>().ok())
+        .filter(|n| *n > 0);
+    match buckets {
+        Some(buckets) => Ok(PartitionTransform::Bucket(buckets)),
+        None => Err(format!(
+            "transform must be identity or bucket(N) with a positive N: {}",
+            transform
+        )),
+    }
+}
+
+impl PartitionFields {
+    // Values of a file. Every declared column must be given, as a column value or as a stored value.
+    pub fn transform_values(
+        &self,
+        values: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>, String> {
+        for field in &self.fields {
+            if !values.contains_key(&field.column) && !values.contains_key(&field.key()) {
+                return Err(format!(
+                    "partition_values must contain the partition column '{}'",
+                    field.column
+                ));
+            }
+        }
+
+        self.transform_filter(values)
+    }
+
+    // Values of a filter. Any subset of the declared columns can be given.
+    pub fn transform_filter(
+        &self,
+        values: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>, String> {
+        let mut transformed: BTreeMap<String, String> = BTreeMap::new();
+        for (column, value) in values {
+            let (key, value) = if let Some(field) = self.fields.iter().find(|f| &f.column == column)
+            {
+                (field.key(), field.apply(value))
+            } else if let Some(field) = self.fields.iter().find(|f| &f.key() == column) {
+                (column.clone(), parse_stored(field, value)?)
+            } else {
+                return Err(format!(
+                    "'{}' is not a partition column of the table",
+                    column
+                ));
+            };
+            if transformed.get(&key).is_some_and(|v| v != &value) {
+                return Err(format!("'{}' is given with conflicting values", key));
+            }
+            transformed.insert(key, value);
+        }
+
+        Ok(transformed)
+    }
+}
+
+// A bucket number given back under "<column>_bucket".
+fn parse_stored(field: &PartitionField, value: &str) -> Result<String, String> {
+    let PartitionTransform::Bucket(buckets) = field.transform else {
+        return Ok(value.to_string());
+    };
+    match value.parse::<u32>() {
+        Ok(bucket) if bucket < buckets => Ok(bucket.to_string()),
+        _ => Err(format!(
+            "'{}' must be a bucket number less than {}: {}",
+            field.key(),
+            buckets,
+            value
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn fields(value: &str) -> PartitionFields {
+        PartitionFields::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn parse_fields() {
+        let parsed = fields("region, metric:bucket(16), host:identity");
+        let transforms: Vec<_> = parsed
+            .fields
+            .iter()
+            .map(|f| (f.column.as_str(), f.transform))
+            .collect();
+        assert_eq!(
+            transforms,
+            vec![
+                ("region", PartitionTransform::Identity),
+                ("metric", PartitionTransform::Bucket(16)),
+                ("host", PartitionTransform::Identity),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_fields() {
+        for value in [
+            "",
+            "region,",
+            "region,region",
+            "metric:bucket(0)",
+            "metric:bucket(-1)",
+            "metric:bucket(x)",
+            "metric:hash",
+        ] {
+            assert!(PartitionFields::from_str(value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn parse_rejects_a_bucket_key_colliding_with_a_column() {
+        assert!(PartitionFields::from_str("metric_bucket,metric:bucket(4)").is_err());
+        assert!(PartitionFields::from_str("metric:bucket(4),metric_bucket").is_err());
+        assert!(PartitionFields::from_str("metric_bucket:bucket(4),metric:bucket(4)").is_err());
+        assert!(PartitionFields::from_str("metric_bucket:bucket(4),metric").is_ok());
+    }
+
+    #[test]
+    fn transform_stores_bucket_numbers_under_the_bucket_key() {
+        let fields = fields("region,metric:bucket(8)");
+        let transformed = fields
+            .transform_values(&values(&[("region", "ap"), ("metric", "cpu")]))
+            .unwrap();
+
+        let bucket = (xxh3_64(b"cpu") % 8).to_string();
+        assert_eq!(
+            transformed,
+            values(&[("region", "ap"), ("metric_bucket", &bucket)])
+        );
+    }
+
+    #[test]
+    fn transform_is_idempotent_for_stored_values() {
+        let fields = fields("region,metric:bucket(8)");
+        let stored = fields
+            .transform_values(&values(&[("region", "ap"), ("metric", "cpu")]))
+            .unwrap();
+
+        assert_eq!(fields.transform_values(&stored).unwrap(), stored);
+        assert_eq!(fields.transform_filter(&stored).unwrap(), stored);
+    }
+
+    #[test]
+    fn transform_values_requires_every_field() {
+        let fields = fields("region,metric:bucket(8)");
+        assert!(
+            fields
+                .transform_values(&values(&[("region", "ap")]))
+                .is_err()
+        );
+        assert!(
+            fields
+                .transform_filter(&values(&[("region", "ap")]))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn transform_filter_rejects_invalid_values() {
+        let fields = fields("region,metric:bucket(8)");
+        for filter in [
+            values(&[("host", "a")]),
+            values(&[("region_bucket", "0")]),
+            values(&[("metric_bucket", "8")]),
+            values(&[("metric_bucket", "-1")]),
+            values(&[("metric_bucket", "x")]),
+        ] {
+            assert!(fields.transform_filter(&filter).is_err(), "{filter:?}");
+        }
+    }
+
+    #[test]
+    fn transform_filter_rejects_conflicting_values() {
+        let fields = fields("metric:bucket(8)");
+        let bucket = xxh3_64(b"cpu") % 8;
+        let other = ((bucket + 1) % 8).to_string();
+        let bucket = bucket.to_string();
+
+        assert!(
+            fields
+                .transform_filter(&values(&[("metric", "cpu"), ("metric_bucket", &bucket)]))
+                .is_ok()
+        );
+        assert!(
+            fields
+                .transform_filter(&values(&[("metric", "cpu"), ("metric_bucket", &other)]))
+                .is_err()
+        );
+    }
+}
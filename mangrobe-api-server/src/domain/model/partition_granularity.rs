@@ -0,0 +1,149 @@
+use chrono::{DateTime, Datelike, TimeZone, Timelike, Utc};
+use strum_macros::{Display, EnumString};
+
+// Declared by the `partition.granularity` table property.
+#[derive(Clone, Copy, Debug, Display, EnumString, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum PartitionGranularity {
+    Minute,
+    Hour,
+    Day,
+    Month,
+}
+
+impl PartitionGranularity {
+    // Start of the partition that contains `time`, in UTC.
+    pub fn truncate(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let (year, month, day) = (time.year(), time.month(), time.day());
+        let truncated = match self {
+            PartitionGranularity::Minute => {
+                Utc.with_ymd_and_hms(year, month, day, time.hour(), time.minute(), 0)
+            }
+            PartitionGranularity::Hour => Utc.with_ymd_and_hms(year, month, day, time.hour(), 0, 0),
+            PartitionGranularity::Day => Utc.with_ymd_and_hms(year, month, day, 0, 0, 0),
+            PartitionGranularity::Month => Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0),
+        };
+
+        truncated.single().unwrap_or(time)
+    }
+}
+
+// Declared by the `partition.alignment` table property. How misaligned partition_time is handled.
+#[derive(Clone, Copy, Debug, Default, Display, EnumString, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum PartitionAlignment {
+    #[default]
+    Reject,
+    Truncate,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PartitionSpec {
+    pub granularity: PartitionGranularity,
+    pub alignment: PartitionAlignment,
+}
+
+impl PartitionSpec {
+    pub fn align(&self, time: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+        let truncated = self.granularity.truncate(time);
+        if truncated == time {
+            return Ok(time);
+        }
+
+        match self.alignment {
+            PartitionAlignment::Reject => Err(format!(
+                "partition_time {} is not aligned to the partition granularity '{}' of the table",
+                time.to_rfc3339(),
+                self.granularity
+            )),
+            PartitionAlignment::Truncate => Ok(truncated),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value).unwrap().into()
+    }
+
+    #[test]
+    fn truncate_to_the_start_of_the_partition() {
+        let time = utc("2026-10-19T11:42:37.5Z");
+        assert_eq!(
+            PartitionGranularity::Minute.truncate(time),
+            utc("2026-10-19T11:42:00Z")
+        );
+        assert_eq!(
+            PartitionGranularity::Hour.truncate(time),
+            utc("2026-10-19T11:00:00Z")
+        );
+        assert_eq!(
+            PartitionGranularity::Day.truncate(time),
+            utc("2026-10-19T00:00:00Z")
+        );
+        assert_eq!(
+            PartitionGranularity::Month.truncate(time),
+            utc("2026-10-01T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn truncate_at_month_boundaries() {
+        let month = PartitionGranularity::Month;
+        assert_eq!(
+            month.truncate(utc("2026-01-31T23:59:59.999Z")),
+            utc("2026-01-01T00:00:00Z")
+        );
+        assert_eq!(
+            month.truncate(utc("2026-02-01T00:00:00Z")),
+            utc("2026-02-01T00:00:00Z")
+        );
+        assert_eq!(
+            month.truncate(utc("2028-02-29T12:00:00Z")),
+            utc("2028-02-01T00:00:00Z")
+        );
+        assert_eq!(
+            month.truncate(utc("2026-12-31T23:59:59Z")),
+            utc("2026-12-01T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn truncate_does_not_fall_back_at_the_range_limits() {
+        for granularity in [
+            PartitionGranularity::Minute,
+            PartitionGranularity::Hour,
+            PartitionGranularity::Day,
+            PartitionGranularity::Month,
+        ] {
+            let max = granularity.truncate(DateTime::<Utc>::MAX_UTC);
+            assert!(max < DateTime::<Utc>::MAX_UTC);
+            assert_eq!(granularity.truncate(max), max);
+
+            let min = granularity.truncate(DateTime::<Utc>::MIN_UTC);
+            assert_eq!(granularity.truncate(min), min);
+        }
+    }
+
+    #[test]
+    fn align_rejects_or_truncates_misaligned_times() {
+        let aligned = utc("2026-10-19T11:00:00Z");
+        let misaligned = aligned + Duration::nanoseconds(1);
+        let reject = PartitionSpec {
+            granularity: PartitionGranularity::Hour,
+            alignment: PartitionAlignment::Reject,
+        };
+        let truncate = PartitionSpec {
+            alignment: PartitionAlignment::Truncate,
+            ..reject
+        };
+
+        assert_eq!(reject.align(aligned), Ok(aligned));
+        assert!(reject.align(misaligned).is_err());
+        assert_eq!(truncate.align(misaligned), Ok(aligned));
+    }
+}
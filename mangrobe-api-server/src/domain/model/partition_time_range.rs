@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+
+// Inclusive range of partition times. None means unbounded on that side.
+#[derive(Clone, Debug, Default)]
+pub struct PartitionTimeRange {
+    pub min: Option<DateTime<Utc>>,
+    pub max: Option<DateTime<Utc>>,
+}
+
+impl PartitionTimeRange {
+    pub fn new(min: Option<DateTime<Utc>>, max: Option<DateTime<Utc>>) -> Self {
+        Self { min, max }
+    }
+}
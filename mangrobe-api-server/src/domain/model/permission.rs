@@ -0,0 +1,22 @@
+use strum_macros::Display;
+
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum Permission {
+    Read,
+    Write,
+    Compact,
+    Admin,
+}
+
+impl Permission {
+    // Admin implies everything. Write and Compact imply Read since both need to see files.
+    pub fn implies(&self, required: Permission) -> bool {
+        match self {
+            Permission::Admin => true,
+            Permission::Write | Permission::Compact => {
+                *self == required || required == Permission::Read
+            }
+            Permission::Read => required == Permission::Read,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::{Display, Formatter};
+
+const ANONYMOUS_ID: &str = "anonymous";
+const SYSTEM_ID: &str = "system";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PrincipalKind {
+    // Authentication is disabled.
+    Anonymous,
+    StaticToken,
+    Jwt,
+    ClientCertificate,
+    // Background jobs of the server itself, such as purging dropped tables.
+    System,
+}
+
+// Who sent the request.
+#[derive(Clone, Debug)]
+pub struct Principal {
+    pub id: String,
+    pub kind: PrincipalKind,
+    // Admins are allowed everything without grants.
+    pub is_admin: bool,
+}
+
+impl Principal {
+    pub fn new(id: String, kind: PrincipalKind) -> Self {
+        Self {
+            id,
+            kind,
+            is_admin: false,
+        }
+    }
+
+    pub fn with_admin(mut self, is_admin: bool) -> Self {
+        self.is_admin = is_admin;
+        self
+    }
+
+    pub fn anonymous() -> Self {
+        Self::new(ANONYMOUS_ID.into(), PrincipalKind::Anonymous)
+    }
+
+    pub fn system() -> Self {
+        Self::new(SYSTEM_ID.into(), PrincipalKind::System)
+    }
+
+    pub fn is_anonymous(&self) -> bool {
+        self.kind == PrincipalKind::Anonymous
+    }
+
+    // Anonymous principals exist only while authentication is disabled, so they are not restricted.
+    pub fn requires_authorization(&self) -> bool {
+        !self.is_anonymous() && !self.is_admin
+    }
+}
+
+impl PrincipalKind {
+    fn prefix(&self) -> &'static str {
+        match self {
+            PrincipalKind::Anonymous => "anonymous",
+            PrincipalKind::StaticToken => "token",
+            PrincipalKind::Jwt => "jwt",
+            PrincipalKind::ClientCertificate => "cert",
+            PrincipalKind::System => "system",
+        }
+    }
+}
+
+// Kinds that admin_principals and grants can name.
+const GRANTABLE_KINDS: [PrincipalKind; 3] = [
+    PrincipalKind::StaticToken,
+    PrincipalKind::Jwt,
+    PrincipalKind::ClientCertificate,
+];
+
+impl Principal {
+    // e.g. "jwt:alice". admin_principals and grants use this, so that a JWT subject or a
+    // certificate CN cannot take over a static token principal with the same id.
+    pub fn qualified_id(&self) -> String {
+        format!("{}:{}", self.kind.prefix(), self.id)
+    }
+}
+
+// Checks that the value is a qualified id of a grantable kind, such as "token:writer".
+pub fn validate_qualified_id(value: &str) -> Result<(), String> {
+    let valid = value.split_once(':').is_some_and(|(prefix, id)| {
+        !id.is_empty() && GRANTABLE_KINDS.iter().any(|k| k.prefix() == prefix)
+    });
+    if valid {
+        return Ok(());
+    }
+    Err(format!(
+        "must be <kind>:<id> with kind token, jwt or cert: {}",
+        value
+    ))
+}
+
+impl Display for Principal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.qualified_id())
+    }
+}
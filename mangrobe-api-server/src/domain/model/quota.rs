@@ -0,0 +1,110 @@
+use crate::domain::model::change_request_raw_file_entry::{
+    ChangeRequestRawAddFileEntry, ChangeRequestRawChangeFilesEntry,
+    ChangeRequestRawCompactFileInfoEntry,
+};
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_name::UserTableName;
+
+// None means unlimited.
+#[derive(Clone, Debug, Default)]
+pub struct QuotaLimits {
+    pub commits_per_second: Option<u32>,
+    pub files_per_commit: Option<u64>,
+    pub max_current_files: Option<u64>,
+    pub max_current_bytes: Option<u64>,
+}
+
+impl QuotaLimits {
+    // Fills unset limits from `fallback`.
+    pub fn or(&self, fallback: &QuotaLimits) -> QuotaLimits {
+        QuotaLimits {
+            commits_per_second: self.commits_per_second.or(fallback.commits_per_second),
+            files_per_commit: self.files_per_commit.or(fallback.files_per_commit),
+            max_current_files: self.max_current_files.or(fallback.max_current_files),
+            max_current_bytes: self.max_current_bytes.or(fallback.max_current_bytes),
+        }
+    }
+}
+
+// Limits for a table, or for a single stream when stream_id is set.
+#[derive(Clone, Debug)]
+pub struct QuotaOverride {
+    pub table_name: UserTableName,
+    pub stream_id: Option<StreamId>,
+    pub limits: QuotaLimits,
+}
+
+// Quotas are applied per stream. The most specific override wins for each limit.
+#[derive(Clone, Debug, Default)]
+pub struct QuotaPolicy {
+    pub default: QuotaLimits,
+    pub overrides: Vec<QuotaOverride>,
+}
+
+impl QuotaPolicy {
+    pub fn limits_for(&self, table_name: &UserTableName, stream_id: &StreamId) -> QuotaLimits {
+        let mut table_limits = QuotaLimits::default();
+        let mut stream_limits = QuotaLimits::default();
+        for o in self
+            .overrides
+            .iter()
+            .filter(|o| &o.table_name == table_name)
+        {
+            match &o.stream_id {
+                Some(id) if id == stream_id => stream_limits = o.limits.clone(),
+                Some(_) => {}
+                None => table_limits = o.limits.clone(),
+            }
+        }
+
+        stream_limits.or(&table_limits).or(&self.default)
+    }
+}
+
+// What a commit is going to add. Compared with the quotas before the commit starts.
+#[derive(Clone, Debug, Default)]
+pub struct CommitUsage {
+    // Number of files the request touches.
+    pub file_count: u64,
+    pub added_files: u64,
+    pub added_bytes: u64,
+}
+
+impl CommitUsage {
+    pub fn from_add_entries(entries: &[ChangeRequestRawAddFileEntry]) -> Self {
+        let files = entries.iter().flat_map(|e| e.files_to_add.iter());
+        let added_files = files.clone().count() as u64;
+        Self {
+            file_count: added_files,
+            added_files,
+            added_bytes: files.map(|f| f.size.max(0) as u64).sum(),
+        }
+    }
+
+    pub fn from_change_entries(entries: &[ChangeRequestRawChangeFilesEntry]) -> Self {
+        Self {
+            file_count: entries.iter().map(|e| e.files_to_delete.len() as u64).sum(),
+            ..Default::default()
+        }
+    }
+
+    // A split into more destinations than sources adds current files. The sizes of the sources
+    // are not known here, so every destination byte is counted as added.
+    pub fn from_compact_entries(entries: &[ChangeRequestRawCompactFileInfoEntry]) -> Self {
+        Self {
+            file_count: entries
+                .iter()
+                .map(|e| (e.src_files.len() + e.dst_files.len()) as u64)
+                .sum(),
+            added_files: entries
+                .iter()
+                .map(|e| e.dst_files.len().saturating_sub(e.src_files.len()) as u64)
+                .sum(),
+            added_bytes: entries
+                .iter()
+                .flat_map(|e| e.dst_files.iter())
+                .map(|f| f.file.size.max(0) as u64)
+                .sum(),
+        }
+    }
+}
@@ -1,7 +1,52 @@
 use crate::domain::model::commit_id::CommitId;
 use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::stream_id_range::StreamIdRange;
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+use strum_macros::Display;
+
+pub const TENANT_NAME_LENGTH_LIMIT: usize = 256;
+pub const LABEL_LENGTH_LIMIT: usize = 256;
+
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum StreamState {
+    Active,
+    // Rejects AddFiles. Compaction and ChangeFiles are still allowed.
+    Sealed,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct StreamMetadata {
+    pub tenant_name: Option<String>,
+    pub labels: BTreeMap<String, String>,
+}
 
 pub struct Stream {
     pub id: StreamId,
-    pub last_commit_id: CommitId,
+    pub metadata: StreamMetadata,
+    pub state: StreamState,
+    pub sealed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    // Updated by every commit, registration and seal.
+    pub updated_at: DateTime<Utc>,
+    // None while nothing has been committed to the stream.
+    pub last_commit_id: Option<CommitId>,
+    pub last_committed_at: Option<DateTime<Utc>>,
+    // Summary of the current files.
+    pub file_count: u64,
+    pub total_bytes: u64,
+}
+
+impl Stream {
+    pub fn is_sealed(&self) -> bool {
+        self.state == StreamState::Sealed
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct StreamFilter {
+    pub updated_since: Option<DateTime<Utc>>,
+    pub stream_id_range: StreamIdRange,
+    // Matches streams having all of these labels with the same values.
+    pub label_selector: BTreeMap<String, String>,
 }
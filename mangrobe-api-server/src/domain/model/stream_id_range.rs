@@ -0,0 +1,31 @@
+use crate::domain::model::stream_id::StreamId;
+
+// Inclusive range of stream ids. None means unbounded on that side.
+#[derive(Clone, Debug, Default)]
+pub struct StreamIdRange {
+    pub min: Option<StreamId>,
+    pub max: Option<StreamId>,
+}
+
+impl StreamIdRange {
+    pub fn new(min: Option<StreamId>, max: Option<StreamId>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn is_unbounded(&self) -> bool {
+        self.min.is_none() && self.max.is_none()
+    }
+
+    pub fn contains(&self, stream_id: &StreamId) -> bool {
+        let above_min = self
+            .min
+            .as_ref()
+            .is_none_or(|min| min.val() <= stream_id.val());
+        let below_max = self
+            .max
+            .as_ref()
+            .is_none_or(|max| stream_id.val() <= max.val());
+
+        above_min && below_max
+    }
+}
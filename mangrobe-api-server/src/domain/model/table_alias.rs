@@ -0,0 +1,9 @@
+use crate::domain::model::user_table_id::UserTableId;
+use crate::domain::model::user_table_name::UserTableName;
+
+// Another name of a table. Aliases and table names share one namespace.
+#[derive(Clone, Debug)]
+pub struct TableAlias {
+    pub name: UserTableName,
+    pub user_table_id: UserTableId,
+}
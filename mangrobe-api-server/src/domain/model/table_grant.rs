@@ -0,0 +1,29 @@
+use crate::domain::model::permission::Permission;
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::stream_id_range::StreamIdRange;
+use crate::domain::model::table_grant_id::TableGrantId;
+use crate::domain::model::user_table_name::UserTableName;
+
+// Permission of a principal on a table. `table_name` None means every table.
+#[derive(Clone, Debug)]
+pub struct TableGrant {
+    pub id: TableGrantId,
+    pub principal_id: String,
+    pub table_name: Option<UserTableName>,
+    pub permission: Permission,
+    pub stream_id_range: StreamIdRange,
+}
+
+impl TableGrant {
+    // stream_id None means the operation is on the whole table, so only unbounded grants allow it.
+    pub fn allows(&self, permission: Permission, stream_id: Option<&StreamId>) -> bool {
+        if !self.permission.implies(permission) {
+            return false;
+        }
+
+        match stream_id {
+            Some(stream_id) => self.stream_id_range.contains(stream_id),
+            None => self.stream_id_range.is_unbounded(),
+        }
+    }
+}
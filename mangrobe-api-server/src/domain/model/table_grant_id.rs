@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, Hash, PartialEq)]
+#[serde(transparent)]
+pub struct TableGrantId(i64);
+
+impl From<TableGrantId> for i64 {
+    fn from(id: TableGrantId) -> Self {
+        id.0
+    }
+}
+
+impl From<i64> for TableGrantId {
+    fn from(id: i64) -> Self {
+        Self(id)
+    }
+}
+
+impl TableGrantId {
+    pub fn val(&self) -> i64 {
+        self.0
+    }
+}
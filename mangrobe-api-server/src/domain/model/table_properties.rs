@@ -0,0 +1,103 @@
+use crate::domain::model::partition_field::PartitionFields;
+use crate::domain::model::partition_granularity::{
+    PartitionAlignment, PartitionGranularity, PartitionSpec,
+};
+use chrono::Duration;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+pub const PROPERTY_LENGTH_LIMIT: usize = 256;
+// About 100 years. Keeps durations and the times computed from them in range.
+pub const DURATION_SEC_LIMIT: i64 = 100 * 366 * 24 * 60 * 60;
+
+// Keys recognized by the server. Other keys are stored as is.
+pub const LOCK_DEFAULT_TTL_SEC: &str = "lock.default_ttl_sec";
+pub const ADD_FILES_MAX_FILES: &str = "add_files.max_files";
+pub const PARTITION_GRANULARITY: &str = "partition.granularity";
+pub const PARTITION_ALIGNMENT: &str = "partition.alignment";
+pub const PARTITION_FIELDS: &str = "partition.fields";
+pub const RETENTION_SEC: &str = "retention.sec";
+
+#[derive(Clone, Debug, Default)]
+pub struct TableProperties {
+    pub values: BTreeMap<String, String>,
+}
+
+impl TableProperties {
+    pub fn new(values: BTreeMap<String, String>) -> Self {
+        Self { values }
+    }
+
+    // Values of recognized keys are validated when they are set, so invalid ones are ignored here.
+    fn parse<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.values.get(key).and_then(|v| v.parse().ok())
+    }
+
+    // Used when AcquireFileLock does not specify ttl_sec.
+    pub fn default_lock_ttl(&self) -> Option<Duration> {
+        self.parse::<i64>(LOCK_DEFAULT_TTL_SEC)
+            .and_then(Duration::try_seconds)
+    }
+
+    pub fn max_files_per_add(&self) -> Option<u64> {
+        self.parse(ADD_FILES_MAX_FILES)
+    }
+
+    // None while the table does not declare partition.granularity.
+    pub fn partition_spec(&self) -> Option<PartitionSpec> {
+        Some(PartitionSpec {
+            granularity: self.parse(PARTITION_GRANULARITY)?,
+            alignment: self.parse(PARTITION_ALIGNMENT).unwrap_or_default(),
+        })
+    }
+
+    // Empty while the table does not declare partition.fields.
+    pub fn partition_fields(&self) -> PartitionFields {
+        self.parse(PARTITION_FIELDS).unwrap_or_default()
+    }
+
+    // AddFiles rejects partitions older than the retention.
+    pub fn retention(&self) -> Option<Duration> {
+        self.parse::<i64>(RETENTION_SEC)
+            .and_then(Duration::try_seconds)
+    }
+
+    pub fn apply(&mut self, set: &BTreeMap<String, String>, remove: &[String]) {
+        for key in remove {
+            self.values.remove(key);
+        }
+        for (key, value) in set {
+            self.values.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+// Checks the value of a recognized key. Other keys accept any value.
+pub fn validate_property(key: &str, value: &str) -> Result<(), String> {
+    match key {
+        ADD_FILES_MAX_FILES => match value.parse::<i64>() {
+            Ok(v) if v > 0 => Ok(()),
+            _ => Err(format!("must be a positive integer: {}", value)),
+        },
+        LOCK_DEFAULT_TTL_SEC | RETENTION_SEC => match value.parse::<i64>() {
+            Ok(v) if v > 0 && v <= DURATION_SEC_LIMIT => Ok(()),
+            _ => Err(format!(
+                "must be a positive integer up to {}: {}",
+                DURATION_SEC_LIMIT, value
+            )),
+        },
+        PARTITION_GRANULARITY => match PartitionGranularity::from_str(value) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(format!(
+                "must be one of minute, hour, day or month: {}",
+                value
+            )),
+        },
+        PARTITION_ALIGNMENT => match PartitionAlignment::from_str(value) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(format!("must be reject or truncate: {}", value)),
+        },
+        PARTITION_FIELDS => PartitionFields::from_str(value).map(|_| ()),
+        _ => Ok(()),
+    }
+}
@@ -1,13 +1,22 @@
+use crate::domain::model::table_properties::TableProperties;
 use crate::domain::model::user_table_id::UserTableId;
 use crate::domain::model::user_table_name::UserTableName;
+use chrono::{DateTime, Utc};
 
 pub struct UserTable {
     pub id: UserTableId,
     pub name: UserTableName,
+    pub created_at: DateTime<Utc>,
+    // Set while the table is dropped. Its data is purged after purge_after.
+    pub dropped_at: Option<DateTime<Utc>>,
+    pub purge_after: Option<DateTime<Utc>>,
+    // Other names that resolve to this table. Ordered by name.
+    pub aliases: Vec<UserTableName>,
+    pub properties: TableProperties,
 }
 
 impl UserTable {
-    pub fn new(id: UserTableId, name: UserTableName) -> Self {
-        Self { id, name }
+    pub fn is_dropped(&self) -> bool {
+        self.dropped_at.is_some()
     }
 }
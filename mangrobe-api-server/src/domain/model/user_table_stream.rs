@@ -1,7 +1,7 @@
 use crate::domain::model::stream_id::StreamId;
 use crate::domain::model::user_table_id::UserTableId;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct UserTablStream {
     pub user_table_id: UserTableId,
     pub stream_id: StreamId,
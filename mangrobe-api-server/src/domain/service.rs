@@ -1,7 +1,11 @@
+pub mod audit_service;
+pub mod authorization_service;
 pub mod change_request_service;
 pub mod committed_change_request_service;
+pub mod compaction_plan_service;
 pub mod file_lock_key_service;
 pub mod file_service;
+pub mod quota_service;
 pub mod snapshot_service;
 pub mod stream_service;
 pub mod user_table_service;
@@ -0,0 +1,67 @@
+use crate::domain::model::audit_log::{AuditEvent, AuditLog, AuditLogFilter, AuditOutcome};
+use crate::domain::model::audit_log_id::AuditLogId;
+use crate::infrastructure::db::repository::audit_log_repository::AuditLogRepository;
+use crate::util::error::AuthError;
+use sea_orm::DatabaseConnection;
+use tracing::error;
+
+const ERROR_MESSAGE_LENGTH_LIMIT: usize = 1024;
+
+pub struct AuditService {
+    connection: DatabaseConnection,
+    audit_log_repository: AuditLogRepository,
+}
+
+impl AuditService {
+    pub fn new(connection: &DatabaseConnection) -> Self {
+        Self {
+            connection: connection.clone(),
+            audit_log_repository: AuditLogRepository::new(),
+        }
+    }
+
+    // Records the result of an operation that has already finished.
+    // Failing to record is logged instead of returned, since the operation itself cannot be undone.
+    pub async fn record<T>(&self, event: &AuditEvent, result: &Result<T, anyhow::Error>) {
+        let (outcome, error_message) = match result {
+            Ok(_) => (AuditOutcome::Succeeded, None),
+            Err(e) => {
+                let outcome = match e.downcast_ref::<AuthError>() {
+                    Some(AuthError::PermissionDenied(_)) => AuditOutcome::Denied,
+                    _ => AuditOutcome::Failed,
+                };
+                let message = e
+                    .to_string()
+                    .chars()
+                    .take(ERROR_MESSAGE_LENGTH_LIMIT)
+                    .collect();
+                (outcome, Some(message))
+            }
+        };
+
+        if let Err(e) = self
+            .audit_log_repository
+            .insert(&self.connection, event, outcome, error_message)
+            .await
+        {
+            error!(
+                error = ?e,
+                operation = %event.operation,
+                principal_id = %event.principal_id,
+                %outcome,
+                "failed to record audit log"
+            );
+        }
+    }
+
+    pub async fn find_all(
+        &self,
+        filter: &AuditLogFilter,
+        id_before: Option<&AuditLogId>,
+        limit: u64,
+    ) -> Result<Vec<AuditLog>, anyhow::Error> {
+        self.audit_log_repository
+            .find_all(&self.connection, filter, id_before, limit)
+            .await
+    }
+}
@@ -0,0 +1,184 @@
+use crate::domain::model::permission::Permission;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::stream_id_range::StreamIdRange;
+use crate::domain::model::table_grant::TableGrant;
+use crate::domain::model::table_grant_id::TableGrantId;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::infrastructure::db::repository::table_grant_repository::TableGrantRepository;
+use crate::infrastructure::db::repository::user_table_repository::UserTableRepository;
+use crate::util::error::AuthError;
+use anyhow::bail;
+use sea_orm::DatabaseConnection;
+
+pub struct AuthorizationService {
+    connection: DatabaseConnection,
+    table_grant_repository: TableGrantRepository,
+    user_table_repository: UserTableRepository,
+}
+
+impl AuthorizationService {
+    pub fn new(connection: &DatabaseConnection) -> Self {
+        Self {
+            connection: connection.clone(),
+            table_grant_repository: TableGrantRepository::new(),
+            user_table_repository: UserTableRepository::new(),
+        }
+    }
+
+    // Checks only the grants, so that the existence of the table is not leaked to the principal.
+    pub async fn authorize(
+        &self,
+        principal: &Principal,
+        table_name: &UserTableName,
+        stream_id: Option<&StreamId>,
+        permission: Permission,
+    ) -> Result<(), anyhow::Error> {
+        self.authorize_any(principal, table_name, stream_id, &[permission])
+            .await
+    }
+
+    // Grants are on table names, so aliases are checked with the grants of their table.
+    pub async fn authorize_any(
+        &self,
+        principal: &Principal,
+        table_name: &UserTableName,
+        stream_id: Option<&StreamId>,
+        permissions: &[Permission],
+    ) -> Result<(), anyhow::Error> {
+        if !principal.requires_authorization() {
+            return Ok(());
+        }
+
+        let aliased = self
+            .user_table_repository
+            .find_by_alias(&self.connection, table_name)
+            .await?;
+        let granted_name = aliased.as_ref().map_or(table_name, |t| &t.name);
+
+        let grants = self
+            .table_grant_repository
+            .find_all_by_principal_and_table(
+                &self.connection,
+                &principal.qualified_id(),
+                granted_name,
+            )
+            .await?;
+        if is_allowed(&grants, permissions, stream_id) {
+            return Ok(());
+        }
+
+        bail!(AuthError::PermissionDenied(format!(
+            "principal={}, table_name={}, stream_id={}, permission={}",
+            principal,
+            table_name.val(),
+            stream_id.map_or("*".to_string(), |s| s.val().to_string()),
+            describe_permissions(permissions),
+        )))
+    }
+
+    // Authorizes operations over every table, such as managing grants on every table.
+    pub async fn authorize_global(
+        &self,
+        principal: &Principal,
+        permission: Permission,
+    ) -> Result<(), anyhow::Error> {
+        if !principal.requires_authorization() {
+            return Ok(());
+        }
+
+        let grants = self
+            .table_grant_repository
+            .find_all_global_by_principal(&self.connection, &principal.qualified_id())
+            .await?;
+        if is_allowed(&grants, &[permission], None) {
+            return Ok(());
+        }
+
+        bail!(AuthError::PermissionDenied(format!(
+            "principal={}, table_name=*, permission={}",
+            principal, permission
+        )))
+    }
+
+    // Tables on which the principal has any grant. None means every table.
+    pub async fn find_visible_table_names(
+        &self,
+        principal: &Principal,
+    ) -> Result<Option<Vec<UserTableName>>, anyhow::Error> {
+        if !principal.requires_authorization() {
+            return Ok(None);
+        }
+
+        let grants = self
+            .table_grant_repository
+            .find_all(&self.connection, Some(&principal.qualified_id()), None)
+            .await?;
+        if grants.iter().any(|g| g.table_name.is_none()) {
+            return Ok(None);
+        }
+
+        let mut names: Vec<_> = grants.into_iter().filter_map(|g| g.table_name).collect();
+        names.sort_by_key(|n| n.val());
+        names.dedup_by_key(|n| n.val());
+        Ok(Some(names))
+    }
+
+    pub async fn grant(
+        &self,
+        principal_id: &str,
+        table_name: Option<&UserTableName>,
+        permission: Permission,
+        stream_id_range: &StreamIdRange,
+    ) -> Result<TableGrant, anyhow::Error> {
+        self.table_grant_repository
+            .insert(
+                &self.connection,
+                principal_id,
+                table_name,
+                permission,
+                stream_id_range,
+            )
+            .await
+    }
+
+    pub async fn find_grant(&self, id: &TableGrantId) -> Result<Option<TableGrant>, anyhow::Error> {
+        self.table_grant_repository
+            .find_by_id(&self.connection, id)
+            .await
+    }
+
+    pub async fn revoke(&self, id: &TableGrantId) -> Result<bool, anyhow::Error> {
+        self.table_grant_repository
+            .delete(&self.connection, id)
+            .await
+    }
+
+    pub async fn list_grants(
+        &self,
+        principal_id: Option<&str>,
+        table_name: Option<&UserTableName>,
+    ) -> Result<Vec<TableGrant>, anyhow::Error> {
+        self.table_grant_repository
+            .find_all(&self.connection, principal_id, table_name)
+            .await
+    }
+}
+
+fn is_allowed(
+    grants: &[TableGrant],
+    permissions: &[Permission],
+    stream_id: Option<&StreamId>,
+) -> bool {
+    grants
+        .iter()
+        .any(|g| permissions.iter().any(|p| g.allows(*p, stream_id)))
+}
+
+fn describe_permissions(permissions: &[Permission]) -> String {
+    permissions
+        .iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>()
+        .join("|")
+}
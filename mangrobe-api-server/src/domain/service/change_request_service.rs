@@ -10,7 +10,7 @@ use crate::domain::model::change_request_file_entry::{
 };
 use crate::domain::model::change_request_raw_file_entry::{
     ChangeRequestRawAddFileEntry, ChangeRequestRawChangeFilesEntry,
-    ChangeRequestRawCompactFilesEntry,
+    ChangeRequestRawCompactFileInfoEntry, PartitionedFilePath,
 };
 use crate::domain::model::changeset::Changeset;
 use crate::domain::model::commit_id::CommitId;
@@ -27,11 +27,13 @@ use crate::infrastructure::db::repository::file_column_statistics_repository::Fi
 use crate::infrastructure::db::repository::file_lock_repository::FileLockRepository;
 use crate::infrastructure::db::repository::file_metadata_repository::FileMetadataRepository;
 use crate::infrastructure::db::repository::file_repository::FileRepository;
+use crate::infrastructure::db::repository::stream_repository::StreamRepository;
 use crate::util::error::MangrobeError::UnexpectedState;
-use crate::util::error::{MangrobeError, UserError};
+use crate::util::error::{CommitError, LockError, MangrobeError};
 use anyhow::bail;
 use sea_orm::sqlx::types::chrono::{DateTime, Utc};
 use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use std::collections::BTreeMap;
 
 pub struct ChangeRequestService {
     connection: DatabaseConnection,
@@ -43,6 +45,7 @@ pub struct ChangeRequestService {
     file_column_statistics_repository: FileColumnStatisticsRepository,
     file_metadata_repository: FileMetadataRepository,
     current_file_repository: CurrentFileRepository,
+    stream_repository: StreamRepository,
 }
 
 impl ChangeRequestService {
@@ -57,9 +60,19 @@ impl ChangeRequestService {
             file_column_statistics_repository: FileColumnStatisticsRepository::new(),
             file_metadata_repository: FileMetadataRepository::new(),
             current_file_repository: CurrentFileRepository::new(),
+            stream_repository: StreamRepository::new(),
         }
     }
 
+    pub async fn find(
+        &self,
+        idempotency_key: &IdempotencyKey,
+    ) -> Result<Option<ChangeRequest>, anyhow::Error> {
+        self.change_request_repository
+            .find_by_idempotency_key(&self.connection, idempotency_key)
+            .await
+    }
+
     pub async fn find_or_create(
         &self,
         idempotency_key: &IdempotencyKey,
@@ -281,7 +294,7 @@ impl ChangeRequestService {
     pub async fn apply_compaction_entry(
         &self,
         change_request: &ChangeRequest,
-        entries: &[ChangeRequestRawCompactFilesEntry],
+        entries: &[ChangeRequestRawCompactFileInfoEntry],
     ) -> Result<ChangeRequestForCompact, anyhow::Error> {
         let txn = self.connection.begin().await?;
 
@@ -306,32 +319,35 @@ impl ChangeRequestService {
             }
         }
 
+        // Every source must exist in its own partition, otherwise nothing is applied.
         let mut compact_entries = vec![];
         for entry in entries {
-            for info_entry in entry.info_entries.iter() {
-                // TODO: accelerate by batch
-                let src_file_ids = self
-                    .find_file_ids(
-                        &txn,
-                        change_request,
-                        entry.partition_time,
-                        &info_entry.src_file_paths,
-                    )
-                    .await?;
+            // TODO: accelerate by batch
+            let src_file_ids = self
+                .find_partitioned_file_ids(&txn, change_request, &entry.src_files)
+                .await?;
+            if src_file_ids.len() != entry.src_files.len() {
+                bail!(LockError::FileNotFound(
+                    "some source files are not in their partitions".into()
+                ))
+            }
 
-                let dst_file_id = self
-                    .insert_file(
+            let mut dst_file_ids = Vec::with_capacity(entry.dst_files.len());
+            for dst_file in &entry.dst_files {
+                dst_file_ids.push(
+                    self.insert_file(
                         &txn,
                         change_request,
-                        entry.partition_time,
-                        &info_entry.dst_file,
+                        dst_file.partition_time,
+                        &dst_file.file,
                     )
-                    .await?;
-                compact_entries.push(ChangeRequestCompactFileEntry {
-                    src_file_ids,
-                    dst_file_id,
-                })
+                    .await?,
+                );
             }
+            compact_entries.push(ChangeRequestCompactFileEntry::new(
+                src_file_ids,
+                dst_file_ids,
+            ))
         }
 
         let mut compaction_request = self
@@ -426,6 +442,30 @@ impl ChangeRequestService {
             .await
     }
 
+    async fn find_partitioned_file_ids(
+        &self,
+        txn: &DatabaseTransaction,
+        change_request: &ChangeRequest,
+        files: &[PartitionedFilePath],
+    ) -> Result<Vec<FileId>, anyhow::Error> {
+        let mut paths_by_partition: BTreeMap<DateTime<Utc>, Vec<FilePath>> = BTreeMap::new();
+        for file in files {
+            paths_by_partition
+                .entry(file.partition_time)
+                .or_default()
+                .push(file.path.clone());
+        }
+
+        let mut file_ids = vec![];
+        for (partition_time, paths) in paths_by_partition {
+            file_ids.extend(
+                self.find_file_ids(txn, change_request, partition_time, &paths)
+                    .await?,
+            );
+        }
+        Ok(file_ids)
+    }
+
     async fn update_file_entry_as_compaction(
         &self,
         txn: &DatabaseTransaction,
@@ -444,6 +484,7 @@ impl ChangeRequestService {
     pub async fn commit_add_only_change_request(
         &self,
         change_request: &mut ChangeRequestForAdd,
+        expected_commit_id: Option<&CommitId>,
     ) -> Result<CommitId, anyhow::Error> {
         let txn = self.connection.begin().await?;
 
@@ -466,7 +507,9 @@ impl ChangeRequestService {
             ));
         }
 
-        let commit_id = self.commit_add_only_change(&txn, change_request).await?;
+        let commit_id = self
+            .commit_add_only_change(&txn, change_request, expected_commit_id)
+            .await?;
 
         self.change_request_repository
             .update_status(&txn, change_request, ChangeRequestStatus::Committed)
@@ -481,11 +524,26 @@ impl ChangeRequestService {
         &self,
         txn: &DatabaseTransaction,
         change_request: &ChangeRequestForAdd,
+        expected_commit_id: Option<&CommitId>,
     ) -> Result<CommitId, anyhow::Error> {
         self.commit_lock_repository
             .acquire_xact_lock(txn, &change_request.base.stream)
             .await?;
 
+        let stream = self
+            .stream_repository
+            .find_or_create(txn, &change_request.base.stream)
+            .await?;
+        if stream.is_sealed() {
+            bail!(CommitError::StreamSealed(format!(
+                "stream_id={}",
+                stream.id.val()
+            )));
+        }
+
+        self.verify_latest_commit(txn, &change_request.base.stream, expected_commit_id)
+            .await?;
+
         self.current_file_repository
             .insert_many(
                 txn,
@@ -498,6 +556,9 @@ impl ChangeRequestService {
             .commit_repository
             .insert(txn, &change_request.base.stream, &change_request.base.id)
             .await?;
+        self.stream_repository
+            .update_last_commit(txn, &change_request.base.stream, &commit_id)
+            .await?;
 
         Ok(commit_id)
     }
@@ -507,6 +568,7 @@ impl ChangeRequestService {
         file_lock_key: &FileLockKey,
         base_change_request: &mut BaseChangeRequest,
         changeset: &Changeset,
+        expected_commit_id: Option<&CommitId>,
     ) -> Result<CommitId, anyhow::Error> {
         let txn = self.connection.begin().await?;
 
@@ -530,7 +592,13 @@ impl ChangeRequestService {
         }
 
         let commit_id = self
-            .commit_changeset(&txn, file_lock_key, base_change_request, changeset)
+            .commit_changeset(
+                &txn,
+                file_lock_key,
+                base_change_request,
+                changeset,
+                expected_commit_id,
+            )
             .await?;
 
         self.change_request_repository
@@ -548,17 +616,28 @@ impl ChangeRequestService {
         file_lock_key: &FileLockKey,
         base_change_request: &BaseChangeRequest,
         changeset: &Changeset,
+        expected_commit_id: Option<&CommitId>,
     ) -> Result<CommitId, anyhow::Error> {
         self.commit_lock_repository
             .acquire_xact_lock(txn, &base_change_request.stream)
             .await?;
 
+        // Sealed streams still accept changes that do not add new data, such as compaction.
+        self.stream_repository
+            .find_or_create(txn, &base_change_request.stream)
+            .await?;
+
+        self.verify_latest_commit(txn, &base_change_request.stream, expected_commit_id)
+            .await?;
+
         let lock_exists = self
             .file_lock_repository
             .exists(&self.connection, file_lock_key)
             .await?;
         if !lock_exists {
-            bail!(UserError::InvalidLockMessage("not found".into()));
+            bail!(LockError::NotAcquired(
+                "the key is unknown or has expired".into()
+            ));
         }
 
         if !changeset.add_file_ids.is_empty() {
@@ -579,8 +658,8 @@ impl ChangeRequestService {
                 .await?;
 
             if !FileId::has_same(&changeset.delete_file_ids, &locked_file_ids) {
-                bail!(UserError::InvalidLockMessage(
-                    "not locked file found".into()
+                bail!(LockError::FileNotLocked(
+                    "some files to delete are not locked by the key".into()
                 ));
             }
 
@@ -593,6 +672,9 @@ impl ChangeRequestService {
             .commit_repository
             .insert(txn, &base_change_request.stream, &base_change_request.id)
             .await?;
+        self.stream_repository
+            .update_last_commit(txn, &base_change_request.stream, &commit_id)
+            .await?;
 
         self.current_file_repository
             .release_lock(txn, file_lock_key)
@@ -608,4 +690,30 @@ impl ChangeRequestService {
 
         Ok(commit_id)
     }
+
+    // Must be called while holding the commit lock so that the latest commit cannot move until the transaction ends.
+    async fn verify_latest_commit(
+        &self,
+        txn: &DatabaseTransaction,
+        stream: &UserTablStream,
+        expected_commit_id: Option<&CommitId>,
+    ) -> Result<(), anyhow::Error> {
+        let Some(expected_commit_id) = expected_commit_id else {
+            return Ok(());
+        };
+
+        let latest_commit_id = self
+            .commit_repository
+            .find_latest(txn, stream)
+            .await?
+            .map_or(0, |commit| commit.id.val());
+        if latest_commit_id != expected_commit_id.val() {
+            bail!(CommitError::HeadMoved(format!(
+                "expected_commit_id={}, latest_commit_id={}",
+                expected_commit_id, latest_commit_id
+            )));
+        }
+
+        Ok(())
+    }
 }
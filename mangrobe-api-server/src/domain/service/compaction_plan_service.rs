@@ -0,0 +1,135 @@
+use crate::domain::model::compaction_plan::{CompactionGroup, CompactionPolicy};
+use crate::domain::model::file_lock_key::FileLockKey;
+use crate::domain::model::file_lock_owner::FileLockOwner;
+use crate::domain::model::partition_time_range::PartitionTimeRange;
+use crate::domain::model::user_table_stream::UserTablStream;
+use crate::domain::service::file_lock_key_service::FileLockService;
+use crate::infrastructure::db::repository::current_file_repository::CurrentFileRepository;
+use chrono::Duration;
+use sea_orm::{ConnectionTrait, DatabaseConnection, TransactionTrait};
+
+// Partition times fetched at once while planning.
+const PARTITION_TIME_BATCH_SIZE: u64 = 100;
+
+pub struct CompactionPlanService {
+    connection: DatabaseConnection,
+    current_file_repository: CurrentFileRepository,
+    file_lock_service: FileLockService,
+}
+
+impl CompactionPlanService {
+    pub fn new(connection: &DatabaseConnection) -> Self {
+        Self {
+            connection: connection.clone(),
+            current_file_repository: CurrentFileRepository::new(),
+            file_lock_service: FileLockService::new(connection),
+        }
+    }
+
+    pub async fn plan(
+        &self,
+        stream: &UserTablStream,
+        policy: &CompactionPolicy,
+        partition_time_range: &PartitionTimeRange,
+    ) -> Result<Vec<CompactionGroup>, anyhow::Error> {
+        self.find_groups(
+            &self.connection,
+            stream,
+            policy,
+            partition_time_range,
+            false,
+        )
+        .await
+    }
+
+    // Plans and locks the planned files with the key in one transaction. Nothing is locked when
+    // there is nothing to compact.
+    pub async fn plan_and_lock(
+        &self,
+        file_lock_key: &FileLockKey,
+        stream: &UserTablStream,
+        ttl: Duration,
+        owner: &FileLockOwner,
+        policy: &CompactionPolicy,
+        partition_time_range: &PartitionTimeRange,
+    ) -> Result<Vec<CompactionGroup>, anyhow::Error> {
+        let txn = self.connection.begin().await?;
+
+        self.file_lock_service
+            .create_lock(&txn, file_lock_key, stream, ttl, owner)
+            .await?;
+
+        let groups = self
+            .find_groups(&txn, stream, policy, partition_time_range, true)
+            .await?;
+        if groups.is_empty() {
+            txn.rollback().await?;
+            return Ok(groups);
+        }
+
+        let file_ids: Vec<_> = groups
+            .iter()
+            .flat_map(|g| g.files.iter().map(|f| f.id.clone()))
+            .collect();
+        self.file_lock_service
+            .lock_files(&txn, file_lock_key, stream, &file_ids)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(groups)
+    }
+
+    // Plans partition by partition and stops once max_groups is filled, so that the files of
+    // later partitions are neither loaded nor locked.
+    async fn find_groups<C>(
+        &self,
+        conn: &C,
+        stream: &UserTablStream,
+        policy: &CompactionPolicy,
+        partition_time_range: &PartitionTimeRange,
+        for_update: bool,
+    ) -> Result<Vec<CompactionGroup>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let mut groups = vec![];
+        let mut after = None;
+        loop {
+            let partition_times = self
+                .current_file_repository
+                .find_partition_times_with_unlocked_files_smaller_than(
+                    conn,
+                    stream,
+                    policy.target_file_size,
+                    partition_time_range,
+                    after,
+                    PARTITION_TIME_BATCH_SIZE,
+                )
+                .await?;
+
+            for partition_time in &partition_times {
+                let files = self
+                    .current_file_repository
+                    .find_unlocked_files_smaller_than(
+                        conn,
+                        stream,
+                        policy.target_file_size,
+                        &PartitionTimeRange::new(Some(*partition_time), Some(*partition_time)),
+                        for_update,
+                    )
+                    .await?;
+                let remaining = policy.max_groups - groups.len();
+                groups.extend(policy.plan(files).into_iter().take(remaining));
+                if groups.len() >= policy.max_groups {
+                    return Ok(groups);
+                }
+            }
+
+            if (partition_times.len() as u64) < PARTITION_TIME_BATCH_SIZE {
+                return Ok(groups);
+            }
+            after = partition_times.last().copied();
+        }
+    }
+}
@@ -1,14 +1,18 @@
 use crate::domain::model::file::FileWithId;
+use crate::domain::model::file_id::FileId;
+use crate::domain::model::file_lock::FileLock;
 use crate::domain::model::file_lock_key::FileLockKey;
+use crate::domain::model::file_lock_owner::FileLockOwner;
 use crate::domain::model::lock_raw_file_entry::LockFileRawAcquireEntry;
 use crate::domain::model::user_table_stream::UserTablStream;
 use crate::infrastructure::db::repository::current_file_repository::CurrentFileRepository;
 use crate::infrastructure::db::repository::file_lock_repository::FileLockRepository;
 use crate::infrastructure::db::repository::file_repository::FileRepository;
-use crate::util::error::UserError;
+use crate::util::error::LockError;
 use anyhow::bail;
 use chrono::Duration;
-use sea_orm::{DatabaseConnection, TransactionTrait};
+use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use std::collections::BTreeMap;
 
 pub struct FileLockService {
     connection: DatabaseConnection,
@@ -36,27 +40,44 @@ impl FileLockService {
             .await
     }
 
+    pub async fn find(
+        &self,
+        file_lock_key: &FileLockKey,
+    ) -> Result<Option<FileLock>, anyhow::Error> {
+        self.file_lock_repository
+            .find_by_key(&self.connection, file_lock_key)
+            .await
+    }
+
+    // With a non-empty partition_filter (transformed values), every current file matching it is
+    // locked instead of the entries.
     pub async fn acquire(
         &self,
         file_lock_key: &FileLockKey,
         stream: &UserTablStream,
         ttl: Duration,
+        owner: &FileLockOwner,
         entries: &[LockFileRawAcquireEntry],
+        partition_filter: &BTreeMap<String, String>,
     ) -> Result<Vec<FileWithId>, anyhow::Error> {
         let txn = self.connection.begin().await?;
 
-        let acquired = self
-            .file_lock_repository
-            .acquire(&txn, stream, ttl, file_lock_key)
+        self.create_lock(&txn, file_lock_key, stream, ttl, owner)
             .await?;
 
-        if !acquired {
-            bail!(UserError::InvalidLockMessage(
-                "cannot acquired. duplicated?".into()
-            ));
-        }
-
         let mut file_ids = vec![];
+        if !partition_filter.is_empty() {
+            let matched_files = self
+                .current_file_repository
+                .find_files_by_stream(&txn, stream, partition_filter)
+                .await?;
+            if matched_files.is_empty() {
+                bail!(LockError::FileNotFound(
+                    "no files match the partition filter".into()
+                ))
+            }
+            file_ids.extend(matched_files.into_iter().map(|f| f.id));
+        }
         for entry in entries {
             let locked_files = self
                 .current_file_repository
@@ -69,30 +90,87 @@ impl FileLockService {
                 .await?;
 
             if locked_files.len() != entry.file_paths.len() {
-                bail!(UserError::InvalidLockMessage("some files not found".into()))
+                bail!(LockError::FileNotFound(
+                    "some paths are not current files of the partition".into()
+                ))
             }
             file_ids.extend(locked_files.iter().map(|f| f.file_id.clone()));
         }
 
+        let files = self
+            .lock_files(&txn, file_lock_key, stream, &file_ids)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(files)
+    }
+
+    // Fails when the key is already used. Callers lock files with lock_files in the same transaction.
+    pub async fn create_lock(
+        &self,
+        txn: &DatabaseTransaction,
+        file_lock_key: &FileLockKey,
+        stream: &UserTablStream,
+        ttl: Duration,
+        owner: &FileLockOwner,
+    ) -> Result<(), anyhow::Error> {
+        let acquired = self
+            .file_lock_repository
+            .acquire(txn, stream, ttl, file_lock_key, owner)
+            .await?;
+
+        if !acquired {
+            let existing_lock = self
+                .file_lock_repository
+                .find_by_key(txn, file_lock_key)
+                .await?;
+            let message = match existing_lock {
+                Some(lock) => format!("the key is already used by [{}]", lock),
+                None => "the key is already used".to_string(),
+            };
+            bail!(LockError::DuplicatedKey(message));
+        }
+
+        Ok(())
+    }
+
+    // Fails unless every file can be locked.
+    pub async fn lock_files(
+        &self,
+        txn: &DatabaseTransaction,
+        file_lock_key: &FileLockKey,
+        stream: &UserTablStream,
+        file_ids: &[FileId],
+    ) -> Result<Vec<FileWithId>, anyhow::Error> {
         let locked_count = self
             .current_file_repository
-            .acquire_lock(&txn, file_lock_key, stream, &file_ids)
+            .acquire_lock(txn, file_lock_key, stream, file_ids)
             .await?;
 
         if locked_count as usize != file_ids.len() {
-            bail!(UserError::InvalidLockMessage(
-                "not all files can be locked".into()
-            ))
+            let conflicting_locks = self
+                .file_lock_repository
+                .find_active_by_file_ids(txn, file_lock_key, stream, file_ids)
+                .await?;
+            bail!(LockError::HeldByOther(format!(
+                "some files are held by {}",
+                Self::describe_locks(&conflicting_locks)
+            )))
         }
 
-        let files = self
-            .file_repository
-            .find_all_by_ids(&txn, stream, &file_ids)
-            .await?;
+        self.file_repository
+            .find_all_by_ids(txn, stream, file_ids)
+            .await
+    }
 
-        txn.commit().await?;
+    fn describe_locks(locks: &[FileLock]) -> String {
+        let descriptions: Vec<_> = locks.iter().map(|lock| format!("[{}]", lock)).collect();
+        if descriptions.is_empty() {
+            return "unknown".to_string();
+        }
 
-        Ok(files)
+        descriptions.join(", ")
     }
 
     pub async fn release(&self, file_lock_key: &FileLockKey) -> Result<bool, anyhow::Error> {
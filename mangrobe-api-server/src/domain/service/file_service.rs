@@ -1,5 +1,11 @@
 use crate::domain::model::file_id::FileId;
+use crate::domain::model::file_stats::FileStats;
 use crate::domain::model::file_with_statistics::FileWithStatistics;
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_id::UserTableId;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::infrastructure::db::repository::commit_repository::CommitRepository;
+use crate::infrastructure::db::repository::current_file_repository::CurrentFileRepository;
 use crate::infrastructure::db::repository::file_column_statistics_repository::FileColumnStatisticsRepository;
 use crate::infrastructure::db::repository::file_metadata_repository::FileMetadataRepository;
 use crate::infrastructure::db::repository::file_repository::FileRepository;
@@ -10,6 +16,8 @@ pub struct FileService {
     file_repository: FileRepository,
     file_column_statistics_repository: FileColumnStatisticsRepository,
     file_metadata_repository: FileMetadataRepository,
+    current_file_repository: CurrentFileRepository,
+    commit_repository: CommitRepository,
 }
 
 impl FileService {
@@ -19,6 +27,8 @@ impl FileService {
             file_repository: FileRepository::new(),
             file_column_statistics_repository: FileColumnStatisticsRepository::new(),
             file_metadata_repository: FileMetadataRepository::new(),
+            current_file_repository: CurrentFileRepository::new(),
+            commit_repository: CommitRepository::new(),
         }
     }
 
@@ -59,4 +69,33 @@ impl FileService {
             .collect();
         Ok(res)
     }
+
+    pub async fn count_current_files_by_table(
+        &self,
+    ) -> Result<Vec<(UserTableName, u64)>, anyhow::Error> {
+        self.current_file_repository
+            .count_files_by_table(&self.connection)
+            .await
+    }
+
+    // Statistics of the current files of the table, or of the stream when it is given.
+    pub async fn get_file_stats(
+        &self,
+        table_id: &UserTableId,
+        stream_id: Option<&StreamId>,
+    ) -> Result<FileStats, anyhow::Error> {
+        let stats = self
+            .current_file_repository
+            .find_file_stats(&self.connection, table_id, stream_id)
+            .await?;
+        let last_committed_at = self
+            .commit_repository
+            .find_last_committed_at(&self.connection, table_id, stream_id)
+            .await?;
+
+        Ok(FileStats {
+            last_committed_at,
+            ..stats
+        })
+    }
 }
@@ -0,0 +1,157 @@
+use crate::domain::model::quota::{CommitUsage, QuotaLimits, QuotaPolicy};
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::domain::model::user_table_stream::UserTablStream;
+use crate::infrastructure::db::repository::current_file_repository::CurrentFileRepository;
+use crate::util::error::QuotaError;
+use crate::util::metrics::QUOTA_REJECTED_TOTAL;
+use anyhow::bail;
+use sea_orm::DatabaseConnection;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+// Buckets of idle streams are dropped when the map grows beyond this.
+const RATE_BUCKET_PRUNE_THRESHOLD: usize = 10_000;
+
+pub struct QuotaService {
+    connection: DatabaseConnection,
+    current_file_repository: CurrentFileRepository,
+    policy: QuotaPolicy,
+    rate_buckets: Mutex<HashMap<(UserTableName, StreamId), TokenBucket>>,
+}
+
+impl QuotaService {
+    pub fn new(connection: &DatabaseConnection, policy: QuotaPolicy) -> Self {
+        Self {
+            connection: connection.clone(),
+            current_file_repository: CurrentFileRepository::new(),
+            policy,
+            rate_buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Must be called before the change request is created, so that rejected requests leave nothing behind.
+    pub async fn check_commit(
+        &self,
+        table_name: &UserTableName,
+        stream: &UserTablStream,
+        usage: &CommitUsage,
+    ) -> Result<(), anyhow::Error> {
+        let limits = self.policy.limits_for(table_name, &stream.stream_id);
+        let result = self.check_limits(&limits, table_name, stream, usage).await;
+        if let Err(e) = &result
+            && let Some(quota_error) = e.downcast_ref::<QuotaError>()
+        {
+            QUOTA_REJECTED_TOTAL
+                .with_label_values(&[table_name.val().as_str(), quota_error.reason()])
+                .inc();
+        }
+
+        result
+    }
+
+    async fn check_limits(
+        &self,
+        limits: &QuotaLimits,
+        table_name: &UserTableName,
+        stream: &UserTablStream,
+        usage: &CommitUsage,
+    ) -> Result<(), anyhow::Error> {
+        if let Some(limit) = limits.files_per_commit
+            && usage.file_count > limit
+        {
+            bail!(QuotaError::FilesPerCommit(format!(
+                "files={}, limit={}",
+                usage.file_count, limit
+            )));
+        }
+
+        if usage.added_files > 0
+            && (limits.max_current_files.is_some() || limits.max_current_bytes.is_some())
+        {
+            let (current_files, current_bytes) = self
+                .current_file_repository
+                .count_files_and_bytes_by_stream(&self.connection, stream)
+                .await?;
+            if let Some(limit) = limits.max_current_files
+                && current_files + usage.added_files > limit
+            {
+                bail!(QuotaError::CurrentFiles(format!(
+                    "current={}, adding={}, limit={}",
+                    current_files, usage.added_files, limit
+                )));
+            }
+            if let Some(limit) = limits.max_current_bytes
+                && current_bytes + usage.added_bytes > limit
+            {
+                bail!(QuotaError::CurrentBytes(format!(
+                    "current={}, adding={}, limit={}",
+                    current_bytes, usage.added_bytes, limit
+                )));
+            }
+        }
+
+        // Taken last, so that requests rejected by other quotas do not consume the rate.
+        if let Some(limit) = limits.commits_per_second
+            && !self.take_rate_token(table_name, &stream.stream_id, limit)
+        {
+            bail!(QuotaError::CommitRate(format!(
+                "limit={} commits per second",
+                limit
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn take_rate_token(
+        &self,
+        table_name: &UserTableName,
+        stream_id: &StreamId,
+        limit: u32,
+    ) -> bool {
+        let now = Instant::now();
+        let mut buckets = self.rate_buckets.lock().unwrap();
+        if buckets.len() > RATE_BUCKET_PRUNE_THRESHOLD {
+            buckets.retain(|_, bucket| !bucket.is_full(now));
+        }
+
+        buckets
+            .entry((table_name.clone(), stream_id.clone()))
+            .or_insert_with(|| TokenBucket::new(limit, now))
+            .try_take(limit, now)
+    }
+}
+
+// Allows bursts up to one second worth of commits.
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: u32, now: Instant) -> Self {
+        Self {
+            tokens: limit as f64,
+            updated_at: now,
+        }
+    }
+
+    fn try_take(&mut self, limit: u32, now: Instant) -> bool {
+        let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * limit as f64).min(limit as f64);
+        self.updated_at = now;
+        if self.tokens < 1.0 {
+            return false;
+        }
+
+        self.tokens -= 1.0;
+        true
+    }
+
+    // Full buckets are indistinguishable from new ones, so they can be dropped.
+    fn is_full(&self, now: Instant) -> bool {
+        now.duration_since(self.updated_at).as_secs() >= 1
+    }
+}
@@ -3,6 +3,7 @@ use crate::domain::model::user_table_stream::UserTablStream;
 use crate::infrastructure::db::repository::commit_repository::CommitRepository;
 use crate::infrastructure::db::repository::current_file_repository::CurrentFileRepository;
 use sea_orm::{AccessMode, DatabaseConnection, IsolationLevel, TransactionTrait};
+use std::collections::BTreeMap;
 
 pub struct SnapshotService {
     connection: DatabaseConnection,
@@ -19,7 +20,12 @@ impl SnapshotService {
         }
     }
 
-    pub async fn get_current(&self, stream: &UserTablStream) -> Result<Snapshot, anyhow::Error> {
+    // partition_filter holds transformed values. Empty returns every file.
+    pub async fn get_current(
+        &self,
+        stream: &UserTablStream,
+        partition_filter: &BTreeMap<String, String>,
+    ) -> Result<Snapshot, anyhow::Error> {
         let txn = self
             .connection
             .begin_with_config(
@@ -36,7 +42,7 @@ impl SnapshotService {
 
         let files = self
             .current_file_repository
-            .find_files_by_stream(&txn, stream)
+            .find_files_by_stream(&txn, stream, partition_filter)
             .await?;
 
         Ok(Snapshot::new(stream.clone(), Some(commit.id), files))
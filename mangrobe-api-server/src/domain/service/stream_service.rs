@@ -1,30 +1,150 @@
-use crate::domain::model::stream::Stream;
+use crate::domain::model::stream::{Stream, StreamFilter, StreamMetadata};
 use crate::domain::model::stream_id::StreamId;
 use crate::domain::model::user_table_id::UserTableId;
-use crate::infrastructure::db::repository::commit_repository::CommitRepository;
-use sea_orm::DatabaseConnection;
+use crate::domain::model::user_table_stream::UserTablStream;
+use crate::infrastructure::db::repository::commit_lock_repository::CommitLockRepository;
+use crate::infrastructure::db::repository::current_file_repository::CurrentFileRepository;
+use crate::infrastructure::db::repository::stream_repository::StreamRepository;
+use sea_orm::{DatabaseConnection, TransactionTrait};
 
 pub struct StreamService {
     connection: DatabaseConnection,
-    commit_repository: CommitRepository,
+    commit_lock_repository: CommitLockRepository,
+    current_file_repository: CurrentFileRepository,
+    stream_repository: StreamRepository,
 }
 
 impl StreamService {
     pub fn new(connection: &DatabaseConnection) -> Self {
         Self {
             connection: connection.clone(),
-            commit_repository: CommitRepository::new(),
+            commit_lock_repository: CommitLockRepository::new(),
+            current_file_repository: CurrentFileRepository::new(),
+            stream_repository: StreamRepository::new(),
         }
     }
 
-    pub async fn find_streams_after(
+    // Without the summary of current files.
+    pub async fn find(&self, stream: &UserTablStream) -> Result<Option<Stream>, anyhow::Error> {
+        self.stream_repository.find(&self.connection, stream).await
+    }
+
+    pub async fn find_all(
         &self,
         table_id: &UserTableId,
-        stream_id: &Option<StreamId>,
+        filter: &StreamFilter,
+        cursor: Option<&StreamId>,
+        descending: bool,
         limit: u64,
     ) -> Result<Vec<Stream>, anyhow::Error> {
-        self.commit_repository
-            .find_streams_after(&self.connection, table_id, stream_id, limit)
-            .await
+        let mut streams = self
+            .stream_repository
+            .find_all(
+                &self.connection,
+                table_id,
+                filter,
+                cursor,
+                descending,
+                limit,
+            )
+            .await?;
+
+        self.fill_file_counts(table_id, &mut streams).await?;
+        Ok(streams)
+    }
+
+    // Returns None when the stream already exists and skip_if_exists is false.
+    pub async fn register(
+        &self,
+        stream: &UserTablStream,
+        metadata: &StreamMetadata,
+        skip_if_exists: bool,
+    ) -> Result<Option<Stream>, anyhow::Error> {
+        let inserted = self
+            .stream_repository
+            .insert_if_not_exists(&self.connection, stream, metadata)
+            .await?;
+        if inserted.is_some() {
+            return Ok(inserted);
+        }
+        if !skip_if_exists {
+            return Ok(None);
+        }
+
+        let existing = self.find(stream).await?;
+        self.with_file_counts(stream, existing).await
+    }
+
+    // Returns None when the stream is not found or already sealed.
+    pub async fn seal(&self, stream: &UserTablStream) -> Result<Option<Stream>, anyhow::Error> {
+        let txn = self.connection.begin().await?;
+
+        // Waits for in-flight commits so that no AddFiles is committed after sealing.
+        self.commit_lock_repository
+            .acquire_xact_lock(&txn, stream)
+            .await?;
+        let sealed = self.stream_repository.seal(&txn, stream).await?;
+
+        txn.commit().await?;
+
+        self.with_file_counts(stream, sealed).await
+    }
+
+    // Returns false when the stream is not found.
+    pub async fn delete(&self, stream: &UserTablStream) -> Result<bool, anyhow::Error> {
+        let txn = self.connection.begin().await?;
+
+        self.commit_lock_repository
+            .acquire_xact_lock(&txn, stream)
+            .await?;
+        if self.stream_repository.find(&txn, stream).await?.is_none() {
+            return Ok(false);
+        }
+        self.stream_repository
+            .delete_with_data(&txn, stream)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    async fn with_file_counts(
+        &self,
+        stream: &UserTablStream,
+        found: Option<Stream>,
+    ) -> Result<Option<Stream>, anyhow::Error> {
+        let Some(found) = found else {
+            return Ok(None);
+        };
+
+        let mut streams = vec![found];
+        self.fill_file_counts(&stream.user_table_id, &mut streams)
+            .await?;
+        Ok(streams.pop())
+    }
+
+    async fn fill_file_counts(
+        &self,
+        table_id: &UserTableId,
+        streams: &mut [Stream],
+    ) -> Result<(), anyhow::Error> {
+        if streams.is_empty() {
+            return Ok(());
+        }
+
+        let stream_ids: Vec<_> = streams.iter().map(|s| s.id.clone()).collect();
+        let counts = self
+            .current_file_repository
+            .count_files_by_stream(&self.connection, table_id, &stream_ids)
+            .await?;
+        for stream in streams.iter_mut() {
+            if let Some((file_count, total_bytes)) = counts.get(&stream.id) {
+                stream.file_count = *file_count;
+                stream.total_bytes = *total_bytes;
+            }
+        }
+
+        Ok(())
     }
 }
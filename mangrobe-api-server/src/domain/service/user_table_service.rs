@@ -1,12 +1,29 @@
+use crate::domain::model::table_properties::{PARTITION_FIELDS, TableProperties};
 use crate::domain::model::user_table::UserTable;
 use crate::domain::model::user_table_id::UserTableId;
 use crate::domain::model::user_table_name::UserTableName;
-use crate::infrastructure::db::repository::user_table_repository::UserTableRepository;
-use sea_orm::DatabaseConnection;
+use crate::infrastructure::db::repository::current_file_repository::CurrentFileRepository;
+use crate::infrastructure::db::repository::table_alias_repository::{
+    TableAliasRepository, TableAliasRepositoryError,
+};
+use crate::infrastructure::db::repository::table_grant_repository::TableGrantRepository;
+use crate::infrastructure::db::repository::table_name_lock_repository::TableNameLockRepository;
+use crate::infrastructure::db::repository::user_table_repository::{
+    UserTableRepository, UserTableRepositoryError,
+};
+use crate::util::error::{MangrobeError, UserError};
+use anyhow::bail;
+use chrono::{Duration, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection, TransactionTrait};
+use std::collections::BTreeMap;
 
 pub struct UserTableService {
     connection: DatabaseConnection,
     user_table_repository: UserTableRepository,
+    table_alias_repository: TableAliasRepository,
+    table_grant_repository: TableGrantRepository,
+    table_name_lock_repository: TableNameLockRepository,
+    current_file_repository: CurrentFileRepository,
 }
 
 impl UserTableService {
@@ -14,12 +31,18 @@ impl UserTableService {
         Self {
             connection: connection.clone(),
             user_table_repository: UserTableRepository::new(),
+            table_alias_repository: TableAliasRepository::new(),
+            table_grant_repository: TableGrantRepository::new(),
+            table_name_lock_repository: TableNameLockRepository::new(),
+            current_file_repository: CurrentFileRepository::new(),
         }
     }
 
+    // With skip_if_exists, an existing table is returned with its own properties.
     pub async fn create(
         &self,
         name: &UserTableName,
+        properties: &TableProperties,
         skip_if_exists: bool,
     ) -> Result<UserTable, anyhow::Error> {
         if skip_if_exists {
@@ -28,29 +51,314 @@ impl UserTableService {
                 .find_by_name(&self.connection, name)
                 .await?;
             if let Some(table) = table {
-                return Ok(table);
+                return self.with_aliases(table).await;
             }
         }
 
+        let txn = self.connection.begin().await?;
+        self.table_name_lock_repository
+            .acquire_xact_lock(&txn, name)
+            .await?;
+
+        let alias = self.table_alias_repository.find_by_name(&txn, name).await?;
+        if alias.is_some() {
+            bail!(UserTableRepositoryError::AlreadyExists);
+        }
+
         let table = self
             .user_table_repository
-            .insert(&self.connection, name)
+            .insert(&txn, name, properties)
             .await?;
+        txn.commit().await?;
 
         Ok(table)
     }
 
+    pub async fn find_all_by_ids(
+        &self,
+        ids: &[UserTableId],
+    ) -> Result<Vec<UserTable>, anyhow::Error> {
+        self.user_table_repository
+            .find_all_by_ids(&self.connection, ids)
+            .await
+    }
+
+    // Dropped tables are not found, so that they reject every operation.
     pub async fn find_id_by_name(
         &self,
         name: &UserTableName,
     ) -> Result<Option<UserTableId>, anyhow::Error> {
+        let table = self.find_by_name(name).await?;
+
+        let Some(table) = table.filter(|t| !t.is_dropped()) else {
+            return Ok(None);
+        };
+
+        Ok(Some(table.id))
+    }
+
+    // Resolves aliases. Includes dropped tables.
+    pub async fn find_by_name(
+        &self,
+        name: &UserTableName,
+    ) -> Result<Option<UserTable>, anyhow::Error> {
         let table = self
             .user_table_repository
             .find_by_name(&self.connection, name)
             .await?;
+        if table.is_some() {
+            return Ok(table);
+        }
 
-        let Some(table) = table else { return Ok(None) };
+        self.user_table_repository
+            .find_by_alias(&self.connection, name)
+            .await
+    }
 
-        Ok(Some(table.id))
+    pub async fn find_all(
+        &self,
+        names: Option<&[UserTableName]>,
+        name_after: Option<&UserTableName>,
+        include_dropped: bool,
+        limit: u64,
+    ) -> Result<Vec<UserTable>, anyhow::Error> {
+        let tables = self
+            .user_table_repository
+            .find_all(&self.connection, names, name_after, include_dropped, limit)
+            .await?;
+
+        self.fill_aliases(&self.connection, tables).await
+    }
+
+    // Returns None when the table is already dropped.
+    pub async fn drop_table(
+        &self,
+        id: &UserTableId,
+        grace_period: Duration,
+    ) -> Result<Option<UserTable>, anyhow::Error> {
+        let table = self
+            .user_table_repository
+            .mark_dropped(&self.connection, id, Utc::now() + grace_period)
+            .await?;
+
+        match table {
+            Some(table) => Ok(Some(self.with_aliases(table).await?)),
+            None => Ok(None),
+        }
+    }
+
+    // Returns None when the table is not dropped or its grace period has passed.
+    pub async fn undrop_table(&self, id: &UserTableId) -> Result<Option<UserTable>, anyhow::Error> {
+        let table = self
+            .user_table_repository
+            .restore(&self.connection, id)
+            .await?;
+
+        match table {
+            Some(table) => Ok(Some(self.with_aliases(table).await?)),
+            None => Ok(None),
+        }
+    }
+
+    // Grants on the old name move to the new name. An alias equal to the new name is replaced
+    // when it belongs to the same table.
+    pub async fn rename(
+        &self,
+        table: &UserTable,
+        new_name: &UserTableName,
+        keep_alias: bool,
+    ) -> Result<UserTable, anyhow::Error> {
+        let txn = self.connection.begin().await?;
+        self.table_name_lock_repository
+            .acquire_xact_lock(&txn, new_name)
+            .await?;
+
+        let alias = self
+            .table_alias_repository
+            .find_by_name(&txn, new_name)
+            .await?;
+        if let Some(alias) = alias {
+            if alias.user_table_id != table.id {
+                bail!(TableAliasRepositoryError::AlreadyExists);
+            }
+            self.table_alias_repository
+                .delete_by_name(&txn, new_name)
+                .await?;
+        }
+
+        let renamed = self
+            .user_table_repository
+            .rename(&txn, &table.id, new_name)
+            .await?;
+        let Some(renamed) = renamed else {
+            bail!(MangrobeError::UnexpectedState(format!(
+                "table {} was deleted while renaming",
+                table.id.val()
+            )));
+        };
+        self.table_grant_repository
+            .rename_table(&txn, &table.name, new_name)
+            .await?;
+        if keep_alias {
+            self.table_alias_repository
+                .insert(&txn, &table.name, &table.id)
+                .await?;
+        }
+
+        let renamed = self.fill_aliases(&txn, vec![renamed]).await?;
+        txn.commit().await?;
+
+        Ok(renamed.into_iter().next().unwrap())
+    }
+
+    pub async fn create_alias(
+        &self,
+        table: UserTable,
+        alias: &UserTableName,
+    ) -> Result<UserTable, anyhow::Error> {
+        let txn = self.connection.begin().await?;
+        self.table_name_lock_repository
+            .acquire_xact_lock(&txn, alias)
+            .await?;
+
+        let existing = self.user_table_repository.find_by_name(&txn, alias).await?;
+        if existing.is_some() {
+            bail!(UserTableRepositoryError::AlreadyExists);
+        }
+
+        self.table_alias_repository
+            .insert(&txn, alias, &table.id)
+            .await?;
+        let table = self.fill_aliases(&txn, vec![table]).await?;
+        txn.commit().await?;
+
+        Ok(table.into_iter().next().unwrap())
+    }
+
+    // Keys in `remove` are removed before `set` is applied.
+    pub async fn alter_properties(
+        &self,
+        table: &UserTable,
+        set: &BTreeMap<String, String>,
+        remove: &[String],
+    ) -> Result<UserTable, anyhow::Error> {
+        let txn = self.connection.begin().await?;
+
+        let current = self
+            .user_table_repository
+            .find_by_id_for_update(&txn, &table.id)
+            .await?;
+        let Some(current) = current else {
+            bail!(MangrobeError::UnexpectedState(format!(
+                "table {} was deleted while altering properties",
+                table.id.val()
+            )));
+        };
+        let mut properties = current.properties.clone();
+        properties.apply(set, remove);
+        // Files store the values transformed by the fields they were added with, so changed fields
+        // would make partition filters miss them.
+        if properties.values.get(PARTITION_FIELDS)
+            != current.properties.values.get(PARTITION_FIELDS)
+            && self
+                .current_file_repository
+                .exists_by_table(&txn, &table.id)
+                .await?
+        {
+            bail!(UserError::FailedPrecondition(format!(
+                "{} of table '{}' cannot be changed while it has files",
+                PARTITION_FIELDS,
+                table.name.val()
+            )));
+        }
+
+        let altered = self
+            .user_table_repository
+            .update_properties(&txn, &table.id, &properties)
+            .await?;
+        let Some(altered) = altered else {
+            bail!(MangrobeError::UnexpectedState(format!(
+                "table {} was deleted while altering properties",
+                table.id.val()
+            )));
+        };
+        let altered = self.fill_aliases(&txn, vec![altered]).await?;
+        txn.commit().await?;
+
+        Ok(altered.into_iter().next().unwrap())
+    }
+
+    // Returns the table the alias pointed to, or None when the alias does not exist.
+    pub async fn drop_alias(
+        &self,
+        alias: &UserTableName,
+    ) -> Result<Option<UserTable>, anyhow::Error> {
+        let table = self
+            .user_table_repository
+            .find_by_alias(&self.connection, alias)
+            .await?;
+        let Some(table) = table else {
+            return Ok(None);
+        };
+
+        let deleted = self
+            .table_alias_repository
+            .delete_by_name(&self.connection, alias)
+            .await?;
+        if !deleted {
+            return Ok(None);
+        }
+
+        Ok(Some(self.with_aliases(table).await?))
+    }
+
+    // Dropped tables whose grace period has passed.
+    pub async fn find_purgeable(&self) -> Result<Vec<UserTable>, anyhow::Error> {
+        self.user_table_repository
+            .find_purgeable(&self.connection, Utc::now())
+            .await
+    }
+
+    // Deletes the table with its files, aliases and grants. Object paths of the files are queued
+    // for garbage collection in the same transaction. Returns the number of queued paths.
+    pub async fn purge(&self, table: &UserTable) -> Result<u64, anyhow::Error> {
+        let txn = self.connection.begin().await?;
+        let garbage_count = self
+            .user_table_repository
+            .delete_with_data(&txn, &table.id)
+            .await?;
+        self.table_grant_repository
+            .delete_by_table_name(&txn, &table.name)
+            .await?;
+        txn.commit().await?;
+
+        Ok(garbage_count)
+    }
+
+    async fn with_aliases(&self, table: UserTable) -> Result<UserTable, anyhow::Error> {
+        let tables = self.fill_aliases(&self.connection, vec![table]).await?;
+        Ok(tables.into_iter().next().unwrap())
+    }
+
+    async fn fill_aliases<C>(
+        &self,
+        conn: &C,
+        mut tables: Vec<UserTable>,
+    ) -> Result<Vec<UserTable>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let ids: Vec<_> = tables.iter().map(|t| t.id.clone()).collect();
+        let aliases = self
+            .table_alias_repository
+            .find_all_by_table_ids(conn, &ids)
+            .await?;
+
+        for alias in aliases {
+            if let Some(table) = tables.iter_mut().find(|t| t.id == alias.user_table_id) {
+                table.aliases.push(alias.name);
+            }
+        }
+        Ok(tables)
     }
 }
@@ -1,7 +1,14 @@
+pub mod access_control;
+pub mod auth;
 pub mod data_definition;
 pub mod data_manipulation;
+pub mod health_checker;
 pub mod information_schema;
 pub mod lock_control;
 mod model;
 pub mod proto;
+mod request_id;
+mod rpc_call;
+pub mod rpc_metrics_layer;
+pub mod rpc_tracing_layer;
 mod util;
@@ -0,0 +1,5 @@
+pub mod access_control_service;
+mod grant_table_permission_param;
+mod list_table_permissions_param;
+mod revoke_table_permission_param;
+mod table_permission_grant_response;
@@ -0,0 +1,79 @@
+use crate::application::access_control::access_control_use_case::AccessControlUseCase;
+use crate::grpc::access_control::grant_table_permission_param::build_grant_table_permission_param;
+use crate::grpc::access_control::list_table_permissions_param::build_list_table_permissions_param;
+use crate::grpc::access_control::revoke_table_permission_param::build_revoke_table_permission_param;
+use crate::grpc::access_control::table_permission_grant_response::build_table_permission_grant;
+use crate::grpc::proto::{
+    GrantTablePermissionRequest, GrantTablePermissionResponse, ListTablePermissionsRequest,
+    ListTablePermissionsResponse, RevokeTablePermissionRequest, RevokeTablePermissionResponse,
+    access_control_service_server,
+};
+use crate::grpc::util::error::{build_invalid_argument, to_grpc_error};
+use sea_orm::DatabaseConnection;
+use tonic::{Request, Response, Status};
+
+pub struct AccessControlService {
+    access_control_use_case: AccessControlUseCase,
+}
+
+impl AccessControlService {
+    pub fn new(db: &DatabaseConnection) -> Self {
+        Self {
+            access_control_use_case: AccessControlUseCase::new(db.clone()),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl access_control_service_server::AccessControlService for AccessControlService {
+    async fn grant_table_permission(
+        &self,
+        request: Request<GrantTablePermissionRequest>,
+    ) -> Result<Response<GrantTablePermissionResponse>, Status> {
+        let param = build_grant_table_permission_param(request).map_err(build_invalid_argument)?;
+
+        let grant = self
+            .access_control_use_case
+            .grant(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        let response = GrantTablePermissionResponse {
+            grant: Some(build_table_permission_grant(&grant)),
+        };
+        Ok(Response::new(response))
+    }
+
+    async fn revoke_table_permission(
+        &self,
+        request: Request<RevokeTablePermissionRequest>,
+    ) -> Result<Response<RevokeTablePermissionResponse>, Status> {
+        let param = build_revoke_table_permission_param(request).map_err(build_invalid_argument)?;
+
+        let deleted = self
+            .access_control_use_case
+            .revoke(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        Ok(Response::new(RevokeTablePermissionResponse { deleted }))
+    }
+
+    async fn list_table_permissions(
+        &self,
+        request: Request<ListTablePermissionsRequest>,
+    ) -> Result<Response<ListTablePermissionsResponse>, Status> {
+        let param = build_list_table_permissions_param(request).map_err(build_invalid_argument)?;
+
+        let grants = self
+            .access_control_use_case
+            .list(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        let response = ListTablePermissionsResponse {
+            grants: grants.iter().map(build_table_permission_grant).collect(),
+        };
+        Ok(Response::new(response))
+    }
+}
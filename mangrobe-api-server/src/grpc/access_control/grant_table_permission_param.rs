@@ -0,0 +1,44 @@
+use crate::application::access_control::grant_table_permission_param::GrantTablePermissionParam;
+use crate::domain::model::permission::Permission;
+use crate::domain::model::principal::validate_qualified_id;
+use crate::grpc::proto::{GrantTablePermissionRequest, TablePermission};
+use crate::grpc::util::param_util::{to_principal, to_stream_id_range, to_table_name};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_grant_table_permission_param(
+    request: Request<GrantTablePermissionRequest>,
+) -> Result<GrantTablePermissionParam, ParameterError> {
+    let req = request.get_ref();
+    if req.principal_id.is_empty() {
+        return Err(ParameterError::Required("principal_id".to_string()));
+    }
+    validate_qualified_id(&req.principal_id)
+        .map_err(|e| ParameterError::Invalid("principal_id".to_string(), e))?;
+    let table_name = req.table_name.clone().map(to_table_name).transpose()?;
+    let permission = to_permission(req.permission)?;
+
+    let stream_id_range = to_stream_id_range(req.stream_id_range.as_ref())?;
+
+    Ok(GrantTablePermissionParam {
+        principal_id: req.principal_id.clone(),
+        table_name,
+        permission,
+        stream_id_range,
+        principal: to_principal(&request),
+    })
+}
+
+fn to_permission(permission: i32) -> Result<Permission, ParameterError> {
+    match TablePermission::try_from(permission) {
+        Ok(TablePermission::Read) => Ok(Permission::Read),
+        Ok(TablePermission::Write) => Ok(Permission::Write),
+        Ok(TablePermission::Compact) => Ok(Permission::Compact),
+        Ok(TablePermission::Admin) => Ok(Permission::Admin),
+        Ok(TablePermission::Unspecified) => Err(ParameterError::Required("permission".to_string())),
+        Err(_) => Err(ParameterError::Invalid(
+            "permission".to_string(),
+            "unknown value".to_string(),
+        )),
+    }
+}
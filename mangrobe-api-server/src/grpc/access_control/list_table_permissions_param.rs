@@ -0,0 +1,24 @@
+use crate::application::access_control::list_table_permissions_param::ListTablePermissionsParam;
+use crate::domain::model::principal::validate_qualified_id;
+use crate::grpc::proto::ListTablePermissionsRequest;
+use crate::grpc::util::param_util::{to_principal, to_table_name};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_list_table_permissions_param(
+    request: Request<ListTablePermissionsRequest>,
+) -> Result<ListTablePermissionsParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = req.table_name.clone().map(to_table_name).transpose()?;
+    let principal_id = req.principal_id.clone().filter(|v| !v.is_empty());
+    if let Some(principal_id) = &principal_id {
+        validate_qualified_id(principal_id)
+            .map_err(|e| ParameterError::Invalid("principal_id".to_string(), e))?;
+    }
+
+    Ok(ListTablePermissionsParam {
+        table_name,
+        principal_id,
+        principal: to_principal(&request),
+    })
+}
@@ -0,0 +1,19 @@
+use crate::application::access_control::revoke_table_permission_param::RevokeTablePermissionParam;
+use crate::grpc::proto::RevokeTablePermissionRequest;
+use crate::grpc::util::param_util::to_principal;
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_revoke_table_permission_param(
+    request: Request<RevokeTablePermissionRequest>,
+) -> Result<RevokeTablePermissionParam, ParameterError> {
+    let req = request.get_ref();
+    let grant_id = req.grant_id.parse::<i64>().map_err(|_| {
+        ParameterError::Invalid("grant_id".to_string(), "invalid number".to_string())
+    })?;
+
+    Ok(RevokeTablePermissionParam {
+        grant_id: grant_id.into(),
+        principal: to_principal(&request),
+    })
+}
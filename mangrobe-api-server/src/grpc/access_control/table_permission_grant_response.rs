@@ -0,0 +1,32 @@
+use crate::domain::model::permission::Permission;
+use crate::domain::model::table_grant::TableGrant;
+use crate::grpc::proto::{StreamIdRange, TablePermission, TablePermissionGrant};
+
+pub(super) fn build_table_permission_grant(grant: &TableGrant) -> TablePermissionGrant {
+    let range = &grant.stream_id_range;
+    let stream_id_range = if range.is_unbounded() {
+        None
+    } else {
+        Some(StreamIdRange {
+            min: range.min.as_ref().map(|v| v.val()),
+            max: range.max.as_ref().map(|v| v.val()),
+        })
+    };
+
+    TablePermissionGrant {
+        grant_id: grant.id.val().to_string(),
+        principal_id: grant.principal_id.clone(),
+        table_name: grant.table_name.as_ref().map(|t| t.val()),
+        permission: to_table_permission(grant.permission).into(),
+        stream_id_range,
+    }
+}
+
+fn to_table_permission(permission: Permission) -> TablePermission {
+    match permission {
+        Permission::Read => TablePermission::Read,
+        Permission::Write => TablePermission::Write,
+        Permission::Compact => TablePermission::Compact,
+        Permission::Admin => TablePermission::Admin,
+    }
+}
@@ -0,0 +1,5 @@
+pub mod auth_interceptor;
+mod authenticator;
+mod client_certificate_authenticator;
+mod jwt_authenticator;
+mod static_token_authenticator;
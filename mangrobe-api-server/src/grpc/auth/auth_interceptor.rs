@@ -0,0 +1,73 @@
+use crate::config::app_config::AppConfig;
+use crate::domain::model::principal::Principal;
+use crate::grpc::auth::authenticator::{Authenticator, bearer_token};
+use crate::grpc::auth::client_certificate_authenticator::ClientCertificateAuthenticator;
+use crate::grpc::auth::jwt_authenticator::JwtAuthenticator;
+use crate::grpc::auth::static_token_authenticator::StaticTokenAuthenticator;
+use crate::grpc::util::error::build_auth_error;
+use crate::util::error::AuthError;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+// Authenticates every request and stores the resulting Principal in the request extensions.
+// When no authenticator is configured, every request is treated as anonymous.
+#[derive(Clone)]
+pub struct AuthInterceptor {
+    authenticators: Arc<Vec<Box<dyn Authenticator>>>,
+    admin_principals: Arc<HashSet<String>>,
+}
+
+impl AuthInterceptor {
+    pub fn new(config: &AppConfig) -> Result<Self, anyhow::Error> {
+        let mut authenticators: Vec<Box<dyn Authenticator>> = vec![];
+        if !config.auth.tokens.is_empty() {
+            authenticators.push(Box::new(StaticTokenAuthenticator::new(&config.auth.tokens)));
+        }
+        if let Some(jwt) = &config.auth.jwt {
+            authenticators.push(Box::new(JwtAuthenticator::load(jwt)?));
+        }
+        if config.server.client_ca_enabled() {
+            authenticators.push(Box::new(ClientCertificateAuthenticator));
+        }
+
+        Ok(Self {
+            authenticators: Arc::new(authenticators),
+            admin_principals: Arc::new(config.auth.admin_principals.iter().cloned().collect()),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.authenticators.is_empty()
+    }
+
+    fn authenticate(&self, request: &Request<()>) -> Result<Principal, AuthError> {
+        if !self.is_enabled() {
+            return Ok(Principal::anonymous());
+        }
+
+        for authenticator in self.authenticators.iter() {
+            if let Some(principal) = authenticator.authenticate(request)? {
+                let is_admin = self.admin_principals.contains(&principal.qualified_id());
+                return Ok(principal.with_admin(is_admin));
+            }
+        }
+
+        if bearer_token(request).is_some() {
+            return Err(AuthError::Unauthenticated("invalid token".into()));
+        }
+        Err(AuthError::Unauthenticated("credential required".into()))
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let principal = self
+            .authenticate(&request)
+            .map_err(|e| build_auth_error(&e))?;
+        request.extensions_mut().insert(principal);
+
+        Ok(request)
+    }
+}
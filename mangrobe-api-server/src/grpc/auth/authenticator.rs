@@ -0,0 +1,22 @@
+use crate::domain::model::principal::Principal;
+use crate::util::error::AuthError;
+use tonic::Request;
+
+const AUTHORIZATION_HEADER: &str = "authorization";
+const BEARER_PREFIX: &str = "Bearer ";
+
+pub(super) trait Authenticator: Send + Sync {
+    // Returns None when the request has no credential this authenticator understands,
+    // so that the next authenticator can try.
+    fn authenticate(&self, request: &Request<()>) -> Result<Option<Principal>, AuthError>;
+}
+
+pub(super) fn bearer_token(request: &Request<()>) -> Option<&str> {
+    request
+        .metadata()
+        .get(AUTHORIZATION_HEADER)?
+        .to_str()
+        .ok()?
+        .strip_prefix(BEARER_PREFIX)
+        .map(str::trim)
+}
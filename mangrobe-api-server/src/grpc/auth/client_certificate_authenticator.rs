@@ -0,0 +1,38 @@
+use crate::domain::model::principal::{Principal, PrincipalKind};
+use crate::grpc::auth::authenticator::Authenticator;
+use crate::util::error::AuthError;
+use tonic::Request;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+// Uses the Common Name of the verified client certificate as the principal.
+// The certificate chain itself is verified by TLS.
+pub(super) struct ClientCertificateAuthenticator;
+
+impl Authenticator for ClientCertificateAuthenticator {
+    fn authenticate(&self, request: &Request<()>) -> Result<Option<Principal>, AuthError> {
+        let Some(certs) = request.peer_certs() else {
+            return Ok(None);
+        };
+        let Some(cert) = certs.first() else {
+            return Ok(None);
+        };
+
+        let (_, cert) = X509Certificate::from_der(cert.as_ref()).map_err(|e| {
+            AuthError::Unauthenticated(format!("invalid client certificate. {}", e))
+        })?;
+        let common_name = cert
+            .subject()
+            .iter_common_name()
+            .next()
+            .and_then(|cn| cn.as_str().ok())
+            .filter(|cn| !cn.is_empty())
+            .ok_or(AuthError::Unauthenticated(
+                "client certificate has no common name".into(),
+            ))?;
+
+        Ok(Some(Principal::new(
+            common_name.to_string(),
+            PrincipalKind::ClientCertificate,
+        )))
+    }
+}
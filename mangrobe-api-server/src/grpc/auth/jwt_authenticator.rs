@@ -0,0 +1,108 @@
+use crate::config::app_config::JwtConfig;
+use crate::domain::model::principal::{Principal, PrincipalKind};
+use crate::grpc::auth::authenticator::{Authenticator, bearer_token};
+use crate::util::error::AuthError;
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header};
+use serde_json::{Map, Value};
+use std::fs;
+use tonic::Request;
+
+// Only asymmetric algorithms are accepted because keys come from a JWKS.
+const ALLOWED_ALGORITHMS: [Algorithm; 9] = [
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+    Algorithm::EdDSA,
+];
+
+pub(super) struct JwtAuthenticator {
+    jwks: JwkSet,
+    issuer: Option<String>,
+    audience: Option<String>,
+    principal_claim: String,
+}
+
+impl JwtAuthenticator {
+    pub fn load(config: &JwtConfig) -> Result<Self, anyhow::Error> {
+        let content = fs::read_to_string(&config.jwks_path)?;
+        let jwks: JwkSet = serde_json::from_str(&content)?;
+
+        Ok(Self {
+            jwks,
+            issuer: config.issuer.clone(),
+            audience: config.audience.clone(),
+            principal_claim: config.principal_claim.clone(),
+        })
+    }
+
+    fn find_key(&self, kid: &Option<String>) -> Option<&Jwk> {
+        match kid {
+            Some(kid) => self.jwks.find(kid),
+            None if self.jwks.keys.len() == 1 => self.jwks.keys.first(),
+            None => None,
+        }
+    }
+
+    fn build_validation(&self, algorithm: Algorithm) -> Validation {
+        let mut validation = Validation::new(algorithm);
+        if let Some(issuer) = &self.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+        match &self.audience {
+            Some(audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+
+        validation
+    }
+}
+
+impl Authenticator for JwtAuthenticator {
+    fn authenticate(&self, request: &Request<()>) -> Result<Option<Principal>, AuthError> {
+        let Some(token) = bearer_token(request) else {
+            return Ok(None);
+        };
+        // Not a JWT. Possibly a token for another authenticator.
+        let Ok(header) = decode_header(token) else {
+            return Ok(None);
+        };
+
+        if !ALLOWED_ALGORITHMS.contains(&header.alg) {
+            return Err(unauthenticated(format!(
+                "unsupported algorithm. alg={:?}",
+                header.alg
+            )));
+        }
+        let jwk = self
+            .find_key(&header.kid)
+            .ok_or(unauthenticated("unknown key".into()))?;
+        let key = DecodingKey::from_jwk(jwk).map_err(|e| unauthenticated(e.to_string()))?;
+
+        let data = decode::<Map<String, Value>>(token, &key, &self.build_validation(header.alg))
+            .map_err(|e| unauthenticated(e.to_string()))?;
+        let principal_id = data
+            .claims
+            .get(&self.principal_claim)
+            .and_then(Value::as_str)
+            .filter(|v| !v.is_empty())
+            .ok_or(unauthenticated(format!(
+                "claim '{}' not found",
+                self.principal_claim
+            )))?;
+
+        Ok(Some(Principal::new(
+            principal_id.to_string(),
+            PrincipalKind::Jwt,
+        )))
+    }
+}
+
+fn unauthenticated(message: String) -> AuthError {
+    AuthError::Unauthenticated(format!("invalid jwt. {}", message))
+}
@@ -0,0 +1,42 @@
+use crate::config::app_config::StaticTokenConfig;
+use crate::domain::model::principal::{Principal, PrincipalKind};
+use crate::grpc::auth::authenticator::{Authenticator, bearer_token};
+use crate::util::error::AuthError;
+use tonic::Request;
+
+pub(super) struct StaticTokenAuthenticator {
+    tokens: Vec<StaticTokenConfig>,
+}
+
+impl StaticTokenAuthenticator {
+    pub fn new(tokens: &[StaticTokenConfig]) -> Self {
+        Self {
+            tokens: tokens.to_vec(),
+        }
+    }
+}
+
+impl Authenticator for StaticTokenAuthenticator {
+    fn authenticate(&self, request: &Request<()>) -> Result<Option<Principal>, AuthError> {
+        let Some(token) = bearer_token(request) else {
+            return Ok(None);
+        };
+
+        let principal = self
+            .tokens
+            .iter()
+            .find(|t| constant_time_eq(t.token.as_bytes(), token.as_bytes()))
+            .map(|t| Principal::new(t.principal.clone(), PrincipalKind::StaticToken));
+
+        Ok(principal)
+    }
+}
+
+// Avoids leaking how many leading bytes matched through response timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -1,2 +1,14 @@
+mod alter_table_properties_param;
+mod create_table_alias_param;
 mod create_table_param;
 pub mod data_definition_service;
+mod delete_stream_param;
+mod describe_table_param;
+mod drop_table_alias_param;
+mod drop_table_param;
+mod list_tables_param;
+mod register_stream_param;
+mod rename_table_param;
+mod seal_stream_param;
+mod table_info_response;
+mod undrop_table_param;
@@ -0,0 +1,32 @@
+use crate::application::data_definition::AlterTablePropertiesParam;
+use crate::grpc::proto::AlterTablePropertiesRequest;
+use crate::grpc::util::param_util::{
+    to_client_info, to_principal, to_table_name, to_table_properties, to_table_property_key,
+};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_alter_table_properties_param(
+    request: Request<AlterTablePropertiesRequest>,
+) -> Result<AlterTablePropertiesParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = to_table_name(req.table_name.clone())?;
+    let set = to_table_properties(&req.set_properties)?;
+    for key in &req.remove_properties {
+        to_table_property_key(key)?;
+        if set.contains_key(key) {
+            return Err(ParameterError::Invalid(
+                format!("properties.{}", key),
+                "must not be both set and removed".to_string(),
+            ));
+        }
+    }
+
+    Ok(AlterTablePropertiesParam {
+        table_name,
+        set,
+        remove: req.remove_properties.clone(),
+        principal: to_principal(&request),
+        client: to_client_info(&request),
+    })
+}
@@ -0,0 +1,22 @@
+use crate::application::data_definition::CreateTableAliasParam;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::grpc::proto::CreateTableAliasRequest;
+use crate::grpc::util::param_util::{to_client_info, to_principal, to_table_name};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_create_table_alias_param(
+    request: Request<CreateTableAliasRequest>,
+) -> Result<CreateTableAliasParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = to_table_name(req.table_name.clone())?;
+    let alias = UserTableName::try_from(req.alias.clone())
+        .map_err(|e| ParameterError::Invalid("alias".into(), e))?;
+
+    Ok(CreateTableAliasParam {
+        table_name,
+        alias,
+        principal: to_principal(&request),
+        client: to_client_info(&request),
+    })
+}
@@ -1,6 +1,9 @@
 use crate::application::data_definition::CreateTableParam;
+use crate::domain::model::table_properties::TableProperties;
 use crate::grpc::proto::CreateTableRequest;
-use crate::grpc::util::param_util::to_table_name;
+use crate::grpc::util::param_util::{
+    to_client_info, to_principal, to_table_name, to_table_properties,
+};
 use crate::util::error::ParameterError;
 use tonic::Request;
 
@@ -9,9 +12,13 @@ pub(super) fn build_create_table_param(
 ) -> Result<CreateTableParam, ParameterError> {
     let req = request.get_ref();
     let table_name = to_table_name(req.table_name.clone())?;
+    let properties = to_table_properties(&req.properties)?;
 
     Ok(CreateTableParam {
         table_name,
+        properties: TableProperties::new(properties),
         skip_if_exists: req.skip_if_exists,
+        principal: to_principal(&request),
+        client: to_client_info(&request),
     })
 }
@@ -1,7 +1,31 @@
 use crate::application::data_definition::data_definition_use_case::DataDefinitionUseCase;
+use crate::grpc::data_definition::alter_table_properties_param::build_alter_table_properties_param;
+use crate::grpc::data_definition::create_table_alias_param::build_create_table_alias_param;
 use crate::grpc::data_definition::create_table_param::build_create_table_param;
-use crate::grpc::proto::{CreateTableRequest, CreateTableResponse, data_definition_service_server};
+use crate::grpc::data_definition::delete_stream_param::build_delete_stream_param;
+use crate::grpc::data_definition::describe_table_param::build_describe_table_param;
+use crate::grpc::data_definition::drop_table_alias_param::build_drop_table_alias_param;
+use crate::grpc::data_definition::drop_table_param::build_drop_table_param;
+use crate::grpc::data_definition::list_tables_param::parse_list_tables_param;
+use crate::grpc::data_definition::register_stream_param::build_register_stream_param;
+use crate::grpc::data_definition::rename_table_param::build_rename_table_param;
+use crate::grpc::data_definition::seal_stream_param::build_seal_stream_param;
+use crate::grpc::data_definition::table_info_response::{
+    build_list_tables_response, build_table_info,
+};
+use crate::grpc::data_definition::undrop_table_param::build_undrop_table_param;
+use crate::grpc::model::stream_info::build_stream_info;
+use crate::grpc::proto::{
+    AlterTablePropertiesRequest, AlterTablePropertiesResponse, CreateTableAliasRequest,
+    CreateTableAliasResponse, CreateTableRequest, CreateTableResponse, DeleteStreamRequest,
+    DeleteStreamResponse, DescribeTableRequest, DescribeTableResponse, DropTableAliasRequest,
+    DropTableAliasResponse, DropTableRequest, DropTableResponse, ListTablesRequest,
+    ListTablesResponse, RegisterStreamRequest, RegisterStreamResponse, RenameTableRequest,
+    RenameTableResponse, SealStreamRequest, SealStreamResponse, UndropTableRequest,
+    UndropTableResponse, data_definition_service_server,
+};
 use crate::grpc::util::error::{build_invalid_argument, to_grpc_error};
+use chrono::Duration;
 use sea_orm::DatabaseConnection;
 use tonic::{Request, Response, Status};
 
@@ -10,9 +34,9 @@ pub struct DataDefinitionService {
 }
 
 impl DataDefinitionService {
-    pub fn new(db: &DatabaseConnection) -> Self {
+    pub fn new(db: &DatabaseConnection, drop_grace_period: Duration) -> Self {
         Self {
-            data_definition_use_case: DataDefinitionUseCase::new(db.clone()),
+            data_definition_use_case: DataDefinitionUseCase::new(db.clone(), drop_grace_period),
         }
     }
 }
@@ -35,4 +59,188 @@ impl data_definition_service_server::DataDefinitionService for DataDefinitionSer
             table_name: table.name.val(),
         }))
     }
+
+    async fn list_tables(
+        &self,
+        request: Request<ListTablesRequest>,
+    ) -> Result<Response<ListTablesResponse>, Status> {
+        let (param, page_size) =
+            parse_list_tables_param(request).map_err(build_invalid_argument)?;
+
+        let tables = self
+            .data_definition_use_case
+            .list_tables(&param, (page_size + 1) as u64)
+            .await
+            .map_err(to_grpc_error)?;
+
+        let response = build_list_tables_response(page_size as usize, &tables);
+        Ok(Response::new(response))
+    }
+
+    async fn drop_table(
+        &self,
+        request: Request<DropTableRequest>,
+    ) -> Result<Response<DropTableResponse>, Status> {
+        let param = build_drop_table_param(request).map_err(build_invalid_argument)?;
+
+        let table = self
+            .data_definition_use_case
+            .drop_table(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        Ok(Response::new(DropTableResponse {
+            table: Some(build_table_info(&table)),
+        }))
+    }
+
+    async fn undrop_table(
+        &self,
+        request: Request<UndropTableRequest>,
+    ) -> Result<Response<UndropTableResponse>, Status> {
+        let param = build_undrop_table_param(request).map_err(build_invalid_argument)?;
+
+        let table = self
+            .data_definition_use_case
+            .undrop_table(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        Ok(Response::new(UndropTableResponse {
+            table: Some(build_table_info(&table)),
+        }))
+    }
+
+    async fn rename_table(
+        &self,
+        request: Request<RenameTableRequest>,
+    ) -> Result<Response<RenameTableResponse>, Status> {
+        let param = build_rename_table_param(request).map_err(build_invalid_argument)?;
+
+        let table = self
+            .data_definition_use_case
+            .rename_table(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        Ok(Response::new(RenameTableResponse {
+            table: Some(build_table_info(&table)),
+        }))
+    }
+
+    async fn create_table_alias(
+        &self,
+        request: Request<CreateTableAliasRequest>,
+    ) -> Result<Response<CreateTableAliasResponse>, Status> {
+        let param = build_create_table_alias_param(request).map_err(build_invalid_argument)?;
+
+        let table = self
+            .data_definition_use_case
+            .create_table_alias(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        Ok(Response::new(CreateTableAliasResponse {
+            table: Some(build_table_info(&table)),
+        }))
+    }
+
+    async fn drop_table_alias(
+        &self,
+        request: Request<DropTableAliasRequest>,
+    ) -> Result<Response<DropTableAliasResponse>, Status> {
+        let param = build_drop_table_alias_param(request).map_err(build_invalid_argument)?;
+
+        let table = self
+            .data_definition_use_case
+            .drop_table_alias(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        Ok(Response::new(DropTableAliasResponse {
+            table: Some(build_table_info(&table)),
+        }))
+    }
+
+    async fn describe_table(
+        &self,
+        request: Request<DescribeTableRequest>,
+    ) -> Result<Response<DescribeTableResponse>, Status> {
+        let param = build_describe_table_param(request).map_err(build_invalid_argument)?;
+
+        let table = self
+            .data_definition_use_case
+            .describe_table(&param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        Ok(Response::new(DescribeTableResponse {
+            table: Some(build_table_info(&table)),
+        }))
+    }
+
+    async fn alter_table_properties(
+        &self,
+        request: Request<AlterTablePropertiesRequest>,
+    ) -> Result<Response<AlterTablePropertiesResponse>, Status> {
+        let param = build_alter_table_properties_param(request).map_err(build_invalid_argument)?;
+
+        let table = self
+            .data_definition_use_case
+            .alter_table_properties(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        Ok(Response::new(AlterTablePropertiesResponse {
+            table: Some(build_table_info(&table)),
+        }))
+    }
+
+    async fn register_stream(
+        &self,
+        request: Request<RegisterStreamRequest>,
+    ) -> Result<Response<RegisterStreamResponse>, Status> {
+        let param = build_register_stream_param(request).map_err(build_invalid_argument)?;
+
+        let stream = self
+            .data_definition_use_case
+            .register_stream(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        Ok(Response::new(RegisterStreamResponse {
+            stream: Some(build_stream_info(&stream)),
+        }))
+    }
+
+    async fn seal_stream(
+        &self,
+        request: Request<SealStreamRequest>,
+    ) -> Result<Response<SealStreamResponse>, Status> {
+        let param = build_seal_stream_param(request).map_err(build_invalid_argument)?;
+
+        let stream = self
+            .data_definition_use_case
+            .seal_stream(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        Ok(Response::new(SealStreamResponse {
+            stream: Some(build_stream_info(&stream)),
+        }))
+    }
+
+    async fn delete_stream(
+        &self,
+        request: Request<DeleteStreamRequest>,
+    ) -> Result<Response<DeleteStreamResponse>, Status> {
+        let param = build_delete_stream_param(request).map_err(build_invalid_argument)?;
+
+        self.data_definition_use_case
+            .delete_stream(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        Ok(Response::new(DeleteStreamResponse {}))
+    }
 }
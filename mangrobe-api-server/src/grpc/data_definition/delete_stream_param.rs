@@ -0,0 +1,19 @@
+use crate::application::data_definition::DeleteStreamParam;
+use crate::grpc::proto::DeleteStreamRequest;
+use crate::grpc::util::param_util::{to_client_info, to_principal, to_table_name};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_delete_stream_param(
+    request: Request<DeleteStreamRequest>,
+) -> Result<DeleteStreamParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = to_table_name(req.table_name.clone())?;
+
+    Ok(DeleteStreamParam {
+        table_name,
+        stream_id: req.stream_id.into(),
+        principal: to_principal(&request),
+        client: to_client_info(&request),
+    })
+}
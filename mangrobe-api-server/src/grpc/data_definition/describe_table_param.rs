@@ -0,0 +1,17 @@
+use crate::application::data_definition::DescribeTableParam;
+use crate::grpc::proto::DescribeTableRequest;
+use crate::grpc::util::param_util::{to_principal, to_table_name};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_describe_table_param(
+    request: Request<DescribeTableRequest>,
+) -> Result<DescribeTableParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = to_table_name(req.table_name.clone())?;
+
+    Ok(DescribeTableParam {
+        table_name,
+        principal: to_principal(&request),
+    })
+}
@@ -0,0 +1,20 @@
+use crate::application::data_definition::DropTableAliasParam;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::grpc::proto::DropTableAliasRequest;
+use crate::grpc::util::param_util::{to_client_info, to_principal};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_drop_table_alias_param(
+    request: Request<DropTableAliasRequest>,
+) -> Result<DropTableAliasParam, ParameterError> {
+    let req = request.get_ref();
+    let alias = UserTableName::try_from(req.alias.clone())
+        .map_err(|e| ParameterError::Invalid("alias".into(), e))?;
+
+    Ok(DropTableAliasParam {
+        alias,
+        principal: to_principal(&request),
+        client: to_client_info(&request),
+    })
+}
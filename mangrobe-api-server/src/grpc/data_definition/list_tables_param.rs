@@ -0,0 +1,37 @@
+use crate::application::data_definition::ListTablesParam;
+use crate::grpc::model::page::build_page;
+use crate::grpc::proto::{ListTablesRequest, PaginationRequest};
+use crate::grpc::util::param_util::to_principal;
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+const DEFAULT_PAGE_SIZE: i32 = 1000;
+
+// The page token is the name of the last table in the previous page.
+pub(super) fn parse_list_tables_param(
+    request: Request<ListTablesRequest>,
+) -> Result<(ListTablesParam, i32), ParameterError> {
+    let req = request.get_ref();
+
+    let pagination = req.pagination.clone().unwrap_or(PaginationRequest {
+        size: 0,
+        token: None,
+    });
+    let page = build_page(&pagination, DEFAULT_PAGE_SIZE)?;
+
+    let table_name_after = match page.token {
+        Some(token) => Some(token.try_into().map_err(|_| {
+            ParameterError::Invalid("page_token".to_string(), "invalid".to_string())
+        })?),
+        None => None,
+    };
+
+    Ok((
+        ListTablesParam {
+            table_name_after,
+            include_dropped: req.include_dropped,
+            principal: to_principal(&request),
+        },
+        page.size,
+    ))
+}
@@ -0,0 +1,60 @@
+use crate::application::data_definition::RegisterStreamParam;
+use crate::domain::model::stream::{LABEL_LENGTH_LIMIT, StreamMetadata, TENANT_NAME_LENGTH_LIMIT};
+use crate::grpc::proto::RegisterStreamRequest;
+use crate::grpc::util::param_util::{to_client_info, to_principal, to_table_name};
+use crate::util::error::ParameterError;
+use std::collections::HashMap;
+use tonic::Request;
+
+pub(super) fn build_register_stream_param(
+    request: Request<RegisterStreamRequest>,
+) -> Result<RegisterStreamParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = to_table_name(req.table_name.clone())?;
+    let metadata = to_stream_metadata(&req.tenant_name, &req.labels)?;
+
+    Ok(RegisterStreamParam {
+        table_name,
+        stream_id: req.stream_id.into(),
+        metadata,
+        skip_if_exists: req.skip_if_exists,
+        principal: to_principal(&request),
+        client: to_client_info(&request),
+    })
+}
+
+fn to_stream_metadata(
+    tenant_name: &str,
+    labels: &HashMap<String, String>,
+) -> Result<StreamMetadata, ParameterError> {
+    if tenant_name.chars().count() >= TENANT_NAME_LENGTH_LIMIT {
+        return Err(ParameterError::Invalid(
+            "tenant_name".to_string(),
+            format!(
+                "must be shorter than {} characters",
+                TENANT_NAME_LENGTH_LIMIT
+            ),
+        ));
+    }
+
+    for (key, value) in labels {
+        if key.is_empty() {
+            return Err(ParameterError::Invalid(
+                "labels".to_string(),
+                "key must not be empty".to_string(),
+            ));
+        }
+        if key.chars().count() >= LABEL_LENGTH_LIMIT || value.chars().count() >= LABEL_LENGTH_LIMIT
+        {
+            return Err(ParameterError::Invalid(
+                format!("labels.{}", key),
+                format!("must be shorter than {} characters", LABEL_LENGTH_LIMIT),
+            ));
+        }
+    }
+
+    Ok(StreamMetadata {
+        tenant_name: Some(tenant_name.to_string()).filter(|v| !v.is_empty()),
+        labels: labels.clone().into_iter().collect(),
+    })
+}
@@ -0,0 +1,23 @@
+use crate::application::data_definition::RenameTableParam;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::grpc::proto::RenameTableRequest;
+use crate::grpc::util::param_util::{to_client_info, to_principal, to_table_name};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_rename_table_param(
+    request: Request<RenameTableRequest>,
+) -> Result<RenameTableParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = to_table_name(req.table_name.clone())?;
+    let new_table_name = UserTableName::try_from(req.new_table_name.clone())
+        .map_err(|e| ParameterError::Invalid("new_table_name".into(), e))?;
+
+    Ok(RenameTableParam {
+        table_name,
+        new_table_name,
+        keep_alias: req.keep_alias,
+        principal: to_principal(&request),
+        client: to_client_info(&request),
+    })
+}
@@ -0,0 +1,19 @@
+use crate::application::data_definition::SealStreamParam;
+use crate::grpc::proto::SealStreamRequest;
+use crate::grpc::util::param_util::{to_client_info, to_principal, to_table_name};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_seal_stream_param(
+    request: Request<SealStreamRequest>,
+) -> Result<SealStreamParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = to_table_name(req.table_name.clone())?;
+
+    Ok(SealStreamParam {
+        table_name,
+        stream_id: req.stream_id.into(),
+        principal: to_principal(&request),
+        client: to_client_info(&request),
+    })
+}
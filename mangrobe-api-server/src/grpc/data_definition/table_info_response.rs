@@ -0,0 +1,36 @@
+use crate::domain::model::user_table::UserTable;
+use crate::grpc::model::timestamp::build_timestamp;
+use crate::grpc::proto::{ListTablesResponse, PaginationResponse, TableInfo};
+
+pub(super) fn build_list_tables_response(
+    page_size: usize,
+    tables: &[UserTable],
+) -> ListTablesResponse {
+    let pagination = if tables.len() > page_size {
+        Some(PaginationResponse {
+            next_token: tables[page_size - 1].name.val(),
+        })
+    } else {
+        None
+    };
+
+    ListTablesResponse {
+        pagination,
+        tables: tables
+            .iter()
+            .take(page_size)
+            .map(build_table_info)
+            .collect(),
+    }
+}
+
+pub(super) fn build_table_info(table: &UserTable) -> TableInfo {
+    TableInfo {
+        table_name: table.name.val(),
+        created_at: Some(build_timestamp(&table.created_at)),
+        dropped_at: table.dropped_at.as_ref().map(build_timestamp),
+        purge_after: table.purge_after.as_ref().map(build_timestamp),
+        aliases: table.aliases.iter().map(|a| a.val()).collect(),
+        properties: table.properties.values.clone().into_iter().collect(),
+    }
+}
@@ -0,0 +1,18 @@
+use crate::application::data_definition::UndropTableParam;
+use crate::grpc::proto::UndropTableRequest;
+use crate::grpc::util::param_util::{to_client_info, to_principal, to_table_name};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_undrop_table_param(
+    request: Request<UndropTableRequest>,
+) -> Result<UndropTableParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = to_table_name(req.table_name.clone())?;
+
+    Ok(UndropTableParam {
+        table_name,
+        principal: to_principal(&request),
+        client: to_client_info(&request),
+    })
+}
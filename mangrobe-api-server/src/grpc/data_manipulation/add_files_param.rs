@@ -4,7 +4,10 @@ use crate::domain::model::file::FileEntry;
 use crate::domain::model::file_column_statistics::FileColumnStatistics;
 use crate::domain::model::file_metadata::FileMetadata;
 use crate::grpc::proto::AddFilesRequest;
-use crate::grpc::util::param_util::{to_idempotency_key, to_partition_time, to_table_name};
+use crate::grpc::util::param_util::{
+    to_client_info, to_expected_commit_id, to_idempotency_key, to_partition_time,
+    to_partition_values, to_principal, to_table_name,
+};
 use crate::util::error::ParameterError;
 use tonic::Request;
 
@@ -13,6 +16,7 @@ pub(super) fn build_add_files_param(
 ) -> Result<AddFilesParam, ParameterError> {
     let req = request.get_ref();
     let table_name = to_table_name(req.table_name.clone())?;
+    let expected_commit_id = to_expected_commit_id(&req.expected_commit_id)?;
 
     let idempotency_key = to_idempotency_key(req.idempotency_key.clone())?;
 
@@ -47,6 +51,7 @@ pub(super) fn build_add_files_param(
                         f.size,
                         stats,
                         file_metadata,
+                        to_partition_values("partition_values", &f.partition_values)?,
                     ))
                 })
                 .collect::<Result<Vec<_>, _>>()?,
@@ -58,6 +63,9 @@ pub(super) fn build_add_files_param(
         table_name,
         stream_id: req.stream_id.into(),
         entries,
+        expected_commit_id,
+        principal: to_principal(&request),
+        client: to_client_info(&request),
     };
     Ok(param)
 }
@@ -1,7 +1,10 @@
 use crate::application::data_manipulation::change_files_param::ChangeFilesParam;
 use crate::domain::model::change_request_raw_file_entry::ChangeRequestRawChangeFilesEntry;
 use crate::grpc::proto::ChangeFilesRequest;
-use crate::grpc::util::param_util::{to_file_lock_key, to_partition_time, to_table_name};
+use crate::grpc::util::param_util::{
+    to_client_info, to_expected_commit_id, to_file_lock_key, to_partition_time, to_principal,
+    to_table_name,
+};
 use crate::util::error::ParameterError;
 use chrono::{DateTime, Utc};
 use tonic::Request;
@@ -12,6 +15,7 @@ pub(super) fn build_change_file_param(
 ) -> Result<ChangeFilesParam, ParameterError> {
     let req = request.get_ref();
     let table_name = to_table_name(req.table_name.clone())?;
+    let expected_commit_id = to_expected_commit_id(&req.expected_commit_id)?;
 
     let file_lock_key = to_file_lock_key(req.file_lock_key.clone(), request_started_at)?;
 
@@ -33,6 +37,9 @@ pub(super) fn build_change_file_param(
         table_name,
         stream_id: req.stream_id.into(),
         entries,
+        expected_commit_id,
+        principal: to_principal(&request),
+        client: to_client_info(&request),
     };
     Ok(param)
 }
@@ -1,12 +1,15 @@
 use crate::application::data_manipulation::compact_files_param::CompactFilesParam;
 use crate::domain::model::change_request_raw_file_entry::{
-    ChangeRequestRawCompactFileInfoEntry, ChangeRequestRawCompactFilesEntry,
+    ChangeRequestRawCompactFileInfoEntry, PartitionedFileEntry, PartitionedFilePath,
 };
 use crate::domain::model::file::FileEntry;
 use crate::domain::model::file_column_statistics::FileColumnStatistics;
 use crate::domain::model::file_metadata::FileMetadata;
-use crate::grpc::proto::CompactFilesRequest;
-use crate::grpc::util::param_util::{to_file_lock_key, to_partition_time, to_table_name};
+use crate::grpc::proto::{CompactFileDstEntry, CompactFilesRequest};
+use crate::grpc::util::param_util::{
+    to_client_info, to_expected_commit_id, to_file_lock_key, to_optional_date_time,
+    to_partition_time, to_partition_values, to_principal, to_table_name,
+};
 use crate::util::error::ParameterError;
 use chrono::{DateTime, Utc};
 use tonic::Request;
@@ -17,6 +20,7 @@ pub(super) fn build_compact_files_param(
 ) -> Result<CompactFilesParam, ParameterError> {
     let req = request.get_ref();
     let table_name = to_table_name(req.table_name.clone())?;
+    let expected_commit_id = to_expected_commit_id(&req.expected_commit_id)?;
 
     let file_lock_key = to_file_lock_key(req.file_lock_key.clone(), request_started_at)?;
 
@@ -24,50 +28,42 @@ pub(super) fn build_compact_files_param(
     for entry in req.compact_file_entries.iter() {
         let partition_time = to_partition_time(entry.partition_time)?;
 
-        let mut file_info_entries = vec![];
         for info_entry in entry.file_info_entries.iter() {
-            let src_file_paths: Vec<_> = info_entry
-                .src_entries
-                .iter()
-                .map(|f| f.path.clone().into())
-                .collect();
-            if src_file_paths.is_empty() {
+            let mut src_files = Vec::with_capacity(info_entry.src_entries.len());
+            for src_entry in &info_entry.src_entries {
+                let src_partition_time =
+                    to_optional_date_time("src_entries.partition_time", src_entry.partition_time)?
+                        .unwrap_or(partition_time);
+                src_files.push(PartitionedFilePath::new(
+                    src_partition_time,
+                    src_entry.path.clone().into(),
+                ));
+            }
+            if src_files.is_empty() {
                 return Err(ParameterError::Required("src_file_entries".to_string()));
             }
 
-            let Some(ref req_dst_file) = info_entry.dst_entry else {
-                return Err(ParameterError::Required("dst_file_entry".to_string()));
-            };
-
-            let mut dst_stats = Vec::with_capacity(req_dst_file.column_statistics.len());
-            for statistics in &req_dst_file.column_statistics {
-                if statistics.column_name.is_empty() {
-                    return Err(ParameterError::Required("column_name".to_string()));
-                }
-                dst_stats.push(FileColumnStatistics::new(
-                    statistics.column_name.clone(),
-                    statistics.min,
-                    statistics.max,
+            // dst_entry is kept for clients that write a single destination.
+            if info_entry.dst_entry.is_some() && !info_entry.dst_entries.is_empty() {
+                return Err(ParameterError::Invalid(
+                    "dst_entries".to_string(),
+                    "must not be set together with dst_entry".to_string(),
                 ));
             }
-            let dst_file = FileEntry::new(
-                req_dst_file.path.clone().into(),
-                req_dst_file.size,
-                dst_stats,
-                req_dst_file
-                    .file_metadata
-                    .as_ref()
-                    .map(|metadata| FileMetadata::new(metadata.parquet_metadata.clone())),
-            );
-            file_info_entries.push(ChangeRequestRawCompactFileInfoEntry::new(
-                src_file_paths,
-                dst_file,
+            let dst_files = info_entry
+                .dst_entry
+                .iter()
+                .chain(info_entry.dst_entries.iter())
+                .map(|f| build_dst_file(f, partition_time))
+                .collect::<Result<Vec<_>, _>>()?;
+            if dst_files.is_empty() {
+                return Err(ParameterError::Required("dst_entries".to_string()));
+            }
+
+            entries.push(ChangeRequestRawCompactFileInfoEntry::new(
+                src_files, dst_files,
             ))
         }
-        entries.push(ChangeRequestRawCompactFilesEntry::new(
-            partition_time,
-            file_info_entries,
-        ))
     }
 
     let param = CompactFilesParam {
@@ -75,6 +71,42 @@ pub(super) fn build_compact_files_param(
         table_name,
         stream_id: req.stream_id.into(),
         entries,
+        expected_commit_id,
+        principal: to_principal(&request),
+        client: to_client_info(&request),
     };
     Ok(param)
 }
+
+fn build_dst_file(
+    req_dst_file: &CompactFileDstEntry,
+    default_partition_time: DateTime<Utc>,
+) -> Result<PartitionedFileEntry, ParameterError> {
+    let partition_time =
+        to_optional_date_time("dst_entries.partition_time", req_dst_file.partition_time)?
+            .unwrap_or(default_partition_time);
+
+    let mut stats = Vec::with_capacity(req_dst_file.column_statistics.len());
+    for statistics in &req_dst_file.column_statistics {
+        if statistics.column_name.is_empty() {
+            return Err(ParameterError::Required("column_name".to_string()));
+        }
+        stats.push(FileColumnStatistics::new(
+            statistics.column_name.clone(),
+            statistics.min,
+            statistics.max,
+        ));
+    }
+    let file = FileEntry::new(
+        req_dst_file.path.clone().into(),
+        req_dst_file.size,
+        stats,
+        req_dst_file
+            .file_metadata
+            .as_ref()
+            .map(|metadata| FileMetadata::new(metadata.parquet_metadata.clone())),
+        to_partition_values("partition_values", &req_dst_file.partition_values)?,
+    );
+
+    Ok(PartitionedFileEntry::new(partition_time, file))
+}
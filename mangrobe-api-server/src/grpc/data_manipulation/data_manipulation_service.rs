@@ -1,4 +1,5 @@
 use crate::application::data_manipulation::data_manipulation_use_case::DataManipulationUseCase;
+use crate::domain::model::quota::QuotaPolicy;
 use crate::grpc::data_manipulation::add_files_param::build_add_files_param;
 use crate::grpc::data_manipulation::build_file_info_response::build_file_info_response;
 use crate::grpc::data_manipulation::change_files_param::build_change_file_param;
@@ -7,9 +8,10 @@ use crate::grpc::data_manipulation::get_changes_param::build_get_commits_param;
 use crate::grpc::data_manipulation::get_changes_response::build_get_commits_response;
 use crate::grpc::data_manipulation::get_current_state_param::build_get_current_state_param;
 use crate::grpc::data_manipulation::get_file_info_param::build_get_file_info_param;
+use crate::grpc::model::file::build_file;
 use crate::grpc::proto::{
     AddFilesRequest, AddFilesResponse, ChangeFilesRequest, ChangeFilesResponse,
-    CompactFilesRequest, CompactFilesResponse, File, GetCommitsRequest, GetCommitsResponse,
+    CompactFilesRequest, CompactFilesResponse, GetCommitsRequest, GetCommitsResponse,
     GetCurrentStateRequest, GetCurrentStateResponse, GetFileInfoRequest, GetFileInfoResponse,
     data_manipulation_service_server,
 };
@@ -25,8 +27,8 @@ pub struct DataManipulationService {
 }
 
 impl DataManipulationService {
-    pub fn new(db: &DatabaseConnection) -> Self {
-        let data_manipulation_use_case = DataManipulationUseCase::new(db.clone());
+    pub fn new(db: &DatabaseConnection, quota_policy: QuotaPolicy) -> Self {
+        let data_manipulation_use_case = DataManipulationUseCase::new(db.clone(), quota_policy);
         Self {
             data_manipulation_use_case,
         }
@@ -51,15 +53,7 @@ impl data_manipulation_service_server::DataManipulationService for DataManipulat
             commit_id: snapshot
                 .commit_id
                 .map_or_else(|| None, |v| Some(v.to_string())),
-            files: snapshot
-                .files
-                .iter()
-                .map(|f| File {
-                    file_id: f.id.val().to_string(),
-                    path: f.file.path.path(),
-                    size: f.file.size,
-                })
-                .collect(),
+            files: snapshot.files.iter().map(build_file).collect(),
         };
 
         Ok(Response::new(response))
@@ -1,6 +1,6 @@
 use crate::application::data_manipulation::get_changes_param::GetChangesParam;
 use crate::grpc::proto::GetCommitsRequest;
-use crate::grpc::util::param_util::to_table_name;
+use crate::grpc::util::param_util::{to_principal, to_table_name};
 use crate::util::error::ParameterError;
 use tonic::Request;
 
@@ -29,5 +29,6 @@ pub(super) fn build_get_commits_param(
         table_name,
         stream_id: req.stream_id.into(),
         commit_id_after: commit_id_after.into(),
+        principal: to_principal(&request),
     })
 }
@@ -43,7 +43,8 @@ fn build_changes(file_data: &ChangeRequestFileData) -> Option<Changes> {
                 .iter()
                 .map(|compact| CompactedFile {
                     src_files: build_committed_files(&compact.src_files),
-                    dst_file: Some(build_committed_file(&compact.dst_file)),
+                    dst_file: compact.dst_files.first().map(build_committed_file),
+                    dst_files: build_committed_files(&compact.dst_files),
                 })
                 .collect(),
         }),
@@ -1,6 +1,6 @@
 use crate::application::data_manipulation::get_current_state_param::GetCurrentStateParam;
 use crate::grpc::proto::GetCurrentStateRequest;
-use crate::grpc::util::param_util::to_table_name;
+use crate::grpc::util::param_util::{to_partition_values, to_principal, to_table_name};
 use crate::util::error::ParameterError;
 use tonic::Request;
 
@@ -13,6 +13,8 @@ pub(super) fn build_get_current_state_param(
     let param = GetCurrentStateParam {
         table_name,
         stream_id: req.stream_id.into(),
+        partition_filter: to_partition_values("partition_filter", &req.partition_filter)?,
+        principal: to_principal(&request),
     };
     Ok(param)
 }
@@ -3,6 +3,7 @@ use crate::application::data_manipulation::get_file_info_param::{
 };
 use crate::domain::model::file_id::FileId;
 use crate::grpc::proto::{FileColumnStatisticsType, FileMetadataType, GetFileInfoRequest};
+use crate::grpc::util::param_util::to_principal;
 use crate::util::error::ParameterError;
 use tonic::Request;
 
@@ -65,5 +66,6 @@ pub(super) fn build_get_file_info_param(
         file_ids,
         stat_types: FileStatisticsSelection::new(include_min, include_max),
         metadata_types: FileMetadataSelection::new(include_parquet_metadata),
+        principal: to_principal(&request),
     })
 }
@@ -0,0 +1,62 @@
+use crate::application::monitoring::monitoring_use_case::MonitoringUseCase;
+use crate::grpc::proto::{
+    access_control_service_server, data_definition_service_server,
+    data_manipulation_service_server, information_schema_service_server,
+    lock_control_service_server,
+};
+use sea_orm::DatabaseConnection;
+use std::time::Duration;
+use tonic_health::ServingStatus;
+use tonic_health::server::HealthReporter;
+use tracing::{info, warn};
+
+// "" is the status of the whole server.
+const SERVICE_NAMES: [&str; 6] = [
+    "",
+    data_manipulation_service_server::SERVICE_NAME,
+    data_definition_service_server::SERVICE_NAME,
+    lock_control_service_server::SERVICE_NAME,
+    information_schema_service_server::SERVICE_NAME,
+    access_control_service_server::SERVICE_NAME,
+];
+
+// Reflects the database state to grpc.health.v1. Every service depends on the database, so they share the status.
+pub struct HealthChecker {
+    reporter: HealthReporter,
+    monitoring_use_case: MonitoringUseCase,
+}
+
+impl HealthChecker {
+    pub fn new(reporter: HealthReporter, db: &DatabaseConnection) -> Self {
+        Self {
+            reporter,
+            monitoring_use_case: MonitoringUseCase::new(db.clone()),
+        }
+    }
+
+    pub async fn run(&self, interval: Duration) {
+        let mut last_status = None;
+        loop {
+            let status = match self.monitoring_use_case.check_health().await {
+                Ok(()) => ServingStatus::Serving,
+                Err(e) => {
+                    warn!(error = ?e, "health check failed");
+                    ServingStatus::NotServing
+                }
+            };
+            if last_status != Some(status) {
+                info!("Health status changed to {:?}", status);
+                self.set_status(status).await;
+                last_status = Some(status);
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    pub async fn set_status(&self, status: ServingStatus) {
+        for service_name in SERVICE_NAMES {
+            self.reporter.set_service_status(service_name, status).await;
+        }
+    }
+}
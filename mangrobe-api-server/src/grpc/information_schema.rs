@@ -1,4 +1,9 @@
+mod file_stats_response;
+mod get_stream_stats_param;
+mod get_table_stats_param;
 pub mod information_schema_service;
+mod list_audit_logs_param;
+mod list_audit_logs_response;
 mod list_stream_page_token;
 pub mod list_streams_param;
 mod list_streams_response;
@@ -0,0 +1,50 @@
+use crate::domain::model::file_stats::FileStats;
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::grpc::model::timestamp::build_timestamp;
+use crate::grpc::proto::{
+    FileStats as FileStatsResponse, FilesPerPartitionBucket, GetStreamStatsResponse,
+    GetTableStatsResponse,
+};
+
+pub(super) fn build_get_table_stats_response(
+    table_name: &UserTableName,
+    stats: &FileStats,
+) -> GetTableStatsResponse {
+    GetTableStatsResponse {
+        table_name: table_name.val(),
+        stream_count: stats.stream_count as i64,
+        stats: Some(build_file_stats(stats)),
+    }
+}
+
+pub(super) fn build_get_stream_stats_response(
+    table_name: &UserTableName,
+    stream_id: &StreamId,
+    stats: &FileStats,
+) -> GetStreamStatsResponse {
+    GetStreamStatsResponse {
+        table_name: table_name.val(),
+        stream_id: stream_id.val(),
+        stats: Some(build_file_stats(stats)),
+    }
+}
+
+fn build_file_stats(stats: &FileStats) -> FileStatsResponse {
+    FileStatsResponse {
+        file_count: stats.file_count as i64,
+        total_bytes: stats.total_bytes as i64,
+        min_partition_time: stats.min_partition_time.as_ref().map(build_timestamp),
+        max_partition_time: stats.max_partition_time.as_ref().map(build_timestamp),
+        partition_count: stats.partition_count as i64,
+        last_committed_at: stats.last_committed_at.as_ref().map(build_timestamp),
+        files_per_partition: stats
+            .files_per_partition
+            .iter()
+            .map(|bucket| FilesPerPartitionBucket {
+                max_files: bucket.max_files.map(|max_files| max_files as i64),
+                partition_count: bucket.partition_count as i64,
+            })
+            .collect(),
+    }
+}
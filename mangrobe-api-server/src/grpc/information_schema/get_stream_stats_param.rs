@@ -0,0 +1,18 @@
+use crate::application::information_schema::get_stream_stats_param::GetStreamStatsParam;
+use crate::grpc::proto::GetStreamStatsRequest;
+use crate::grpc::util::param_util::{to_principal, to_table_name};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_get_stream_stats_param(
+    request: Request<GetStreamStatsRequest>,
+) -> Result<GetStreamStatsParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = to_table_name(req.table_name.clone())?;
+
+    Ok(GetStreamStatsParam {
+        table_name,
+        stream_id: req.stream_id.into(),
+        principal: to_principal(&request),
+    })
+}
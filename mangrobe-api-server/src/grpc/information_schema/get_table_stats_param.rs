@@ -0,0 +1,17 @@
+use crate::application::information_schema::get_table_stats_param::GetTableStatsParam;
+use crate::grpc::proto::GetTableStatsRequest;
+use crate::grpc::util::param_util::{to_principal, to_table_name};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+pub(super) fn build_get_table_stats_param(
+    request: Request<GetTableStatsRequest>,
+) -> Result<GetTableStatsParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = to_table_name(req.table_name.clone())?;
+
+    Ok(GetTableStatsParam {
+        table_name,
+        principal: to_principal(&request),
+    })
+}
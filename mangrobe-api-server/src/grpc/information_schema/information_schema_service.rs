@@ -1,8 +1,17 @@
 use crate::application::information_schema::information_schema_use_case::InformationSchemaUseCase;
+use crate::grpc::information_schema::file_stats_response::{
+    build_get_stream_stats_response, build_get_table_stats_response,
+};
+use crate::grpc::information_schema::get_stream_stats_param::build_get_stream_stats_param;
+use crate::grpc::information_schema::get_table_stats_param::build_get_table_stats_param;
+use crate::grpc::information_schema::list_audit_logs_param::parse_list_audit_logs_param;
+use crate::grpc::information_schema::list_audit_logs_response::build_list_audit_logs_response;
 use crate::grpc::information_schema::list_streams_param::parse_list_streams_param;
 use crate::grpc::information_schema::list_streams_response::build_list_streams_response;
 use crate::grpc::proto::{
-    ListStreamsRequest, ListStreamsResponse, information_schema_service_server,
+    GetStreamStatsRequest, GetStreamStatsResponse, GetTableStatsRequest, GetTableStatsResponse,
+    ListAuditLogsRequest, ListAuditLogsResponse, ListStreamsRequest, ListStreamsResponse,
+    information_schema_service_server,
 };
 use crate::grpc::util::error::{build_invalid_argument, to_grpc_error};
 use sea_orm::DatabaseConnection;
@@ -35,7 +44,61 @@ impl information_schema_service_server::InformationSchemaService for Information
             .await
             .map_err(to_grpc_error)?;
 
-        let response = build_list_streams_response(&param.table_name, page_size as usize, &streams);
+        let response = build_list_streams_response(
+            &param.table_name,
+            param.descending,
+            page_size as usize,
+            &streams,
+        );
+        Ok(Response::new(response))
+    }
+
+    async fn list_audit_logs(
+        &self,
+        request: Request<ListAuditLogsRequest>,
+    ) -> Result<Response<ListAuditLogsResponse>, Status> {
+        let (param, page_size) =
+            parse_list_audit_logs_param(request).map_err(build_invalid_argument)?;
+
+        let logs = self
+            .information_schema_use_case
+            .list_audit_logs(&param, (page_size + 1) as u64)
+            .await
+            .map_err(to_grpc_error)?;
+
+        let response = build_list_audit_logs_response(page_size as usize, &logs);
+        Ok(Response::new(response))
+    }
+
+    async fn get_table_stats(
+        &self,
+        request: Request<GetTableStatsRequest>,
+    ) -> Result<Response<GetTableStatsResponse>, Status> {
+        let param = build_get_table_stats_param(request).map_err(build_invalid_argument)?;
+
+        let stats = self
+            .information_schema_use_case
+            .get_table_stats(&param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        let response = build_get_table_stats_response(&param.table_name, &stats);
+        Ok(Response::new(response))
+    }
+
+    async fn get_stream_stats(
+        &self,
+        request: Request<GetStreamStatsRequest>,
+    ) -> Result<Response<GetStreamStatsResponse>, Status> {
+        let param = build_get_stream_stats_param(request).map_err(build_invalid_argument)?;
+
+        let stats = self
+            .information_schema_use_case
+            .get_stream_stats(&param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        let response = build_get_stream_stats_response(&param.table_name, &param.stream_id, &stats);
         Ok(Response::new(response))
     }
 }
@@ -0,0 +1,100 @@
+use crate::application::information_schema::list_audit_logs_param::ListAuditLogsParam;
+use crate::domain::model::audit_log::{AuditLogFilter, AuditOperation, AuditOutcome};
+use crate::grpc::model::page::build_page;
+use crate::grpc::proto::{
+    AuditOperation as AuditOperationParam, AuditOutcome as AuditOutcomeParam, ListAuditLogsRequest,
+    PaginationRequest,
+};
+use crate::grpc::util::param_util::{to_optional_date_time, to_principal, to_table_name};
+use crate::util::error::ParameterError;
+use tonic::Request;
+
+const DEFAULT_PAGE_SIZE: i32 = 100;
+
+pub(super) fn parse_list_audit_logs_param(
+    request: Request<ListAuditLogsRequest>,
+) -> Result<(ListAuditLogsParam, i32), ParameterError> {
+    let req = request.get_ref();
+    let table_name = req.table_name.clone().map(to_table_name).transpose()?;
+
+    let pagination = req.pagination.clone().unwrap_or(PaginationRequest {
+        size: 0,
+        token: None,
+    });
+    let page = build_page(&pagination, DEFAULT_PAGE_SIZE)?;
+
+    // The token is the id of the last entry of the previous page.
+    let audit_log_id_before = match page.token {
+        Some(token) => Some(
+            token
+                .parse::<i64>()
+                .map_err(|_| invalid_page_token())?
+                .into(),
+        ),
+        None => None,
+    };
+
+    let filter = AuditLogFilter {
+        table_name,
+        stream_id: req.stream_id.map(|v| v.into()),
+        principal_id: req.principal_id.clone().filter(|v| !v.is_empty()),
+        operation: to_audit_operation(req.operation)?,
+        outcome: to_audit_outcome(req.outcome)?,
+        since: to_optional_date_time("since", req.since)?,
+        until: to_optional_date_time("until", req.until)?,
+    };
+
+    Ok((
+        ListAuditLogsParam {
+            filter,
+            audit_log_id_before,
+            principal: to_principal(&request),
+        },
+        page.size,
+    ))
+}
+
+fn to_audit_operation(operation: i32) -> Result<Option<AuditOperation>, ParameterError> {
+    match AuditOperationParam::try_from(operation) {
+        Ok(AuditOperationParam::Unspecified) => Ok(None),
+        Ok(AuditOperationParam::CreateTable) => Ok(Some(AuditOperation::CreateTable)),
+        Ok(AuditOperationParam::AddFiles) => Ok(Some(AuditOperation::AddFiles)),
+        Ok(AuditOperationParam::ChangeFiles) => Ok(Some(AuditOperation::ChangeFiles)),
+        Ok(AuditOperationParam::CompactFiles) => Ok(Some(AuditOperation::CompactFiles)),
+        Ok(AuditOperationParam::AcquireFileLock) => Ok(Some(AuditOperation::AcquireFileLock)),
+        Ok(AuditOperationParam::ReleaseFileLock) => Ok(Some(AuditOperation::ReleaseFileLock)),
+        Ok(AuditOperationParam::DropTable) => Ok(Some(AuditOperation::DropTable)),
+        Ok(AuditOperationParam::UndropTable) => Ok(Some(AuditOperation::UndropTable)),
+        Ok(AuditOperationParam::RegisterStream) => Ok(Some(AuditOperation::RegisterStream)),
+        Ok(AuditOperationParam::SealStream) => Ok(Some(AuditOperation::SealStream)),
+        Ok(AuditOperationParam::DeleteStream) => Ok(Some(AuditOperation::DeleteStream)),
+        Ok(AuditOperationParam::RenameTable) => Ok(Some(AuditOperation::RenameTable)),
+        Ok(AuditOperationParam::CreateTableAlias) => Ok(Some(AuditOperation::CreateTableAlias)),
+        Ok(AuditOperationParam::DropTableAlias) => Ok(Some(AuditOperation::DropTableAlias)),
+        Ok(AuditOperationParam::AlterTableProperties) => {
+            Ok(Some(AuditOperation::AlterTableProperties))
+        }
+        Ok(AuditOperationParam::PurgeTable) => Ok(Some(AuditOperation::PurgeTable)),
+        Err(_) => Err(ParameterError::Invalid(
+            "operation".to_string(),
+            "unknown value".to_string(),
+        )),
+    }
+}
+
+fn to_audit_outcome(outcome: i32) -> Result<Option<AuditOutcome>, ParameterError> {
+    match AuditOutcomeParam::try_from(outcome) {
+        Ok(AuditOutcomeParam::Unspecified) => Ok(None),
+        Ok(AuditOutcomeParam::Succeeded) => Ok(Some(AuditOutcome::Succeeded)),
+        Ok(AuditOutcomeParam::Denied) => Ok(Some(AuditOutcome::Denied)),
+        Ok(AuditOutcomeParam::Failed) => Ok(Some(AuditOutcome::Failed)),
+        Err(_) => Err(ParameterError::Invalid(
+            "outcome".to_string(),
+            "unknown value".to_string(),
+        )),
+    }
+}
+
+fn invalid_page_token() -> ParameterError {
+    ParameterError::Invalid("page_token".to_string(), "invalid".to_string())
+}
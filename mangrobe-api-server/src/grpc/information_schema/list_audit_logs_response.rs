@@ -0,0 +1,74 @@
+use crate::domain::model::audit_log::{AuditLog, AuditOperation, AuditOutcome};
+use crate::grpc::model::timestamp::build_timestamp;
+use crate::grpc::proto::{
+    AuditLogEntry, AuditOperation as AuditOperationResponse, AuditOutcome as AuditOutcomeResponse,
+    ListAuditLogsResponse, PaginationResponse,
+};
+
+pub(super) fn build_list_audit_logs_response(
+    page_size: usize,
+    logs: &[AuditLog],
+) -> ListAuditLogsResponse {
+    let pagination = if logs.len() > page_size {
+        Some(PaginationResponse {
+            next_token: logs[page_size - 1].id.val().to_string(),
+        })
+    } else {
+        None
+    };
+
+    ListAuditLogsResponse {
+        pagination,
+        entries: logs
+            .iter()
+            .take(page_size)
+            .map(build_audit_log_entry)
+            .collect(),
+    }
+}
+
+fn build_audit_log_entry(log: &AuditLog) -> AuditLogEntry {
+    let event = &log.event;
+    AuditLogEntry {
+        audit_log_id: log.id.val().to_string(),
+        operation: to_audit_operation(event.operation).into(),
+        principal_id: event.principal_id.clone(),
+        client_address: event.client.address.clone(),
+        request_id: event.client.request_id.clone(),
+        table_name: event.table_name.as_ref().map(|t| t.val()),
+        stream_id: event.stream_id.as_ref().map(|s| s.val()),
+        outcome: to_audit_outcome(log.outcome).into(),
+        error_message: log.error_message.clone(),
+        file_lock_key: event.file_lock_key.clone(),
+        created_at: Some(build_timestamp(&log.created_at)),
+    }
+}
+
+fn to_audit_operation(operation: AuditOperation) -> AuditOperationResponse {
+    match operation {
+        AuditOperation::CreateTable => AuditOperationResponse::CreateTable,
+        AuditOperation::AddFiles => AuditOperationResponse::AddFiles,
+        AuditOperation::ChangeFiles => AuditOperationResponse::ChangeFiles,
+        AuditOperation::CompactFiles => AuditOperationResponse::CompactFiles,
+        AuditOperation::AcquireFileLock => AuditOperationResponse::AcquireFileLock,
+        AuditOperation::ReleaseFileLock => AuditOperationResponse::ReleaseFileLock,
+        AuditOperation::DropTable => AuditOperationResponse::DropTable,
+        AuditOperation::UndropTable => AuditOperationResponse::UndropTable,
+        AuditOperation::RegisterStream => AuditOperationResponse::RegisterStream,
+        AuditOperation::SealStream => AuditOperationResponse::SealStream,
+        AuditOperation::DeleteStream => AuditOperationResponse::DeleteStream,
+        AuditOperation::RenameTable => AuditOperationResponse::RenameTable,
+        AuditOperation::CreateTableAlias => AuditOperationResponse::CreateTableAlias,
+        AuditOperation::DropTableAlias => AuditOperationResponse::DropTableAlias,
+        AuditOperation::AlterTableProperties => AuditOperationResponse::AlterTableProperties,
+        AuditOperation::PurgeTable => AuditOperationResponse::PurgeTable,
+    }
+}
+
+fn to_audit_outcome(outcome: AuditOutcome) -> AuditOutcomeResponse {
+    match outcome {
+        AuditOutcome::Succeeded => AuditOutcomeResponse::Succeeded,
+        AuditOutcome::Denied => AuditOutcomeResponse::Denied,
+        AuditOutcome::Failed => AuditOutcomeResponse::Failed,
+    }
+}
@@ -1,16 +1,20 @@
 use crate::domain::model::stream_id::StreamId;
 use crate::domain::model::user_table_name::UserTableName;
 
+const DESCENDING_SUFFIX: &str = "desc";
+
 pub(super) struct ListStreamPageToken {
     pub(super) table_name: UserTableName,
     pub(super) stream_id: StreamId,
+    pub(super) descending: bool,
 }
 
 impl ListStreamPageToken {
-    pub(super) fn new(table_name: UserTableName, stream_id: StreamId) -> Self {
+    pub(super) fn new(table_name: UserTableName, stream_id: StreamId, descending: bool) -> Self {
         Self {
             table_name,
             stream_id,
+            descending,
         }
     }
 
@@ -18,6 +22,11 @@ impl ListStreamPageToken {
         let mut parts = token.split(':');
         let token_table_name = parts.next()?;
         let token_stream_id = parts.next()?;
+        let descending = match parts.next() {
+            Some(DESCENDING_SUFFIX) => true,
+            Some(_) => return None,
+            None => false,
+        };
 
         if parts.next().is_some() {
             return None;
@@ -29,10 +38,20 @@ impl ListStreamPageToken {
         Some(ListStreamPageToken {
             table_name,
             stream_id: token_stream_id.into(),
+            descending,
         })
     }
 
     pub(super) fn to_token_string(&self) -> String {
-        format!("{}:{}", self.table_name.val(), self.stream_id.val())
+        if self.descending {
+            format!(
+                "{}:{}:{}",
+                self.table_name.val(),
+                self.stream_id.val(),
+                DESCENDING_SUFFIX
+            )
+        } else {
+            format!("{}:{}", self.table_name.val(), self.stream_id.val())
+        }
     }
 }
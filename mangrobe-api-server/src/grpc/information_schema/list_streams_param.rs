@@ -1,8 +1,11 @@
 use crate::application::information_schema::list_streams_param::ListStreamsParam;
+use crate::domain::model::stream::StreamFilter;
 use crate::grpc::information_schema::list_stream_page_token::ListStreamPageToken;
 use crate::grpc::model::page::build_page;
 use crate::grpc::proto::{ListStreamsRequest, PaginationRequest};
-use crate::grpc::util::param_util::to_table_name;
+use crate::grpc::util::param_util::{
+    to_optional_date_time, to_principal, to_stream_id_range, to_table_name,
+};
 use crate::util::error::ParameterError;
 use tonic::Request;
 
@@ -23,7 +26,7 @@ pub(super) fn parse_list_streams_param(
     let stream_id_after = match page.token {
         Some(token) => {
             let token = ListStreamPageToken::parse(token).ok_or(invalid_page_token())?;
-            if token.table_name != table_name {
+            if token.table_name != table_name || token.descending != req.descending {
                 return Err(invalid_page_token());
             }
             Some(token.stream_id)
@@ -31,10 +34,19 @@ pub(super) fn parse_list_streams_param(
         None => None,
     };
 
+    let filter = StreamFilter {
+        updated_since: to_optional_date_time("updated_since", req.updated_since)?,
+        stream_id_range: to_stream_id_range(req.stream_id_range.as_ref())?,
+        label_selector: req.label_selector.clone().into_iter().collect(),
+    };
+
     Ok((
         ListStreamsParam {
             table_name,
+            filter,
+            descending: req.descending,
             stream_id_after,
+            principal: to_principal(&request),
         },
         page.size,
     ))
@@ -1,16 +1,19 @@
 use crate::domain::model::stream::Stream;
 use crate::domain::model::user_table_name::UserTableName;
 use crate::grpc::information_schema::list_stream_page_token::ListStreamPageToken;
-use crate::grpc::proto::{ListStreamsResponse, PaginationResponse, StreamInfo};
+use crate::grpc::model::stream_info::build_stream_info;
+use crate::grpc::proto::{ListStreamsResponse, PaginationResponse};
 
 pub(super) fn build_list_streams_response(
     table_name: &UserTableName,
+    descending: bool,
     page_size: usize,
     streams: &[Stream],
 ) -> ListStreamsResponse {
     let pagination = if streams.len() > page_size {
         let last_stream = &streams[page_size - 1];
-        let next_token = ListStreamPageToken::new(table_name.clone(), last_stream.id.clone());
+        let next_token =
+            ListStreamPageToken::new(table_name.clone(), last_stream.id.clone(), descending);
 
         Some(PaginationResponse {
             next_token: next_token.to_token_string(),
@@ -24,10 +27,7 @@ pub(super) fn build_list_streams_response(
         streams: streams
             .iter()
             .take(page_size)
-            .map(|stream| StreamInfo {
-                stream_id: stream.id.val(),
-                last_commit_id: stream.last_commit_id.to_string(),
-            })
+            .map(build_stream_info)
             .collect(),
         pagination,
     }
@@ -1,2 +1,4 @@
 mod acquire_file_lock_param;
 pub mod lock_control_service;
+mod plan_compaction_param;
+mod plan_compaction_response;
@@ -1,9 +1,12 @@
 use crate::application::lock_control::acquire_file_lock_param::AcquireFileLockParam;
 use crate::domain::model::lock_raw_file_entry::LockFileRawAcquireEntry;
 use crate::grpc::proto::AcquireFileLockRequest;
-use crate::grpc::util::param_util::{to_file_lock_key, to_partition_time, to_table_name};
+use crate::grpc::util::param_util::{
+    to_client_info, to_file_lock_key, to_file_lock_owner, to_partition_time, to_partition_values,
+    to_principal, to_table_name, to_ttl,
+};
 use crate::util::error::ParameterError;
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Utc};
 use tonic::Request;
 
 pub fn build_acquire_file_lock_param(
@@ -14,6 +17,15 @@ pub fn build_acquire_file_lock_param(
     let table_name = to_table_name(req.table_name.clone())?;
 
     let file_lock_key = to_file_lock_key(req.file_lock_key.clone(), request_started_at)?;
+    let owner = to_file_lock_owner(req.owner_id.clone(), req.reason.clone())?;
+
+    let partition_filter = to_partition_values("partition_filter", &req.partition_filter)?;
+    if !partition_filter.is_empty() && !req.acquire_file_lock_entries.is_empty() {
+        return Err(ParameterError::Invalid(
+            "partition_filter".to_string(),
+            "must not be set together with acquire_file_lock_entries".to_string(),
+        ));
+    }
 
     let mut entries = vec![];
     for entry in req.acquire_file_lock_entries.iter() {
@@ -32,8 +44,12 @@ pub fn build_acquire_file_lock_param(
         file_lock_key,
         table_name,
         stream_id: req.stream_id.into(),
-        ttl: Duration::seconds(req.ttl_sec),
+        ttl: to_ttl(req.ttl_sec)?,
+        owner,
         entries,
+        partition_filter,
+        principal: to_principal(&request),
+        client: to_client_info(&request),
     };
     Ok(param)
 }
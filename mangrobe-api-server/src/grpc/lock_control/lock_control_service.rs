@@ -1,11 +1,14 @@
 use crate::application::lock_control::lock_control_use_case::LockControlUseCase;
 use crate::grpc::lock_control::acquire_file_lock_param::build_acquire_file_lock_param;
+use crate::grpc::lock_control::plan_compaction_param::build_plan_compaction_param;
+use crate::grpc::lock_control::plan_compaction_response::build_plan_compaction_response;
+use crate::grpc::model::file::build_file;
 use crate::grpc::proto::{
-    AcquireFileLockRequest, AcquireFileLockResponse, File, ReleaseFileLockRequest,
-    ReleaseFileLockResponse, lock_control_service_server,
+    AcquireFileLockRequest, AcquireFileLockResponse, PlanCompactionRequest, PlanCompactionResponse,
+    ReleaseFileLockRequest, ReleaseFileLockResponse, lock_control_service_server,
 };
 use crate::grpc::util::error::{build_invalid_argument, to_grpc_error};
-use crate::grpc::util::param_util::to_file_lock_key;
+use crate::grpc::util::param_util::{to_client_info, to_file_lock_key, to_principal};
 use chrono::Utc;
 use sea_orm::DatabaseConnection;
 use tonic::{Request, Response, Status};
@@ -39,14 +42,7 @@ impl lock_control_service_server::LockControlService for LockControlService {
             .map_err(to_grpc_error)?;
 
         let response = AcquireFileLockResponse {
-            files: locked_files
-                .iter()
-                .map(|f| File {
-                    file_id: f.id.val().to_string(),
-                    path: f.file.path.path(),
-                    size: f.file.size,
-                })
-                .collect(),
+            files: locked_files.iter().map(build_file).collect(),
         };
 
         Ok(Response::new(response))
@@ -63,11 +59,34 @@ impl lock_control_service_server::LockControlService for LockControlService {
 
         let deleted = self
             .lock_control_use_case
-            .release_lock(file_lock_key)
+            .release_lock(
+                file_lock_key,
+                &to_principal(&request),
+                &to_client_info(&request),
+            )
             .await
             .map_err(to_grpc_error)?;
 
         let response = ReleaseFileLockResponse { deleted };
         Ok(Response::new(response))
     }
+
+    async fn plan_compaction(
+        &self,
+        request: Request<PlanCompactionRequest>,
+    ) -> Result<Response<PlanCompactionResponse>, Status> {
+        let request_started_at = Utc::now();
+        let param = build_plan_compaction_param(request, request_started_at)
+            .map_err(build_invalid_argument)?;
+        let locking = param.file_lock_key.is_some();
+
+        let groups = self
+            .lock_control_use_case
+            .plan_compaction(param)
+            .await
+            .map_err(to_grpc_error)?;
+
+        let response = build_plan_compaction_response(&groups, locking && !groups.is_empty());
+        Ok(Response::new(response))
+    }
 }
@@ -0,0 +1,72 @@
+use crate::application::lock_control::plan_compaction_param::PlanCompactionParam;
+use crate::domain::model::compaction_plan::CompactionPolicy;
+use crate::grpc::proto::PlanCompactionRequest;
+use crate::grpc::util::param_util::{
+    to_client_info, to_file_lock_key, to_file_lock_owner, to_partition_time_range, to_principal,
+    to_table_name, to_ttl,
+};
+use crate::util::error::ParameterError;
+use chrono::{DateTime, Utc};
+use tonic::Request;
+
+const DEFAULT_MIN_FILES: i64 = 2;
+const DEFAULT_MAX_GROUPS: i64 = 100;
+const MAX_GROUPS_LIMIT: i64 = 1000;
+
+pub fn build_plan_compaction_param(
+    request: Request<PlanCompactionRequest>,
+    request_started_at: DateTime<Utc>,
+) -> Result<PlanCompactionParam, ParameterError> {
+    let req = request.get_ref();
+    let table_name = to_table_name(req.table_name.clone())?;
+
+    if req.target_file_size <= 0 {
+        return Err(ParameterError::Invalid(
+            "target_file_size".to_string(),
+            "must be positive".to_string(),
+        ));
+    }
+    let min_files = match req.min_files {
+        0 => DEFAULT_MIN_FILES,
+        n if n >= 2 => n,
+        _ => {
+            return Err(ParameterError::Invalid(
+                "min_files".to_string(),
+                "must be at least 2".to_string(),
+            ));
+        }
+    };
+    let max_groups = match req.max_groups {
+        0 => DEFAULT_MAX_GROUPS,
+        n if (1..=MAX_GROUPS_LIMIT).contains(&n) => n,
+        _ => {
+            return Err(ParameterError::Invalid(
+                "max_groups".to_string(),
+                format!("must be between 1 and {}", MAX_GROUPS_LIMIT),
+            ));
+        }
+    };
+
+    let file_lock_key = match req.file_lock_key.clone() {
+        Some(key) => Some(to_file_lock_key(Some(key), request_started_at)?),
+        None => None,
+    };
+    let owner = to_file_lock_owner(req.owner_id.clone(), req.reason.clone())?;
+
+    let param = PlanCompactionParam {
+        table_name,
+        stream_id: req.stream_id.into(),
+        policy: CompactionPolicy {
+            target_file_size: req.target_file_size,
+            min_files: min_files as usize,
+            max_groups: max_groups as usize,
+        },
+        partition_time_range: to_partition_time_range(req.partition_time_range.as_ref())?,
+        file_lock_key,
+        ttl: to_ttl(req.ttl_sec)?,
+        owner,
+        principal: to_principal(&request),
+        client: to_client_info(&request),
+    };
+    Ok(param)
+}
@@ -0,0 +1,22 @@
+use crate::domain::model::compaction_plan::CompactionGroup;
+use crate::grpc::model::file::build_file;
+use crate::grpc::model::timestamp::build_timestamp;
+use crate::grpc::proto::{CompactionGroup as CompactionGroupResponse, PlanCompactionResponse};
+
+pub(super) fn build_plan_compaction_response(
+    groups: &[CompactionGroup],
+    locked: bool,
+) -> PlanCompactionResponse {
+    PlanCompactionResponse {
+        groups: groups
+            .iter()
+            .map(|group| CompactionGroupResponse {
+                partition_time: Some(build_timestamp(&group.partition_time)),
+                partition_values: group.partition_values.clone().into_iter().collect(),
+                files: group.files.iter().map(build_file).collect(),
+                total_size: group.total_size(),
+            })
+            .collect(),
+        locked,
+    }
+}
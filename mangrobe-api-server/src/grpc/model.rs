@@ -1 +1,4 @@
+pub(super) mod file;
 pub(super) mod page;
+pub(super) mod stream_info;
+pub(super) mod timestamp;
@@ -0,0 +1,11 @@
+use crate::domain::model::file::FileWithId;
+use crate::grpc::proto::File;
+
+pub fn build_file(file: &FileWithId) -> File {
+    File {
+        file_id: file.id.val().to_string(),
+        path: file.file.path.path(),
+        size: file.file.size,
+        partition_values: file.file.partition_values.clone().into_iter().collect(),
+    }
+}
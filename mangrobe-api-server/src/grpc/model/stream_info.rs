@@ -0,0 +1,29 @@
+use crate::domain::model::stream::{Stream, StreamState};
+use crate::grpc::model::timestamp::build_timestamp;
+use crate::grpc::proto::{StreamInfo, StreamState as StreamStateResponse};
+
+pub fn build_stream_info(stream: &Stream) -> StreamInfo {
+    StreamInfo {
+        stream_id: stream.id.val(),
+        last_commit_id: stream
+            .last_commit_id
+            .as_ref()
+            .map_or("0".to_string(), |id| id.to_string()),
+        tenant_name: stream.metadata.tenant_name.clone().unwrap_or_default(),
+        labels: stream.metadata.labels.clone().into_iter().collect(),
+        state: to_stream_state(stream.state).into(),
+        sealed_at: stream.sealed_at.as_ref().map(build_timestamp),
+        created_at: Some(build_timestamp(&stream.created_at)),
+        updated_at: Some(build_timestamp(&stream.updated_at)),
+        last_committed_at: stream.last_committed_at.as_ref().map(build_timestamp),
+        file_count: stream.file_count as i64,
+        total_bytes: stream.total_bytes as i64,
+    }
+}
+
+fn to_stream_state(state: StreamState) -> StreamStateResponse {
+    match state {
+        StreamState::Active => StreamStateResponse::Active,
+        StreamState::Sealed => StreamStateResponse::Sealed,
+    }
+}
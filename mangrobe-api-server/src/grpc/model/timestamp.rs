@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+use prost_types::Timestamp;
+
+pub fn build_timestamp(time: &DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: time.timestamp(),
+        nanos: time.timestamp_subsec_nanos() as i32,
+    }
+}
@@ -0,0 +1,24 @@
+use http::{HeaderMap, HeaderValue};
+use uuid::Uuid;
+
+pub(super) const REQUEST_ID_HEADER: &str = "x-request-id";
+pub(super) const REQUEST_ID_LENGTH_LIMIT: usize = 128;
+
+// Returns the request id sent by the client, or generates one. The generated id is added to the
+// headers so that handlers record the same id as the logs.
+pub(super) fn ensure_request_id(headers: &mut HeaderMap) -> String {
+    let existing = headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| !v.is_empty() && v.len() <= REQUEST_ID_LENGTH_LIMIT);
+    if let Some(request_id) = existing {
+        return request_id.to_string();
+    }
+
+    let request_id = Uuid::new_v4().to_string();
+    headers.insert(
+        REQUEST_ID_HEADER,
+        HeaderValue::from_str(&request_id).expect("uuid is a valid header value"),
+    );
+    request_id
+}
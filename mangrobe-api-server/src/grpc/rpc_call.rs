@@ -0,0 +1,23 @@
+use tonic::Code;
+
+// "/mangrobe.api.DataManipulationService/AddFiles" is split into the service and the method.
+pub(super) fn split_path(path: &str) -> (String, String) {
+    let path = path.trim_start_matches('/');
+    match path.split_once('/') {
+        Some((service, method)) => (service.to_string(), method.to_string()),
+        None => (path.to_string(), String::new()),
+    }
+}
+
+// Errors of unary calls are returned as trailers-only responses, so grpc-status is in the headers.
+// Without it, the status is sent later in the trailers, which is OK for the unary calls here.
+pub(super) fn response_code<ResBody, E>(result: &Result<http::Response<ResBody>, E>) -> Code {
+    match result {
+        Ok(response) => response
+            .headers()
+            .get("grpc-status")
+            .map(|v| Code::from_bytes(v.as_bytes()))
+            .unwrap_or(Code::Ok),
+        Err(_) => Code::Unknown,
+    }
+}
@@ -0,0 +1,64 @@
+use crate::grpc::rpc_call::{response_code, split_path};
+use crate::util::metrics::{RPC_DURATION_SECONDS, RPC_REQUESTS_TOTAL};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+use tonic::Code;
+use tower::{Layer, Service};
+
+// Records latency and status code of every gRPC request.
+#[derive(Clone, Default)]
+pub struct RpcMetricsLayer;
+
+impl<S> Layer<S> for RpcMetricsLayer {
+    type Service = RpcMetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcMetricsService { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct RpcMetricsService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for RpcMetricsService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        let path = request.uri().path().to_string();
+        let started_at = Instant::now();
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            let result = future.await;
+            let code = response_code(&result);
+            // Unknown paths are not given their own labels, since clients can send anything.
+            let (service, method) = match code {
+                Code::Unimplemented => ("unknown".to_string(), "unknown".to_string()),
+                _ => split_path(&path),
+            };
+
+            RPC_DURATION_SECONDS
+                .with_label_values(&[service.as_str(), method.as_str()])
+                .observe(started_at.elapsed().as_secs_f64());
+            RPC_REQUESTS_TOTAL
+                .with_label_values(&[service.as_str(), method.as_str(), &format!("{:?}", code)])
+                .inc();
+
+            result
+        })
+    }
+}
@@ -0,0 +1,102 @@
+use crate::grpc::request_id::{REQUEST_ID_HEADER, ensure_request_id};
+use crate::grpc::rpc_call::{response_code, split_path};
+use http::HeaderValue;
+use opentelemetry::global;
+use opentelemetry::propagation::Extractor;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+use tracing::{Instrument, field, info_span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+// Starts a server span for every gRPC request, continuing the W3C trace context in the metadata.
+// The span carries the request id, so every log line of the request includes it.
+#[derive(Clone, Default)]
+pub struct RpcTracingLayer;
+
+impl<S> Layer<S> for RpcTracingLayer {
+    type Service = RpcTracingService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcTracingService { inner }
+    }
+}
+
+#[derive(Clone)]
+pub struct RpcTracingService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for RpcTracingService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<ReqBody>) -> Self::Future {
+        let request_id = ensure_request_id(request.headers_mut());
+        let path = request.uri().path();
+        let (service, method) = split_path(path);
+        let span = info_span!(
+            "grpc.request",
+            request_id = %request_id,
+            otel.name = path.trim_start_matches('/'),
+            otel.kind = "server",
+            otel.status_code = field::Empty,
+            rpc.system = "grpc",
+            rpc.service = service,
+            rpc.method = method,
+            rpc.grpc.status_code = field::Empty,
+        );
+        let parent = global::get_text_map_propagator(|propagator| {
+            propagator.extract(&HeaderExtractor(request.headers()))
+        });
+        // Fails only when the OpenTelemetry layer is not installed.
+        let _ = span.set_parent(parent);
+
+        let future = {
+            let _entered = span.enter();
+            self.inner.call(request)
+        };
+
+        Box::pin(
+            async move {
+                let mut result = future.await;
+                if let Ok(response) = &mut result
+                    && let Ok(value) = HeaderValue::from_str(&request_id)
+                {
+                    response.headers_mut().insert(REQUEST_ID_HEADER, value);
+                }
+                let code = response_code(&result);
+                let span = tracing::Span::current();
+                span.record("rpc.grpc.status_code", code as i32);
+                if code != tonic::Code::Ok {
+                    span.record("otel.status_code", "ERROR");
+                }
+                result
+            }
+            .instrument(span),
+        )
+    }
+}
+
+// gRPC metadata is carried in HTTP headers.
+struct HeaderExtractor<'a>(&'a http::HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|v| v.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|k| k.as_str()).collect()
+    }
+}
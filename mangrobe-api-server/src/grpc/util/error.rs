@@ -1,25 +1,98 @@
-use crate::util::error::{ParameterError, UserError};
+use crate::util::error::{
+    AuthError, CommitError, LockError, ParameterError, QuotaError, UserError,
+};
+use std::collections::HashMap;
 use tonic::{Code, Status};
+use tonic_types::{ErrorDetails, StatusExt};
 use tracing::error;
 
+const ERROR_DOMAIN: &str = "mangrobe";
+
 pub fn to_grpc_error(error: anyhow::Error) -> Status {
     if let Some(e) = error.downcast_ref::<UserError>() {
         return match e {
             UserError::InvalidParameterMessage(_) => {
                 build_invalid_argument_with_error_message(e.to_string())
             }
-            UserError::InvalidLockMessage(_) => {
-                build_invalid_argument_with_error_message(e.to_string())
-            }
-            UserError::AlreadyExistsMessage(_) => {
-                Status::new(Code::AlreadyExists, e.to_string())
+            UserError::AlreadyExistsMessage(_) => Status::new(Code::AlreadyExists, e.to_string()),
+            UserError::NotFoundMessage(_) => Status::new(Code::NotFound, e.to_string()),
+            UserError::FailedPrecondition(_) => {
+                Status::new(Code::FailedPrecondition, e.to_string())
             }
         };
     }
 
+    if let Some(e) = error.downcast_ref::<AuthError>() {
+        return build_auth_error(e);
+    }
+
+    if let Some(e) = error.downcast_ref::<LockError>() {
+        return build_lock_error(e);
+    }
+
+    if let Some(e) = error.downcast_ref::<CommitError>() {
+        return build_commit_error(e);
+    }
+
+    if let Some(e) = error.downcast_ref::<QuotaError>() {
+        return build_quota_error(e);
+    }
+
     to_internal_error(error)
 }
 
+fn build_lock_error(error: &LockError) -> Status {
+    let code = match error {
+        // Retryable. the lock will be released by its owner or expire.
+        LockError::HeldByOther(_) => Code::Aborted,
+        // Never succeeds with the same key.
+        LockError::DuplicatedKey(_) => Code::AlreadyExists,
+        LockError::NotAcquired(_) | LockError::FileNotLocked(_) => Code::FailedPrecondition,
+        LockError::FileNotFound(_) => Code::NotFound,
+    };
+
+    Status::with_error_details(
+        code,
+        error.to_string(),
+        ErrorDetails::with_error_info(error.reason(), ERROR_DOMAIN, HashMap::new()),
+    )
+}
+
+fn build_commit_error(error: &CommitError) -> Status {
+    let code = match error {
+        CommitError::HeadMoved(_) => Code::Aborted,
+        CommitError::StreamSealed(_) => Code::FailedPrecondition,
+    };
+
+    Status::with_error_details(
+        code,
+        error.to_string(),
+        ErrorDetails::with_error_info(error.reason(), ERROR_DOMAIN, HashMap::new()),
+    )
+}
+
+fn build_quota_error(error: &QuotaError) -> Status {
+    // Every quota error is retryable later, or after compaction reduces current files.
+    Status::with_error_details(
+        Code::ResourceExhausted,
+        error.to_string(),
+        ErrorDetails::with_error_info(error.reason(), ERROR_DOMAIN, HashMap::new()),
+    )
+}
+
+pub fn build_auth_error(error: &AuthError) -> Status {
+    let code = match error {
+        AuthError::Unauthenticated(_) => Code::Unauthenticated,
+        AuthError::PermissionDenied(_) => Code::PermissionDenied,
+    };
+
+    Status::with_error_details(
+        code,
+        error.to_string(),
+        ErrorDetails::with_error_info(error.reason(), ERROR_DOMAIN, HashMap::new()),
+    )
+}
+
 fn to_internal_error(error: anyhow::Error) -> Status {
     error!(?error, "internal server error");
     Status::new(Code::Internal, "internal server error")
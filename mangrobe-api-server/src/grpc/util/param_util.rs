@@ -1,11 +1,28 @@
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::commit_id::CommitId;
 use crate::domain::model::file_lock_key::FileLockKey;
+use crate::domain::model::file_lock_owner::{
+    FileLockOwner, OWNER_ID_LENGTH_LIMIT, REASON_LENGTH_LIMIT,
+};
 use crate::domain::model::idempotency_key::IdempotencyKey;
+use crate::domain::model::partition_field::PARTITION_VALUE_LENGTH_LIMIT;
+use crate::domain::model::partition_time_range::PartitionTimeRange;
+use crate::domain::model::principal::Principal;
+use crate::domain::model::stream_id_range::StreamIdRange;
+use crate::domain::model::table_properties::{
+    DURATION_SEC_LIMIT, PROPERTY_LENGTH_LIMIT, validate_property,
+};
 use crate::domain::model::user_table_name::UserTableName;
 use crate::grpc::proto::FileLockKey as FileLockKeyParam;
 use crate::grpc::proto::IdempotencyKey as IdempotencyKeyParam;
+use crate::grpc::proto::PartitionTimeRange as PartitionTimeRangeParam;
+use crate::grpc::proto::StreamIdRange as StreamIdRangeParam;
+use crate::grpc::request_id::{REQUEST_ID_HEADER, REQUEST_ID_LENGTH_LIMIT};
 use crate::util::error::ParameterError;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use prost_types::Timestamp;
+use std::collections::{BTreeMap, HashMap};
+use tonic::Request;
 
 pub fn to_table_name(table_name: String) -> Result<UserTableName, ParameterError> {
     match table_name.try_into() {
@@ -25,6 +42,22 @@ pub fn to_partition_time(param: Option<Timestamp>) -> Result<DateTime<Utc>, Para
     )
 }
 
+pub fn to_optional_date_time(
+    name: &str,
+    param: Option<Timestamp>,
+) -> Result<Option<DateTime<Utc>>, ParameterError> {
+    let Some(param) = param else {
+        return Ok(None);
+    };
+
+    DateTime::from_timestamp(param.seconds, param.nanos as u32)
+        .map(Some)
+        .ok_or(ParameterError::Invalid(
+            name.to_string(),
+            "out-of-range number of seconds or nanos".to_string(),
+        ))
+}
+
 pub fn to_idempotency_key(
     param: Option<IdempotencyKeyParam>,
 ) -> Result<IdempotencyKey, ParameterError> {
@@ -47,3 +80,184 @@ pub fn to_file_lock_key(
     FileLockKey::new(param.key, request_started_at)
         .map_err(|msg| ParameterError::Invalid("file_lock_key".to_string(), msg))
 }
+
+pub fn to_file_lock_owner(
+    owner_id: String,
+    reason: String,
+) -> Result<FileLockOwner, ParameterError> {
+    if owner_id.chars().count() >= OWNER_ID_LENGTH_LIMIT {
+        return Err(ParameterError::Invalid(
+            "owner_id".to_string(),
+            format!("must be shorter than {} characters", OWNER_ID_LENGTH_LIMIT),
+        ));
+    }
+    if reason.chars().count() >= REASON_LENGTH_LIMIT {
+        return Err(ParameterError::Invalid(
+            "reason".to_string(),
+            format!("must be shorter than {} characters", REASON_LENGTH_LIMIT),
+        ));
+    }
+
+    Ok(FileLockOwner::new(Some(owner_id), Some(reason)))
+}
+
+pub fn to_expected_commit_id(param: &Option<String>) -> Result<Option<CommitId>, ParameterError> {
+    let Some(param) = param else {
+        return Ok(None);
+    };
+
+    let commit_id = param.parse::<i64>().map_err(|_| {
+        ParameterError::Invalid(
+            "expected_commit_id".to_string(),
+            "invalid number".to_string(),
+        )
+    })?;
+    if commit_id < 0 {
+        return Err(ParameterError::Invalid(
+            "expected_commit_id".to_string(),
+            "must be non-negative".to_string(),
+        ));
+    }
+
+    Ok(Some(commit_id.into()))
+}
+
+// Unbounded when not set.
+pub fn to_stream_id_range(
+    param: Option<&StreamIdRangeParam>,
+) -> Result<StreamIdRange, ParameterError> {
+    let Some(range) = param else {
+        return Ok(StreamIdRange::default());
+    };
+
+    if let (Some(min), Some(max)) = (range.min, range.max)
+        && min > max
+    {
+        return Err(ParameterError::Invalid(
+            "stream_id_range".to_string(),
+            "min must not exceed max".to_string(),
+        ));
+    }
+
+    Ok(StreamIdRange::new(
+        range.min.map(|v| v.into()),
+        range.max.map(|v| v.into()),
+    ))
+}
+
+// Unbounded when not set.
+pub fn to_partition_time_range(
+    param: Option<&PartitionTimeRangeParam>,
+) -> Result<PartitionTimeRange, ParameterError> {
+    let Some(range) = param else {
+        return Ok(PartitionTimeRange::default());
+    };
+
+    let min = to_optional_date_time("partition_time_range.min", range.min)?;
+    let max = to_optional_date_time("partition_time_range.max", range.max)?;
+    if let (Some(min), Some(max)) = (min, max)
+        && min > max
+    {
+        return Err(ParameterError::Invalid(
+            "partition_time_range".to_string(),
+            "min must not exceed max".to_string(),
+        ));
+    }
+
+    Ok(PartitionTimeRange::new(min, max))
+}
+
+// None (0) falls back to the lock.default_ttl_sec property of the table.
+pub fn to_ttl(ttl_sec: i64) -> Result<Option<Duration>, ParameterError> {
+    match ttl_sec {
+        0 => Ok(None),
+        n if (1..=DURATION_SEC_LIMIT).contains(&n) => Ok(Duration::try_seconds(n)),
+        _ => Err(ParameterError::Invalid(
+            "ttl_sec".to_string(),
+            format!("must be between 0 and {}", DURATION_SEC_LIMIT),
+        )),
+    }
+}
+
+pub fn to_table_properties(
+    properties: &HashMap<String, String>,
+) -> Result<BTreeMap<String, String>, ParameterError> {
+    for (key, value) in properties {
+        to_table_property_key(key)?;
+        if value.chars().count() >= PROPERTY_LENGTH_LIMIT {
+            return Err(ParameterError::Invalid(
+                format!("properties.{}", key),
+                format!("must be shorter than {} characters", PROPERTY_LENGTH_LIMIT),
+            ));
+        }
+        validate_property(key, value)
+            .map_err(|e| ParameterError::Invalid(format!("properties.{}", key), e))?;
+    }
+
+    Ok(properties.clone().into_iter().collect())
+}
+
+pub fn to_table_property_key(key: &str) -> Result<(), ParameterError> {
+    if key.is_empty() {
+        return Err(ParameterError::Invalid(
+            "properties".to_string(),
+            "key must not be empty".to_string(),
+        ));
+    }
+    if key.chars().count() >= PROPERTY_LENGTH_LIMIT {
+        return Err(ParameterError::Invalid(
+            format!("properties.{}", key),
+            format!(
+                "key must be shorter than {} characters",
+                PROPERTY_LENGTH_LIMIT
+            ),
+        ));
+    }
+    Ok(())
+}
+
+// Columns are checked against the table's partition fields by the use cases.
+pub fn to_partition_values(
+    field_name: &str,
+    values: &HashMap<String, String>,
+) -> Result<BTreeMap<String, String>, ParameterError> {
+    for (column, value) in values {
+        if column.is_empty() {
+            return Err(ParameterError::Invalid(
+                field_name.to_string(),
+                "column must not be empty".to_string(),
+            ));
+        }
+        if value.chars().count() >= PARTITION_VALUE_LENGTH_LIMIT {
+            return Err(ParameterError::Invalid(
+                format!("{}.{}", field_name, column),
+                format!(
+                    "must be shorter than {} characters",
+                    PARTITION_VALUE_LENGTH_LIMIT
+                ),
+            ));
+        }
+    }
+
+    Ok(values.clone().into_iter().collect())
+}
+
+// The Principal is set by AuthInterceptor. Missing only when the service is not intercepted.
+pub fn to_principal<T>(request: &Request<T>) -> Principal {
+    request
+        .extensions()
+        .get::<Principal>()
+        .cloned()
+        .unwrap_or_else(Principal::anonymous)
+}
+
+pub fn to_client_info<T>(request: &Request<T>) -> ClientInfo {
+    let request_id = request
+        .metadata()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| !v.is_empty())
+        .map(|v| v.chars().take(REQUEST_ID_LENGTH_LIMIT).collect());
+
+    ClientInfo::new(request.remote_addr().map(|a| a.to_string()), request_id)
+}
@@ -0,0 +1,56 @@
+use crate::application::monitoring::monitoring_use_case::MonitoringUseCase;
+use crate::config::app_config::MetricsConfig;
+use crate::util::metrics::encode_metrics;
+use axum::Router;
+use axum::extract::State;
+use axum::http::{StatusCode, header};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use sea_orm::DatabaseConnection;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tracing::{error, info};
+
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+pub async fn run_metrics_server(
+    addr: SocketAddr,
+    config: &MetricsConfig,
+    db: &DatabaseConnection,
+) -> Result<(), anyhow::Error> {
+    let use_case = Arc::new(MonitoringUseCase::new(db.clone()));
+    // Scrapes read the gauges of the last refresh, so that they never scan the database.
+    tokio::spawn({
+        let use_case = use_case.clone();
+        let interval = config.refresh_interval();
+        let max_table_labels = config.max_table_labels;
+        async move {
+            use_case
+                .run_table_gauge_refresher(interval, max_table_labels)
+                .await
+        }
+    });
+
+    let router = Router::new()
+        .route("/metrics", get(metrics))
+        .with_state(use_case);
+
+    let listener = TcpListener::bind(addr).await?;
+    info!("Serving metrics at http://{}/metrics", addr);
+    axum::serve(listener, router).await?;
+
+    Ok(())
+}
+
+async fn metrics(State(use_case): State<Arc<MonitoringUseCase>>) -> impl IntoResponse {
+    use_case.refresh_pool_gauges();
+
+    match encode_metrics() {
+        Ok(body) => (StatusCode::OK, [(header::CONTENT_TYPE, CONTENT_TYPE)], body).into_response(),
+        Err(e) => {
+            error!(error = ?e, "failed to encode metrics");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
@@ -2,3 +2,4 @@ pub mod connection;
 pub mod entity;
 mod entity_ext;
 pub mod repository;
+pub mod schema;
@@ -1,16 +1,15 @@
+use crate::config::app_config::DatabaseConfig;
 use sea_orm::{ConnectOptions, Database, DatabaseConnection};
-use std::time::Duration;
 
-pub async fn connect(url: String) -> Result<DatabaseConnection, anyhow::Error> {
-    let mut opt = ConnectOptions::new(url);
-    opt.max_connections(100)
-        .min_connections(5)
-        .connect_timeout(Duration::from_secs(8))
-        .acquire_timeout(Duration::from_secs(8))
-        .idle_timeout(Duration::from_secs(8))
-        .max_lifetime(Duration::from_secs(8))
-        .sqlx_logging(true)
-        // TODO: log in production?
+pub async fn connect(config: &DatabaseConfig) -> Result<DatabaseConnection, anyhow::Error> {
+    let mut opt = ConnectOptions::new(config.url.clone());
+    opt.max_connections(config.max_connections)
+        .min_connections(config.min_connections)
+        .connect_timeout(config.connect_timeout())
+        .acquire_timeout(config.acquire_timeout())
+        .idle_timeout(config.idle_timeout())
+        .max_lifetime(config.max_lifetime())
+        .sqlx_logging(config.sqlx_logging)
         .sqlx_logging_level(log::LevelFilter::Debug);
     let db = Database::connect(opt).await?;
 
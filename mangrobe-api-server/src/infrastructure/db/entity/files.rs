@@ -14,6 +14,8 @@ pub struct Model {
     #[sea_orm(column_type = "VarBinary(StringLen::None)")]
     pub path_xxh3: Vec<u8>,
     pub size: i64,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub partition_values: Json,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
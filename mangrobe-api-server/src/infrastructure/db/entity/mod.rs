@@ -2,6 +2,7 @@
 
 pub mod prelude;
 
+pub mod audit_logs;
 pub mod change_request_idempotency_keys;
 pub mod change_requests;
 pub mod commits;
@@ -10,4 +11,8 @@ pub mod file_column_statistics;
 pub mod file_locks;
 pub mod file_metadata;
 pub mod files;
+pub mod garbage_files;
+pub mod streams;
+pub mod table_aliases;
+pub mod table_grants;
 pub mod user_tables;
@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.17
 
+pub use super::audit_logs::Entity as AuditLogs;
 pub use super::change_request_idempotency_keys::Entity as ChangeRequestIdempotencyKeys;
 pub use super::change_requests::Entity as ChangeRequests;
 pub use super::commits::Entity as Commits;
@@ -8,4 +9,8 @@ pub use super::file_column_statistics::Entity as FileColumnStatistics;
 pub use super::file_locks::Entity as FileLocks;
 pub use super::file_metadata::Entity as FileMetadata;
 pub use super::files::Entity as Files;
+pub use super::garbage_files::Entity as GarbageFiles;
+pub use super::streams::Entity as Streams;
+pub use super::table_aliases::Entity as TableAliases;
+pub use super::table_grants::Entity as TableGrants;
 pub use super::user_tables::Entity as UserTables;
@@ -11,6 +11,10 @@ pub struct Model {
     pub name: String,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub dropped_at: Option<DateTimeWithTimeZone>,
+    pub purge_after: Option<DateTimeWithTimeZone>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub properties: Json,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -25,6 +29,10 @@ pub enum Relation {
     FileLocks,
     #[sea_orm(has_many = "super::files::Entity")]
     Files,
+    #[sea_orm(has_many = "super::streams::Entity")]
+    Streams,
+    #[sea_orm(has_many = "super::table_aliases::Entity")]
+    TableAliases,
 }
 
 impl Related<super::change_requests::Entity> for Entity {
@@ -57,4 +65,16 @@ impl Related<super::files::Entity> for Entity {
     }
 }
 
+impl Related<super::streams::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Streams.def()
+    }
+}
+
+impl Related<super::table_aliases::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TableAliases.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -1,3 +1,5 @@
+mod audit_log_dto;
+pub mod audit_log_repository;
 mod change_request_dto;
 mod change_request_idempotency_key_dto;
 mod change_request_idempotency_key_repository;
@@ -7,13 +9,22 @@ pub mod commit_lock_repository;
 pub mod commit_repository;
 mod current_file_dto;
 pub mod current_file_repository;
+mod file_column_statistics_dto;
+pub mod file_column_statistics_repository;
 mod file_dto;
 mod file_lock_dto;
 pub mod file_lock_repository;
 mod file_metadata_dto;
 pub mod file_metadata_repository;
 pub mod file_repository;
-mod file_column_statistics_dto;
-pub mod file_column_statistics_repository;
+mod garbage_file_repository;
+mod stream_dto;
+pub mod stream_repository;
+mod table_alias_dto;
+pub mod table_alias_repository;
+mod table_data_repository;
+mod table_grant_dto;
+pub mod table_grant_repository;
+pub mod table_name_lock_repository;
 mod user_table_dto;
 pub mod user_table_repository;
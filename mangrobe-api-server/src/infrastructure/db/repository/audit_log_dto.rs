@@ -0,0 +1,145 @@
+use crate::domain::model::audit_log::{AuditEvent, AuditLog, AuditOperation, AuditOutcome};
+use crate::domain::model::client_info::ClientInfo;
+use crate::domain::model::principal::PrincipalKind;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::infrastructure::db::entity::audit_logs::{ActiveModel, Model};
+use crate::util::error::MangrobeError;
+use anyhow::bail;
+use sea_orm::Set;
+
+pub(super) fn build_entity_audit_log(
+    event: &AuditEvent,
+    outcome: AuditOutcome,
+    error_message: Option<String>,
+) -> ActiveModel {
+    ActiveModel {
+        id: Default::default(),
+        operation: Set(build_model_operation(event.operation)),
+        principal_id: Set(event.principal_id.clone()),
+        principal_kind: Set(build_model_principal_kind(&event.principal_kind)),
+        client_address: Set(event.client.address.clone()),
+        request_id: Set(event.client.request_id.clone()),
+        table_name: Set(event.table_name.as_ref().map(|t| t.val())),
+        stream_id: Set(event.stream_id.as_ref().map(|s| s.val())),
+        outcome: Set(build_model_outcome(outcome)),
+        error_message: Set(error_message),
+        created_at: Default::default(),
+        file_lock_key: Set(event.file_lock_key.clone()),
+    }
+}
+
+pub(super) fn build_domain_audit_log(log: &Model) -> Result<AuditLog, anyhow::Error> {
+    let table_name = match &log.table_name {
+        Some(name) => match UserTableName::try_from(name.clone()) {
+            Ok(name) => Some(name),
+            Err(msg) => bail!(msg),
+        },
+        None => None,
+    };
+
+    Ok(AuditLog {
+        id: log.id.into(),
+        event: AuditEvent {
+            operation: build_domain_operation(log.operation)?,
+            principal_id: log.principal_id.clone(),
+            principal_kind: build_domain_principal_kind(log.principal_kind)?,
+            client: ClientInfo::new(log.client_address.clone(), log.request_id.clone()),
+            table_name,
+            stream_id: log.stream_id.map(|v| v.into()),
+            file_lock_key: log.file_lock_key.clone(),
+        },
+        outcome: build_domain_outcome(log.outcome)?,
+        error_message: log.error_message.clone(),
+        created_at: log.created_at.into(),
+    })
+}
+
+pub(super) fn build_model_operation(operation: AuditOperation) -> i32 {
+    match operation {
+        AuditOperation::CreateTable => 0,
+        AuditOperation::AddFiles => 1,
+        AuditOperation::ChangeFiles => 2,
+        AuditOperation::CompactFiles => 3,
+        AuditOperation::AcquireFileLock => 4,
+        AuditOperation::ReleaseFileLock => 5,
+        AuditOperation::DropTable => 6,
+        AuditOperation::UndropTable => 7,
+        AuditOperation::RegisterStream => 8,
+        AuditOperation::SealStream => 9,
+        AuditOperation::DeleteStream => 10,
+        AuditOperation::RenameTable => 11,
+        AuditOperation::CreateTableAlias => 12,
+        AuditOperation::DropTableAlias => 13,
+        AuditOperation::AlterTableProperties => 14,
+        AuditOperation::PurgeTable => 15,
+    }
+}
+
+fn build_domain_operation(operation: i32) -> Result<AuditOperation, anyhow::Error> {
+    match operation {
+        0 => Ok(AuditOperation::CreateTable),
+        1 => Ok(AuditOperation::AddFiles),
+        2 => Ok(AuditOperation::ChangeFiles),
+        3 => Ok(AuditOperation::CompactFiles),
+        4 => Ok(AuditOperation::AcquireFileLock),
+        5 => Ok(AuditOperation::ReleaseFileLock),
+        6 => Ok(AuditOperation::DropTable),
+        7 => Ok(AuditOperation::UndropTable),
+        8 => Ok(AuditOperation::RegisterStream),
+        9 => Ok(AuditOperation::SealStream),
+        10 => Ok(AuditOperation::DeleteStream),
+        11 => Ok(AuditOperation::RenameTable),
+        12 => Ok(AuditOperation::CreateTableAlias),
+        13 => Ok(AuditOperation::DropTableAlias),
+        14 => Ok(AuditOperation::AlterTableProperties),
+        15 => Ok(AuditOperation::PurgeTable),
+        _ => bail!(MangrobeError::UnexpectedState(format!(
+            "invalid operation at AuditLog: {}",
+            operation
+        ))),
+    }
+}
+
+pub(super) fn build_model_outcome(outcome: AuditOutcome) -> i32 {
+    match outcome {
+        AuditOutcome::Succeeded => 0,
+        AuditOutcome::Denied => 1,
+        AuditOutcome::Failed => 2,
+    }
+}
+
+fn build_domain_outcome(outcome: i32) -> Result<AuditOutcome, anyhow::Error> {
+    match outcome {
+        0 => Ok(AuditOutcome::Succeeded),
+        1 => Ok(AuditOutcome::Denied),
+        2 => Ok(AuditOutcome::Failed),
+        _ => bail!(MangrobeError::UnexpectedState(format!(
+            "invalid outcome at AuditLog: {}",
+            outcome
+        ))),
+    }
+}
+
+fn build_model_principal_kind(kind: &PrincipalKind) -> i32 {
+    match kind {
+        PrincipalKind::Anonymous => 0,
+        PrincipalKind::StaticToken => 1,
+        PrincipalKind::Jwt => 2,
+        PrincipalKind::ClientCertificate => 3,
+        PrincipalKind::System => 4,
+    }
+}
+
+fn build_domain_principal_kind(kind: i32) -> Result<PrincipalKind, anyhow::Error> {
+    match kind {
+        0 => Ok(PrincipalKind::Anonymous),
+        1 => Ok(PrincipalKind::StaticToken),
+        2 => Ok(PrincipalKind::Jwt),
+        3 => Ok(PrincipalKind::ClientCertificate),
+        4 => Ok(PrincipalKind::System),
+        _ => bail!(MangrobeError::UnexpectedState(format!(
+            "invalid principal kind at AuditLog: {}",
+            kind
+        ))),
+    }
+}
@@ -0,0 +1,83 @@
+use crate::domain::model::audit_log::{AuditEvent, AuditLog, AuditLogFilter, AuditOutcome};
+use crate::domain::model::audit_log_id::AuditLogId;
+use crate::infrastructure::db::entity::audit_logs::Column;
+use crate::infrastructure::db::entity::prelude::AuditLogs;
+use crate::infrastructure::db::repository::audit_log_dto::{
+    build_domain_audit_log, build_entity_audit_log, build_model_operation, build_model_outcome,
+};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use tracing::instrument;
+
+// Audit logs are append-only. The table rejects updates and deletes.
+#[derive(Clone, Copy)]
+pub struct AuditLogRepository {}
+
+impl AuditLogRepository {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(skip_all, name = "AuditLogRepository::insert")]
+    pub async fn insert<C>(
+        &self,
+        conn: &C,
+        event: &AuditEvent,
+        outcome: AuditOutcome,
+        error_message: Option<String>,
+    ) -> Result<(), anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let log = build_entity_audit_log(event, outcome, error_message);
+        AuditLogs::insert(log).exec(conn).await?;
+
+        Ok(())
+    }
+
+    // Newest first.
+    #[instrument(skip_all, name = "AuditLogRepository::find_all")]
+    pub async fn find_all<C>(
+        &self,
+        conn: &C,
+        filter: &AuditLogFilter,
+        id_before: Option<&AuditLogId>,
+        limit: u64,
+    ) -> Result<Vec<AuditLog>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let mut query = AuditLogs::find();
+        if let Some(table_name) = &filter.table_name {
+            query = query.filter(Column::TableName.eq(table_name.val()));
+        }
+        if let Some(stream_id) = &filter.stream_id {
+            query = query.filter(Column::StreamId.eq(stream_id.val()));
+        }
+        if let Some(principal_id) = &filter.principal_id {
+            query = query.filter(Column::PrincipalId.eq(principal_id));
+        }
+        if let Some(operation) = filter.operation {
+            query = query.filter(Column::Operation.eq(build_model_operation(operation)));
+        }
+        if let Some(outcome) = filter.outcome {
+            query = query.filter(Column::Outcome.eq(build_model_outcome(outcome)));
+        }
+        if let Some(since) = filter.since {
+            query = query.filter(Column::CreatedAt.gte(since));
+        }
+        if let Some(until) = filter.until {
+            query = query.filter(Column::CreatedAt.lt(until));
+        }
+        if let Some(id_before) = id_before {
+            query = query.filter(Column::Id.lt(id_before.val()));
+        }
+
+        let logs = query
+            .order_by_desc(Column::Id)
+            .limit(limit)
+            .all(conn)
+            .await?;
+
+        logs.iter().map(build_domain_audit_log).collect()
+    }
+}
@@ -9,6 +9,7 @@ use chrono::{DateTime, Utc};
 use sea_orm::QueryFilter;
 use sea_orm::{ColumnTrait, ConnectionTrait};
 use sea_orm::{EntityTrait, TryInsertResult};
+use tracing::instrument;
 
 // ChangeRequestIdempotencyKeyRepository is only for other infra repositories. Must not be used from domain.
 pub(super) struct ChangeRequestIdempotencyKeyRepository {}
@@ -18,6 +19,7 @@ impl ChangeRequestIdempotencyKeyRepository {
         Self {}
     }
 
+    #[instrument(skip_all, name = "ChangeRequestIdempotencyKeyRepository::find_by_key")]
     pub async fn find_by_key<C>(
         &self,
         conn: &C,
@@ -28,6 +28,7 @@ use sea_orm::{
     QuerySelect,
 };
 use std::time::Duration;
+use tracing::instrument;
 
 pub struct ChangeRequestRepository {
     idempotency_key_repository: ChangeRequestIdempotencyKeyRepository,
@@ -40,6 +41,7 @@ impl ChangeRequestRepository {
         }
     }
 
+    #[instrument(skip_all, name = "ChangeRequestRepository::create")]
     pub async fn create<C>(
         &self,
         conn: &C,
@@ -53,13 +55,12 @@ impl ChangeRequestRepository {
         build_domain_change_request(&change_request)
     }
 
-    pub async fn find_by_idempotency_key_or_create<C>(
+    #[instrument(skip_all, name = "ChangeRequestRepository::find_by_idempotency_key")]
+    pub async fn find_by_idempotency_key<C>(
         &self,
         conn: &C,
         idempotency_key: &IdempotencyKey,
-        stream: &UserTablStream,
-        change_type: ChangeRequestType,
-    ) -> Result<ChangeRequest, anyhow::Error>
+    ) -> Result<Option<ChangeRequest>, anyhow::Error>
     where
         C: ConnectionTrait,
     {
@@ -67,14 +68,35 @@ impl ChangeRequestRepository {
             .idempotency_key_repository
             .find_by_key(conn, idempotency_key)
             .await?;
-        if let Some(existing_key) = existing_key {
-            let change_request = self
-                .find_by_id(conn, existing_key.change_request_id)
-                .await?;
-            if let Some(change_request) = change_request {
-                return build_domain_change_request(&change_request);
-            }
-            bail!("invalid state found. idempotency key doesn't belong any change requests");
+        let Some(existing_key) = existing_key else {
+            return Ok(None);
+        };
+
+        let change_request = self
+            .find_by_id(conn, existing_key.change_request_id)
+            .await?;
+        if let Some(change_request) = change_request {
+            return build_domain_change_request(&change_request).map(Some);
+        }
+        bail!("invalid state found. idempotency key doesn't belong any change requests");
+    }
+
+    #[instrument(
+        skip_all,
+        name = "ChangeRequestRepository::find_by_idempotency_key_or_create"
+    )]
+    pub async fn find_by_idempotency_key_or_create<C>(
+        &self,
+        conn: &C,
+        idempotency_key: &IdempotencyKey,
+        stream: &UserTablStream,
+        change_type: ChangeRequestType,
+    ) -> Result<ChangeRequest, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        if let Some(change_request) = self.find_by_idempotency_key(conn, idempotency_key).await? {
+            return Ok(change_request);
         }
 
         let change_request = self.insert(conn, stream, change_type).await?;
@@ -137,6 +159,7 @@ impl ChangeRequestRepository {
         Ok(res)
     }
 
+    #[instrument(skip_all, name = "ChangeRequestRepository::select_for_update")]
     pub async fn select_for_update<CR>(
         &self,
         txn: &DatabaseTransaction,
@@ -160,6 +183,7 @@ impl ChangeRequestRepository {
         build_domain_change_request(&selected)
     }
 
+    #[instrument(skip_all, name = "ChangeRequestRepository::update_status")]
     pub async fn update_status<CR>(
         &self,
         txn: &DatabaseTransaction,
@@ -182,6 +206,7 @@ impl ChangeRequestRepository {
         Ok(())
     }
 
+    #[instrument(skip_all, name = "ChangeRequestRepository::update_add_file_entry")]
     pub async fn update_add_file_entry<C, CR>(
         &self,
         conn: &C,
@@ -205,6 +230,7 @@ impl ChangeRequestRepository {
         Ok(add_files_entry)
     }
 
+    #[instrument(skip_all, name = "ChangeRequestRepository::update_change_file_entry")]
     pub async fn update_change_file_entry<C, CR>(
         &self,
         conn: &C,
@@ -228,6 +254,7 @@ impl ChangeRequestRepository {
         Ok(change_files_entry)
     }
 
+    #[instrument(skip_all, name = "ChangeRequestRepository::update_compact_file_entry")]
     pub async fn update_compact_file_entry<C, CR>(
         &self,
         conn: &C,
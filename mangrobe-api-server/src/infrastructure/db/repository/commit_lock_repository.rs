@@ -1,9 +1,11 @@
 use crate::domain::model::user_table_stream::UserTablStream;
+use crate::util::metrics::COMMIT_LOCK_WAIT_SECONDS;
 use ahash::RandomState;
 use sea_orm::ConnectionTrait;
 use sea_orm::DatabaseTransaction;
 use sea_orm::{DatabaseBackend, Statement};
 use std::hash::{BuildHasher, Hasher};
+use tracing::instrument;
 
 // Kx values MUST NOT be changed. When they are changed, the number for advisory lock will be changed.
 const K0: u64 = 0;
@@ -22,11 +24,13 @@ impl CommitLockRepository {
     }
 
     // Acquire a lock that will be released automatically when its transaction ends.
+    #[instrument(skip_all, name = "CommitLockRepository::acquire_xact_lock")]
     pub async fn acquire_xact_lock(
         &self,
         txn: &DatabaseTransaction,
         stream: &UserTablStream,
     ) -> Result<(), anyhow::Error> {
+        let _timer = COMMIT_LOCK_WAIT_SECONDS.start_timer();
         txn.execute(Statement::from_sql_and_values(
             DatabaseBackend::Postgres,
             "SELECT pg_advisory_xact_lock($1)",
@@ -2,7 +2,6 @@ use crate::domain::model::change_request_id::ChangeRequestId;
 use crate::domain::model::commit::Commit;
 use crate::domain::model::commit_id::CommitId;
 use crate::domain::model::committed_change_request::CommittedChangeRequest;
-use crate::domain::model::stream::Stream;
 use crate::domain::model::stream_id::StreamId;
 use crate::domain::model::user_table_id::UserTableId;
 use crate::domain::model::user_table_stream::UserTablStream;
@@ -11,11 +10,13 @@ use crate::infrastructure::db::entity::prelude::{ChangeRequests, Commits};
 use crate::infrastructure::db::entity_ext::change_request_ext::ChangeRequestExt;
 use crate::infrastructure::db::repository::commit_dto::{build_domain_commit, build_entity_commit};
 use crate::util::error::MangrobeError;
-use sea_orm::prelude::Expr;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::{DateTimeWithTimeZone, Expr};
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait, QueryFilter,
     QueryOrder, QuerySelect,
 };
+use tracing::instrument;
 
 #[derive(Clone, Copy)]
 pub struct CommitRepository {}
@@ -25,6 +26,7 @@ impl CommitRepository {
         Self {}
     }
 
+    #[instrument(skip_all, name = "CommitRepository::find_latest")]
     pub async fn find_latest<C>(
         &self,
         conn: &C,
@@ -47,6 +49,35 @@ impl CommitRepository {
         Ok(Some(build_domain_commit(&commit)))
     }
 
+    #[instrument(skip_all, name = "CommitRepository::find_last_committed_at")]
+    pub async fn find_last_committed_at<C>(
+        &self,
+        conn: &C,
+        table_id: &UserTableId,
+        stream_id: Option<&StreamId>,
+    ) -> Result<Option<DateTime<Utc>>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let mut query = Commits::find()
+            .select_only()
+            .column_as(Expr::col(Column::CommittedAt).max(), "last_committed_at")
+            .filter(Column::UserTableId.eq(table_id.val()));
+
+        if let Some(stream_id) = stream_id {
+            query = query.filter(Column::StreamId.eq(stream_id.val()));
+        }
+
+        let last_committed_at = query
+            .into_tuple::<Option<DateTimeWithTimeZone>>()
+            .one(conn)
+            .await?
+            .flatten();
+
+        Ok(last_committed_at.map(|t| t.into()))
+    }
+
+    #[instrument(skip_all, name = "CommitRepository::insert")]
     pub async fn insert<C>(
         &self,
         conn: &C,
@@ -63,6 +94,7 @@ impl CommitRepository {
         Ok(commit.id.into())
     }
 
+    #[instrument(skip_all, name = "CommitRepository::find_by_change_request_id")]
     pub async fn find_by_change_request_id(
         &self,
         txn: &DatabaseTransaction,
@@ -79,6 +111,7 @@ impl CommitRepository {
         Ok(commit.id.into())
     }
 
+    #[instrument(skip_all, name = "CommitRepository::find_change_requests_after")]
     pub async fn find_change_requests_after<C: ConnectionTrait>(
         &self,
         conn: &C,
@@ -113,40 +146,4 @@ impl CommitRepository {
 
         Ok(change_requests)
     }
-
-    pub async fn find_streams_after<C: ConnectionTrait>(
-        &self,
-        conn: &C,
-        table_id: &UserTableId,
-        stream_id: &Option<StreamId>,
-        limit: u64,
-    ) -> Result<Vec<Stream>, anyhow::Error> {
-        let mut query = Commits::find()
-            .select_only()
-            .column(Column::StreamId)
-            .column_as(Expr::col(Column::Id).max(), "last_commit_id")
-            .filter(Column::UserTableId.eq(table_id.val()))
-            .group_by(Column::StreamId);
-
-        if let Some(stream_id) = stream_id {
-            query = query.filter(Column::StreamId.gt(stream_id.val()));
-        }
-
-        let rows: Vec<(i64, i64)> = query
-            .order_by_asc(Column::StreamId)
-            .limit(limit)
-            .into_tuple::<(i64, i64)>()
-            .all(conn)
-            .await?;
-
-        let streams = rows
-            .iter()
-            .map(|(stream_id, last_commit_id)| Stream {
-                id: stream_id.into(),
-                last_commit_id: last_commit_id.into(),
-            })
-            .collect();
-
-        Ok(streams)
-    }
 }
@@ -2,10 +2,17 @@ use crate::domain::model::current_file::CurrentFile;
 use crate::domain::model::file::{FilePath, FileWithId};
 use crate::domain::model::file_id::FileId;
 use crate::domain::model::file_lock_key::FileLockKey;
+use crate::domain::model::file_stats::{
+    FILES_PER_PARTITION_BOUNDS, FileStats, FilesPerPartitionBucket,
+};
+use crate::domain::model::partition_time_range::PartitionTimeRange;
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_id::UserTableId;
+use crate::domain::model::user_table_name::UserTableName;
 use crate::domain::model::user_table_stream::UserTablStream;
-use crate::infrastructure::db::entity::current_files::{Column, Entity};
-use crate::infrastructure::db::entity::file_locks;
+use crate::infrastructure::db::entity::current_files::{Column, Entity, Relation};
 use crate::infrastructure::db::entity::prelude::{CurrentFiles, Files};
+use crate::infrastructure::db::entity::{file_locks, files, user_tables};
 use crate::infrastructure::db::repository::current_file_dto::{
     build_domain_current_file, build_entity_current_file,
 };
@@ -13,12 +20,16 @@ use crate::infrastructure::db::repository::file_dto::build_domain_file;
 use crate::infrastructure::db::repository::file_repository::FileRepository;
 use anyhow::bail;
 use chrono::{DateTime, Utc};
-use sea_orm::prelude::Expr;
-use sea_orm::sea_query::{LockType, Query};
-use sea_orm::{ColumnTrait, QuerySelect, Value};
+use sea_orm::prelude::{DateTimeWithTimeZone, Expr};
+use sea_orm::sea_query::extension::postgres::PgBinOper;
+use sea_orm::sea_query::{Alias, LockBehavior, LockType, Query};
+use sea_orm::{
+    ColumnTrait, EntityName, JoinType, QueryOrder, QuerySelect, RelationTrait, Select, Value,
+};
 use sea_orm::{Condition, QueryFilter};
-use sea_orm::{ConnectionTrait, EntityTrait};
-use std::collections::HashMap;
+use sea_orm::{ConnectionTrait, EntityTrait, QueryTrait};
+use std::collections::{BTreeMap, HashMap};
+use tracing::instrument;
 
 pub struct CurrentFileRepository {
     file_repository: FileRepository,
@@ -31,21 +42,261 @@ impl CurrentFileRepository {
         }
     }
 
-    pub async fn find_files_by_stream<C>(
+    // Returns the number of current files and the sum of their sizes.
+    #[instrument(
+        skip_all,
+        name = "CurrentFileRepository::count_files_and_bytes_by_stream"
+    )]
+    pub async fn count_files_and_bytes_by_stream<C>(
         &self,
         conn: &C,
         stream: &UserTablStream,
-    ) -> Result<Vec<FileWithId>, anyhow::Error>
+    ) -> Result<(u64, u64), anyhow::Error>
     where
         C: ConnectionTrait,
     {
-        let current_files = CurrentFiles::find()
-            .find_also_related(Files)
+        let result = CurrentFiles::find()
+            .select_only()
+            .column_as(Column::Id.count(), "file_count")
+            .column_as(
+                Expr::cust(format!(
+                    "COALESCE(SUM({}.size), 0)::BIGINT",
+                    files::Entity.table_name()
+                )),
+                "byte_count",
+            )
+            .join(JoinType::InnerJoin, Relation::Files.def())
             .filter(Column::UserTableId.eq(stream.user_table_id.val()))
             .filter(Column::StreamId.eq(stream.stream_id.val()))
+            .into_tuple::<(i64, i64)>()
+            .one(conn)
+            .await?;
+
+        let (file_count, byte_count) = result.unwrap_or((0, 0));
+        Ok((file_count as u64, byte_count as u64))
+    }
+
+    // Returns statistics of the current files of the table or the stream, aggregated by the database.
+    // last_committed_at is not filled.
+    #[instrument(skip_all, name = "CurrentFileRepository::find_file_stats")]
+    pub async fn find_file_stats<C>(
+        &self,
+        conn: &C,
+        table_id: &UserTableId,
+        stream_id: Option<&StreamId>,
+    ) -> Result<FileStats, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let table_name = Entity.table_name();
+        let mut summary_query = CurrentFiles::find()
+            .select_only()
+            .column_as(
+                Expr::cust(format!("COUNT(DISTINCT {table_name}.stream_id)")),
+                "stream_count",
+            )
+            .column_as(Column::Id.count(), "file_count")
+            .column_as(
+                Expr::cust(format!(
+                    "COALESCE(SUM({}.size), 0)::BIGINT",
+                    files::Entity.table_name()
+                )),
+                "byte_count",
+            )
+            .column_as(Column::PartitionTime.min(), "min_partition_time")
+            .column_as(Column::PartitionTime.max(), "max_partition_time")
+            .column_as(
+                Expr::cust(format!(
+                    "COUNT(DISTINCT ({table_name}.stream_id, {table_name}.partition_time))"
+                )),
+                "partition_count",
+            )
+            .join(JoinType::InnerJoin, Relation::Files.def())
+            .filter(Column::UserTableId.eq(table_id.val()));
+        // Partitions of the table or the stream, counting files of each.
+        let mut partition_query = CurrentFiles::find()
+            .select_only()
+            .column_as(Column::Id.count(), "file_count")
+            .filter(Column::UserTableId.eq(table_id.val()))
+            .group_by(Column::StreamId)
+            .group_by(Column::PartitionTime);
+
+        if let Some(stream_id) = stream_id {
+            summary_query = summary_query.filter(Column::StreamId.eq(stream_id.val()));
+            partition_query = partition_query.filter(Column::StreamId.eq(stream_id.val()));
+        }
+
+        let summary = summary_query
+            .into_tuple::<(
+                i64,
+                i64,
+                i64,
+                Option<DateTimeWithTimeZone>,
+                Option<DateTimeWithTimeZone>,
+                i64,
+            )>()
+            .one(conn)
+            .await?;
+        let Some((
+            stream_count,
+            file_count,
+            byte_count,
+            min_partition_time,
+            max_partition_time,
+            partition_count,
+        )) = summary
+        else {
+            bail!("aggregate query returned no rows")
+        };
+
+        // width_bucket returns the number of thresholds <= file_count, i.e. the index of the
+        // first bound >= file_count since file counts are integers.
+        let thresholds = FILES_PER_PARTITION_BOUNDS
+            .iter()
+            .map(|max_files| (max_files + 1).to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let histogram_query = Query::select()
+            .expr_as(
+                Expr::cust(format!("width_bucket(file_count, ARRAY[{thresholds}])")),
+                Alias::new("bucket"),
+            )
+            .expr_as(Expr::cust("COUNT(*)"), Alias::new("partition_count"))
+            .from_subquery(partition_query.into_query(), Alias::new("partitions"))
+            .group_by_col(Alias::new("bucket"))
+            .to_owned();
+        let rows = conn
+            .query_all(conn.get_database_backend().build(&histogram_query))
+            .await?;
+        let mut partition_counts = HashMap::new();
+        for row in rows {
+            let bucket: i32 = row.try_get("", "bucket")?;
+            let count: i64 = row.try_get("", "partition_count")?;
+            partition_counts.insert(bucket as usize, count as u64);
+        }
+
+        Ok(FileStats {
+            stream_count: stream_count as u64,
+            file_count: file_count as u64,
+            total_bytes: byte_count as u64,
+            min_partition_time: min_partition_time.map(|t| t.into()),
+            max_partition_time: max_partition_time.map(|t| t.into()),
+            partition_count: partition_count as u64,
+            last_committed_at: None,
+            files_per_partition: FilesPerPartitionBucket::histogram(&partition_counts),
+        })
+    }
+
+    #[instrument(skip_all, name = "CurrentFileRepository::exists_by_table")]
+    pub async fn exists_by_table<C>(
+        &self,
+        conn: &C,
+        table_id: &UserTableId,
+    ) -> Result<bool, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let file = CurrentFiles::find()
+            .filter(Column::UserTableId.eq(table_id.val()))
+            .one(conn)
+            .await?;
+
+        Ok(file.is_some())
+    }
+
+    // Returns the number of current files and the sum of their sizes by stream. Streams without files are omitted.
+    #[instrument(skip_all, name = "CurrentFileRepository::count_files_by_stream")]
+    pub async fn count_files_by_stream<C>(
+        &self,
+        conn: &C,
+        table_id: &UserTableId,
+        stream_ids: &[StreamId],
+    ) -> Result<HashMap<StreamId, (u64, u64)>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let rows = CurrentFiles::find()
+            .select_only()
+            .column(Column::StreamId)
+            .column_as(Column::Id.count(), "file_count")
+            .column_as(
+                Expr::cust(format!(
+                    "COALESCE(SUM({}.size), 0)::BIGINT",
+                    files::Entity.table_name()
+                )),
+                "byte_count",
+            )
+            .join(JoinType::InnerJoin, Relation::Files.def())
+            .filter(Column::UserTableId.eq(table_id.val()))
+            .filter(Column::StreamId.is_in(stream_ids.iter().map(|s| s.val())))
+            .group_by(Column::StreamId)
+            .into_tuple::<(i64, i64, i64)>()
             .all(conn)
             .await?;
 
+        Ok(rows
+            .into_iter()
+            .map(|(stream_id, file_count, byte_count)| {
+                (stream_id.into(), (file_count as u64, byte_count as u64))
+            })
+            .collect())
+    }
+
+    // Returns the number of current files of every table that has any, except dropped tables.
+    // Ordered by the number in descending order.
+    #[instrument(skip_all, name = "CurrentFileRepository::count_files_by_table")]
+    pub async fn count_files_by_table<C>(
+        &self,
+        conn: &C,
+    ) -> Result<Vec<(UserTableName, u64)>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let counts = CurrentFiles::find()
+            .select_only()
+            .column(user_tables::Column::Name)
+            .column_as(Column::Id.count(), "file_count")
+            .join(JoinType::InnerJoin, Relation::UserTables.def())
+            .filter(user_tables::Column::DroppedAt.is_null())
+            .group_by(user_tables::Column::Name)
+            .order_by_desc(Column::Id.count())
+            .into_tuple::<(String, i64)>()
+            .all(conn)
+            .await?;
+
+        let mut res = vec![];
+        for (name, count) in counts {
+            match UserTableName::try_from(name) {
+                Ok(name) => res.push((name, count as u64)),
+                Err(msg) => bail!(msg),
+            }
+        }
+        Ok(res)
+    }
+
+    #[instrument(skip_all, name = "CurrentFileRepository::find_files_by_stream")]
+    pub async fn find_files_by_stream<C>(
+        &self,
+        conn: &C,
+        stream: &UserTablStream,
+        partition_filter: &BTreeMap<String, String>,
+    ) -> Result<Vec<FileWithId>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let mut query = CurrentFiles::find()
+            .find_also_related(Files)
+            .filter(Column::UserTableId.eq(stream.user_table_id.val()))
+            .filter(Column::StreamId.eq(stream.stream_id.val()));
+        if !partition_filter.is_empty() {
+            let values = serde_json::to_value(partition_filter)?;
+            query = query.filter(
+                Expr::col((Files, files::Column::PartitionValues))
+                    .binary(PgBinOper::Contains, Expr::val(values)),
+            );
+        }
+        let current_files = query.all(conn).await?;
+
         let result = current_files
             .iter()
             .filter_map(|(_current_file, file)| {
@@ -58,6 +309,75 @@ impl CurrentFileRepository {
         Ok(result)
     }
 
+    // Partition times holding current files smaller than max_size that are not locked by an
+    // active lock, in ascending order after the given time.
+    #[instrument(
+        skip_all,
+        name = "CurrentFileRepository::find_partition_times_with_unlocked_files_smaller_than"
+    )]
+    pub async fn find_partition_times_with_unlocked_files_smaller_than<C>(
+        &self,
+        conn: &C,
+        stream: &UserTablStream,
+        max_size: i64,
+        partition_time_range: &PartitionTimeRange,
+        after: Option<DateTime<Utc>>,
+        limit: u64,
+    ) -> Result<Vec<DateTime<Utc>>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let mut query = unlocked_files_smaller_than(stream, max_size, partition_time_range)
+            .select_only()
+            .column(Column::PartitionTime)
+            .distinct()
+            .order_by_asc(Column::PartitionTime)
+            .limit(limit);
+        if let Some(after) = after {
+            query = query.filter(Column::PartitionTime.gt(after));
+        }
+        let partition_times = query.into_tuple::<DateTimeWithTimeZone>().all(conn).await?;
+
+        Ok(partition_times.into_iter().map(|t| t.into()).collect())
+    }
+
+    // Current files smaller than max_size that are not locked by an active lock. With for_update,
+    // rows locked by concurrent transactions are skipped so that concurrent planners pick
+    // different files.
+    #[instrument(
+        skip_all,
+        name = "CurrentFileRepository::find_unlocked_files_smaller_than"
+    )]
+    pub async fn find_unlocked_files_smaller_than<C>(
+        &self,
+        conn: &C,
+        stream: &UserTablStream,
+        max_size: i64,
+        partition_time_range: &PartitionTimeRange,
+        for_update: bool,
+    ) -> Result<Vec<FileWithId>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let mut query = unlocked_files_smaller_than(stream, max_size, partition_time_range)
+            .select_also(Files)
+            .order_by_asc(Column::PartitionTime)
+            .order_by_asc(Column::FileId);
+        if for_update {
+            query = query.lock_with_behavior(LockType::Update, LockBehavior::SkipLocked);
+        }
+        let current_files = query.all(conn).await?;
+
+        Ok(current_files
+            .iter()
+            .filter_map(|(_current_file, file)| file.as_ref().map(build_domain_file))
+            .collect())
+    }
+
+    #[instrument(
+        skip_all,
+        name = "CurrentFileRepository::select_locked_file_ids_for_update"
+    )]
     pub async fn select_locked_file_ids_for_update<C>(
         &self,
         conn: &C,
@@ -82,6 +402,10 @@ impl CurrentFileRepository {
         Ok(file_ids)
     }
 
+    #[instrument(
+        skip_all,
+        name = "CurrentFileRepository::select_files_by_paths_for_update"
+    )]
     pub async fn select_files_by_paths_for_update<C>(
         &self,
         conn: &C,
@@ -111,6 +435,7 @@ impl CurrentFileRepository {
         Ok(files)
     }
 
+    #[instrument(skip_all, name = "CurrentFileRepository::acquire_lock")]
     pub async fn acquire_lock<C>(
         &self,
         conn: &C,
@@ -144,6 +469,7 @@ impl CurrentFileRepository {
         Ok(locked_files.rows_affected)
     }
 
+    #[instrument(skip_all, name = "CurrentFileRepository::insert_many")]
     pub async fn insert_many<C>(
         &self,
         conn: &C,
@@ -182,6 +508,7 @@ impl CurrentFileRepository {
         Ok(())
     }
 
+    #[instrument(skip_all, name = "CurrentFileRepository::delete_many")]
     pub async fn delete_many<C>(
         &self,
         conn: &C,
@@ -201,6 +528,7 @@ impl CurrentFileRepository {
         Ok(())
     }
 
+    #[instrument(skip_all, name = "CurrentFileRepository::release_lock")]
     pub async fn release_lock<C>(
         &self,
         conn: &C,
@@ -218,3 +546,33 @@ impl CurrentFileRepository {
         Ok(())
     }
 }
+
+fn unlocked_files_smaller_than(
+    stream: &UserTablStream,
+    max_size: i64,
+    partition_time_range: &PartitionTimeRange,
+) -> Select<CurrentFiles> {
+    let mut query = CurrentFiles::find()
+        .join(JoinType::InnerJoin, Relation::Files.def())
+        .filter(Column::UserTableId.eq(stream.user_table_id.val()))
+        .filter(Column::StreamId.eq(stream.stream_id.val()))
+        .filter(Expr::col((Files, files::Column::Size)).lt(max_size))
+        .filter(
+            Condition::any().add(Column::FileLockKey.is_null()).add(
+                Column::FileLockKey.not_in_subquery(
+                    Query::select()
+                        .column(file_locks::Column::Key)
+                        .from(file_locks::Entity)
+                        .and_where(file_locks::Column::ExpireAt.gte(Utc::now()))
+                        .to_owned(),
+                ),
+            ),
+        );
+    if let Some(min) = partition_time_range.min {
+        query = query.filter(Column::PartitionTime.gte(min));
+    }
+    if let Some(max) = partition_time_range.max {
+        query = query.filter(Column::PartitionTime.lte(max));
+    }
+    query
+}
@@ -8,6 +8,7 @@ use crate::infrastructure::db::repository::file_column_statistics_dto::{
 use sea_orm::QueryFilter;
 use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait};
 use std::collections::HashMap;
+use tracing::instrument;
 
 pub struct FileColumnStatisticsRepository {}
 
@@ -16,6 +17,7 @@ impl FileColumnStatisticsRepository {
         Self {}
     }
 
+    #[instrument(skip_all, name = "FileColumnStatisticsRepository::insert_many")]
     pub async fn insert_many<C>(
         &self,
         conn: &C,
@@ -38,6 +40,10 @@ impl FileColumnStatisticsRepository {
         Ok(())
     }
 
+    #[instrument(
+        skip_all,
+        name = "FileColumnStatisticsRepository::find_file_column_stats_map_by_id"
+    )]
     pub async fn find_file_column_stats_map_by_id<C: ConnectionTrait>(
         &self,
         conn: &C,
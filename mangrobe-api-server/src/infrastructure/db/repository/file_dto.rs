@@ -3,6 +3,7 @@ use crate::domain::model::user_table_stream::UserTablStream;
 use crate::infrastructure::db::entity::files::ActiveModel;
 use crate::infrastructure::db::entity::files;
 use sea_orm::Set;
+use serde_json::Value;
 
 pub(super) fn build_entity_file(file: &File) -> ActiveModel {
     ActiveModel {
@@ -13,17 +14,28 @@ pub(super) fn build_entity_file(file: &File) -> ActiveModel {
         path: Set(file.path.path()),
         path_xxh3: Set(file.path.to_xxh3_128()),
         size: Set(file.size),
+        partition_values: Set(Value::Object(
+            file.partition_values
+                .iter()
+                .map(|(k, v)| (k.clone(), Value::String(v.clone())))
+                .collect(),
+        )),
         created_at: Default::default(),
         updated_at: Default::default(),
     }
 }
 
 pub(super) fn build_domain_file(file: &files::Model) -> FileWithId {
+    // Values are written from a string map, so other shapes are not expected.
+    let partition_values =
+        serde_json::from_value(file.partition_values.clone()).unwrap_or_default();
+
     FileWithId::new(
         file.id.into(),
         UserTablStream::new(file.user_table_id.into(), file.stream_id.into()),
         file.partition_time.into(),
         file.path.clone().into(),
         file.size,
+        partition_values,
     )
 }
@@ -1,12 +1,15 @@
+use crate::domain::model::file_lock::FileLock;
 use crate::domain::model::file_lock_key::FileLockKey;
+use crate::domain::model::file_lock_owner::FileLockOwner;
 use crate::domain::model::user_table_stream::UserTablStream;
-use crate::infrastructure::db::entity::file_locks::ActiveModel;
+use crate::infrastructure::db::entity::file_locks::{ActiveModel, Model};
 use chrono::{DateTime, Utc};
 use sea_orm::Set;
 
 pub(super) fn build_entity_file_lock(
     key: &FileLockKey,
     stream: &UserTablStream,
+    owner: &FileLockOwner,
     expire_at: DateTime<Utc>,
 ) -> ActiveModel {
     ActiveModel {
@@ -16,5 +19,16 @@ pub(super) fn build_entity_file_lock(
         expire_at: Set(expire_at.into()),
         created_at: Default::default(),
         updated_at: Default::default(),
+        owner_id: Set(owner.owner_id.clone()),
+        reason: Set(owner.reason.clone()),
     }
 }
+
+pub(super) fn build_domain_file_lock(file_lock: &Model) -> FileLock {
+    FileLock::new(
+        file_lock.key.clone(),
+        UserTablStream::new(file_lock.user_table_id.into(), file_lock.stream_id.into()),
+        FileLockOwner::new(file_lock.owner_id.clone(), file_lock.reason.clone()),
+        file_lock.expire_at.into(),
+    )
+}
@@ -1,11 +1,19 @@
+use crate::domain::model::file_id::FileId;
+use crate::domain::model::file_lock::FileLock;
 use crate::domain::model::file_lock_key::FileLockKey;
+use crate::domain::model::file_lock_owner::FileLockOwner;
 use crate::domain::model::user_table_stream::UserTablStream;
+use crate::infrastructure::db::entity::current_files;
 use crate::infrastructure::db::entity::file_locks::{Column, Entity};
 use crate::infrastructure::db::entity::prelude::FileLocks;
-use crate::infrastructure::db::repository::file_lock_dto::build_entity_file_lock;
+use crate::infrastructure::db::repository::file_lock_dto::{
+    build_domain_file_lock, build_entity_file_lock,
+};
 use chrono::{Duration, Utc};
+use sea_orm::sea_query::Query;
 use sea_orm::{ColumnTrait, TryInsertResult};
 use sea_orm::{ConnectionTrait, EntityTrait, QueryFilter};
+use tracing::instrument;
 
 pub struct FileLockRepository {}
 
@@ -14,6 +22,7 @@ impl FileLockRepository {
         Self {}
     }
 
+    #[instrument(skip_all, name = "FileLockRepository::exists")]
     pub async fn exists<C>(&self, conn: &C, key: &FileLockKey) -> Result<bool, anyhow::Error>
     where
         C: ConnectionTrait,
@@ -27,17 +36,69 @@ impl FileLockRepository {
         Ok(lock.is_some())
     }
 
+    #[instrument(skip_all, name = "FileLockRepository::find_by_key")]
+    pub async fn find_by_key<C>(
+        &self,
+        conn: &C,
+        key: &FileLockKey,
+    ) -> Result<Option<FileLock>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let lock = FileLocks::find_by_id(key.key.clone()).one(conn).await?;
+
+        Ok(lock.as_ref().map(build_domain_file_lock))
+    }
+
+    // Find unexpired locks holding any of the files, except the lock of `key`.
+    #[instrument(skip_all, name = "FileLockRepository::find_active_by_file_ids")]
+    pub async fn find_active_by_file_ids<C>(
+        &self,
+        conn: &C,
+        key: &FileLockKey,
+        stream: &UserTablStream,
+        file_ids: &[FileId],
+    ) -> Result<Vec<FileLock>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let locks = FileLocks::find()
+            .filter(
+                Column::Key.in_subquery(
+                    Query::select()
+                        .column(current_files::Column::FileLockKey)
+                        .from(current_files::Entity)
+                        .and_where(
+                            current_files::Column::UserTableId.eq(stream.user_table_id.val()),
+                        )
+                        .and_where(current_files::Column::StreamId.eq(stream.stream_id.val()))
+                        .and_where(
+                            current_files::Column::FileId.is_in(file_ids.iter().map(|id| id.val())),
+                        )
+                        .to_owned(),
+                ),
+            )
+            .filter(Column::Key.ne(key.key.clone()))
+            .filter(Column::ExpireAt.gte(Utc::now()))
+            .all(conn)
+            .await?;
+
+        Ok(locks.iter().map(build_domain_file_lock).collect())
+    }
+
+    #[instrument(skip_all, name = "FileLockRepository::acquire")]
     pub async fn acquire<C>(
         &self,
         conn: &C,
         stream: &UserTablStream,
         ttl: Duration,
         key: &FileLockKey,
+        owner: &FileLockOwner,
     ) -> Result<bool, anyhow::Error>
     where
         C: ConnectionTrait,
     {
-        let lock = build_entity_file_lock(key, stream, Utc::now() + ttl);
+        let lock = build_entity_file_lock(key, stream, owner, Utc::now() + ttl);
 
         let result = Entity::insert(lock)
             .on_conflict_do_nothing()
@@ -53,6 +114,7 @@ impl FileLockRepository {
         Ok(inserted)
     }
 
+    #[instrument(skip_all, name = "FileLockRepository::release")]
     pub async fn release<C>(&self, conn: &C, key: &FileLockKey) -> Result<bool, anyhow::Error>
     where
         C: ConnectionTrait,
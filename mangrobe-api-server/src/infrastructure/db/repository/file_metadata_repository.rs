@@ -8,6 +8,7 @@ use crate::infrastructure::db::repository::file_metadata_dto::{
 use sea_orm::QueryFilter;
 use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QuerySelect};
 use std::collections::HashMap;
+use tracing::instrument;
 
 pub struct FileMetadataRepository {}
 
@@ -16,6 +17,7 @@ impl FileMetadataRepository {
         Self {}
     }
 
+    #[instrument(skip_all, name = "FileMetadataRepository::insert_many")]
     pub async fn insert_many<C>(
         &self,
         conn: &C,
@@ -37,6 +39,10 @@ impl FileMetadataRepository {
     }
 
     // Because metadata can be large, select only specified metadata column.
+    #[instrument(
+        skip_all,
+        name = "FileMetadataRepository::find_column_selected_metadata_map_by_id"
+    )]
     pub async fn find_column_selected_metadata_map_by_id<C: ConnectionTrait>(
         &self,
         conn: &C,
@@ -4,11 +4,10 @@ use crate::domain::model::user_table_stream::UserTablStream;
 use crate::infrastructure::db::entity::files;
 use crate::infrastructure::db::entity::files::Column;
 use crate::infrastructure::db::entity::prelude::Files;
-use crate::infrastructure::db::repository::file_dto::{
-    build_domain_file, build_entity_file,
-};
+use crate::infrastructure::db::repository::file_dto::{build_domain_file, build_entity_file};
 use chrono::{DateTime, Utc};
 use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QuerySelect};
+use tracing::instrument;
 
 #[derive(Clone, Copy)]
 pub struct FileRepository {}
@@ -18,6 +17,7 @@ impl FileRepository {
         Self {}
     }
 
+    #[instrument(skip_all, name = "FileRepository::find_all_by_ids")]
     pub async fn find_all_by_ids<C>(
         &self,
         conn: &C,
@@ -34,6 +34,7 @@ impl FileRepository {
         Ok(domain_files)
     }
 
+    #[instrument(skip_all, name = "FileRepository::find_all_ids_by_paths")]
     pub async fn find_all_ids_by_paths<C>(
         &self,
         conn: &C,
@@ -82,6 +83,7 @@ impl FileRepository {
         Ok(files)
     }
 
+    #[instrument(skip_all, name = "FileRepository::find_all_files_by_ids")]
     pub async fn find_all_files_by_ids<C>(
         &self,
         conn: &C,
@@ -99,6 +101,7 @@ impl FileRepository {
         Ok(res)
     }
 
+    #[instrument(skip_all, name = "FileRepository::insert")]
     pub async fn insert<C>(&self, conn: &C, target_file: &File) -> Result<FileId, anyhow::Error>
     where
         C: ConnectionTrait,
@@ -109,6 +112,7 @@ impl FileRepository {
         Ok(inserted.id.into())
     }
 
+    #[instrument(skip_all, name = "FileRepository::insert_many")]
     pub async fn insert_many<C>(
         &self,
         conn: &C,
@@ -0,0 +1,51 @@
+use crate::infrastructure::db::entity::prelude::{Files, GarbageFiles};
+use crate::infrastructure::db::entity::{files, garbage_files};
+use sea_orm::ConnectionTrait;
+use sea_orm::sea_query::{Condition, Query};
+use tracing::instrument;
+
+// GarbageFileRepository is only for other infra repositories. Must not be used from domain.
+pub(super) struct GarbageFileRepository {}
+
+impl GarbageFileRepository {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    // Queues the object paths of the files matching the condition, before the files rows are deleted.
+    // Returns the number of queued paths.
+    #[instrument(skip_all, name = "GarbageFileRepository::insert_from_files")]
+    pub async fn insert_from_files<C>(
+        &self,
+        conn: &C,
+        condition: Condition,
+    ) -> Result<u64, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let select = Query::select()
+            .columns([
+                files::Column::UserTableId,
+                files::Column::StreamId,
+                files::Column::Path,
+            ])
+            .from(Files)
+            .cond_where(condition)
+            .to_owned();
+        let insert = Query::insert()
+            .into_table(GarbageFiles)
+            .columns([
+                garbage_files::Column::UserTableId,
+                garbage_files::Column::StreamId,
+                garbage_files::Column::Path,
+            ])
+            .select_from(select)?
+            .to_owned();
+
+        let result = conn
+            .execute(conn.get_database_backend().build(&insert))
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::domain::model::stream::{Stream, StreamMetadata, StreamState};
+use crate::domain::model::user_table_stream::UserTablStream;
+use crate::infrastructure::db::entity::streams::{ActiveModel, Model};
+use crate::util::error::MangrobeError;
+use anyhow::bail;
+use sea_orm::Set;
+use std::collections::BTreeMap;
+
+pub(super) fn build_entity_stream(
+    stream: &UserTablStream,
+    metadata: &StreamMetadata,
+) -> Result<ActiveModel, anyhow::Error> {
+    Ok(ActiveModel {
+        id: Default::default(),
+        user_table_id: Set(stream.user_table_id.val()),
+        stream_id: Set(stream.stream_id.val()),
+        tenant_name: Set(metadata.tenant_name.clone()),
+        labels: Set(serde_json::to_value(&metadata.labels)?),
+        state: Set(build_model_state(StreamState::Active)),
+        sealed_at: Default::default(),
+        created_at: Default::default(),
+        updated_at: Default::default(),
+        last_commit_id: Default::default(),
+        last_committed_at: Default::default(),
+    })
+}
+
+pub(super) fn build_domain_stream(stream: &Model) -> Result<Stream, anyhow::Error> {
+    let labels: BTreeMap<String, String> = serde_json::from_value(stream.labels.clone())?;
+
+    Ok(Stream {
+        id: stream.stream_id.into(),
+        metadata: StreamMetadata {
+            tenant_name: stream.tenant_name.clone(),
+            labels,
+        },
+        state: build_domain_state(stream.state)?,
+        sealed_at: stream.sealed_at.map(|t| t.into()),
+        created_at: stream.created_at.into(),
+        updated_at: stream.updated_at.into(),
+        last_commit_id: stream.last_commit_id.map(|id| id.into()),
+        last_committed_at: stream.last_committed_at.map(|t| t.into()),
+        file_count: 0,
+        total_bytes: 0,
+    })
+}
+
+pub(super) fn build_model_state(state: StreamState) -> i32 {
+    match state {
+        StreamState::Active => 0,
+        StreamState::Sealed => 1,
+    }
+}
+
+fn build_domain_state(state: i32) -> Result<StreamState, anyhow::Error> {
+    match state {
+        0 => Ok(StreamState::Active),
+        1 => Ok(StreamState::Sealed),
+        _ => bail!(MangrobeError::UnexpectedState(format!(
+            "invalid state at Stream: {}",
+            state
+        ))),
+    }
+}
@@ -0,0 +1,217 @@
+use crate::domain::model::commit_id::CommitId;
+use crate::domain::model::stream::{Stream, StreamFilter, StreamMetadata, StreamState};
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_id::UserTableId;
+use crate::domain::model::user_table_stream::UserTablStream;
+use crate::infrastructure::db::entity::prelude::Streams;
+use crate::infrastructure::db::entity::streams::{Column, Entity};
+use crate::infrastructure::db::repository::stream_dto::{
+    build_domain_stream, build_entity_stream, build_model_state,
+};
+use crate::infrastructure::db::repository::table_data_repository::TableDataRepository;
+use crate::util::error::MangrobeError;
+use sea_orm::prelude::Expr;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::sea_query::extension::postgres::PgBinOper;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    TryInsertResult,
+};
+use tracing::instrument;
+
+pub struct StreamRepository {
+    table_data_repository: TableDataRepository,
+}
+
+impl StreamRepository {
+    pub fn new() -> Self {
+        Self {
+            table_data_repository: TableDataRepository::new(),
+        }
+    }
+
+    #[instrument(skip_all, name = "StreamRepository::find")]
+    pub async fn find<C>(
+        &self,
+        conn: &C,
+        stream: &UserTablStream,
+    ) -> Result<Option<Stream>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let model = Streams::find()
+            .filter(Column::UserTableId.eq(stream.user_table_id.val()))
+            .filter(Column::StreamId.eq(stream.stream_id.val()))
+            .one(conn)
+            .await?;
+
+        model.as_ref().map(build_domain_stream).transpose()
+    }
+
+    // Returns None when the stream already exists.
+    #[instrument(skip_all, name = "StreamRepository::insert_if_not_exists")]
+    pub async fn insert_if_not_exists<C>(
+        &self,
+        conn: &C,
+        stream: &UserTablStream,
+        metadata: &StreamMetadata,
+    ) -> Result<Option<Stream>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let result = Entity::insert(build_entity_stream(stream, metadata)?)
+            .on_conflict(
+                OnConflict::columns([Column::UserTableId, Column::StreamId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec_without_returning(conn)
+            .await?;
+
+        let TryInsertResult::Inserted(rows) = result else {
+            return Ok(None);
+        };
+        if rows == 0 {
+            return Ok(None);
+        }
+
+        self.find(conn, stream).await
+    }
+
+    // Streams are created implicitly by their first commit unless registered beforehand.
+    #[instrument(skip_all, name = "StreamRepository::find_or_create")]
+    pub async fn find_or_create<C>(
+        &self,
+        conn: &C,
+        stream: &UserTablStream,
+    ) -> Result<Stream, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        if let Some(inserted) = self
+            .insert_if_not_exists(conn, stream, &StreamMetadata::default())
+            .await?
+        {
+            return Ok(inserted);
+        }
+
+        self.find(conn, stream)
+            .await?
+            .ok_or(anyhow::Error::from(MangrobeError::UnexpectedState(
+                "stream not found after conflicting on insert".to_string(),
+            )))
+    }
+
+    // Streams ordered by stream_id. The cursor is the last stream_id of the previous page.
+    #[instrument(skip_all, name = "StreamRepository::find_all")]
+    pub async fn find_all<C>(
+        &self,
+        conn: &C,
+        table_id: &UserTableId,
+        filter: &StreamFilter,
+        cursor: Option<&StreamId>,
+        descending: bool,
+        limit: u64,
+    ) -> Result<Vec<Stream>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let mut query = Streams::find().filter(Column::UserTableId.eq(table_id.val()));
+        if let Some(updated_since) = filter.updated_since {
+            query = query.filter(Column::UpdatedAt.gte(updated_since));
+        }
+        if let Some(min) = &filter.stream_id_range.min {
+            query = query.filter(Column::StreamId.gte(min.val()));
+        }
+        if let Some(max) = &filter.stream_id_range.max {
+            query = query.filter(Column::StreamId.lte(max.val()));
+        }
+        if !filter.label_selector.is_empty() {
+            let labels = serde_json::to_value(&filter.label_selector)?;
+            query = query
+                .filter(Expr::col(Column::Labels).binary(PgBinOper::Contains, Expr::val(labels)));
+        }
+
+        query = match (cursor, descending) {
+            (Some(cursor), false) => query.filter(Column::StreamId.gt(cursor.val())),
+            (Some(cursor), true) => query.filter(Column::StreamId.lt(cursor.val())),
+            (None, _) => query,
+        };
+        query = if descending {
+            query.order_by_desc(Column::StreamId)
+        } else {
+            query.order_by_asc(Column::StreamId)
+        };
+
+        let streams = query.limit(limit).all(conn).await?;
+
+        streams.iter().map(build_domain_stream).collect()
+    }
+
+    // Must be called in the transaction of the commit.
+    #[instrument(skip_all, name = "StreamRepository::update_last_commit")]
+    pub async fn update_last_commit<C>(
+        &self,
+        conn: &C,
+        stream: &UserTablStream,
+        commit_id: &CommitId,
+    ) -> Result<(), anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        Streams::update_many()
+            .col_expr(
+                Column::LastCommitId,
+                Expr::value(i64::from(commit_id.clone())),
+            )
+            .col_expr(Column::LastCommittedAt, Expr::current_timestamp().into())
+            .filter(Column::UserTableId.eq(stream.user_table_id.val()))
+            .filter(Column::StreamId.eq(stream.stream_id.val()))
+            .exec(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    // Returns None when the stream is not found or already sealed.
+    #[instrument(skip_all, name = "StreamRepository::seal")]
+    pub async fn seal<C>(
+        &self,
+        conn: &C,
+        stream: &UserTablStream,
+    ) -> Result<Option<Stream>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let streams = Streams::update_many()
+            .col_expr(
+                Column::State,
+                Expr::value(build_model_state(StreamState::Sealed)),
+            )
+            .col_expr(Column::SealedAt, Expr::current_timestamp().into())
+            .filter(Column::UserTableId.eq(stream.user_table_id.val()))
+            .filter(Column::StreamId.eq(stream.stream_id.val()))
+            .filter(Column::State.eq(build_model_state(StreamState::Active)))
+            .exec_with_returning(conn)
+            .await?;
+
+        streams.first().map(build_domain_stream).transpose()
+    }
+
+    // Deletes the stream and every row that refers to it. Audit logs are kept.
+    // Object paths of the files are queued in garbage_files. Returns the number of queued paths.
+    #[instrument(skip_all, name = "StreamRepository::delete_with_data")]
+    pub async fn delete_with_data<C>(
+        &self,
+        conn: &C,
+        stream: &UserTablStream,
+    ) -> Result<u64, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        self.table_data_repository
+            .delete(conn, &stream.user_table_id, Some(&stream.stream_id))
+            .await
+    }
+}
@@ -0,0 +1,28 @@
+use crate::domain::model::table_alias::TableAlias;
+use crate::domain::model::user_table_id::UserTableId;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::infrastructure::db::entity::table_aliases::{ActiveModel, Model};
+use anyhow::bail;
+use sea_orm::Set;
+
+pub(super) fn build_entity_table_alias(
+    name: &UserTableName,
+    table_id: &UserTableId,
+) -> ActiveModel {
+    ActiveModel {
+        id: Default::default(),
+        name: Set(name.val()),
+        user_table_id: Set(table_id.val()),
+        created_at: Default::default(),
+    }
+}
+
+pub(super) fn build_domain_table_alias(alias: &Model) -> Result<TableAlias, anyhow::Error> {
+    match UserTableName::try_from(alias.name.clone()) {
+        Ok(name) => Ok(TableAlias {
+            name,
+            user_table_id: alias.user_table_id.into(),
+        }),
+        Err(msg) => bail!(msg),
+    }
+}
@@ -0,0 +1,104 @@
+use crate::domain::model::table_alias::TableAlias;
+use crate::domain::model::user_table_id::UserTableId;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::infrastructure::db::entity::prelude::TableAliases;
+use crate::infrastructure::db::entity::table_aliases::Column;
+use crate::infrastructure::db::repository::table_alias_dto::{
+    build_domain_table_alias, build_entity_table_alias,
+};
+use anyhow::bail;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, SqlErr};
+use thiserror::Error;
+use tracing::instrument;
+
+#[derive(Clone, Copy)]
+pub struct TableAliasRepository {}
+
+#[derive(Error, Debug)]
+pub enum TableAliasRepositoryError {
+    #[error("Already exists.")]
+    AlreadyExists,
+}
+
+impl TableAliasRepository {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    #[instrument(skip_all, name = "TableAliasRepository::find_by_name")]
+    pub async fn find_by_name<C>(
+        &self,
+        conn: &C,
+        name: &UserTableName,
+    ) -> Result<Option<TableAlias>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let alias = TableAliases::find()
+            .filter(Column::Name.eq(name.val()))
+            .one(conn)
+            .await?;
+
+        alias.as_ref().map(build_domain_table_alias).transpose()
+    }
+
+    // Ordered by name.
+    #[instrument(skip_all, name = "TableAliasRepository::find_all_by_table_ids")]
+    pub async fn find_all_by_table_ids<C>(
+        &self,
+        conn: &C,
+        table_ids: &[UserTableId],
+    ) -> Result<Vec<TableAlias>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let aliases = TableAliases::find()
+            .filter(Column::UserTableId.is_in(table_ids.iter().map(|id| id.val())))
+            .order_by_asc(Column::Name)
+            .all(conn)
+            .await?;
+
+        aliases.iter().map(build_domain_table_alias).collect()
+    }
+
+    #[instrument(skip_all, name = "TableAliasRepository::insert")]
+    pub async fn insert<C>(
+        &self,
+        conn: &C,
+        name: &UserTableName,
+        table_id: &UserTableId,
+    ) -> Result<TableAlias, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let alias = build_entity_table_alias(name, table_id);
+
+        let inserted = TableAliases::insert(alias).exec_with_returning(conn).await;
+        match inserted {
+            Ok(model) => build_domain_table_alias(&model),
+            Err(err) => {
+                if matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) {
+                    bail!(TableAliasRepositoryError::AlreadyExists);
+                }
+                Err(err.into())
+            }
+        }
+    }
+
+    #[instrument(skip_all, name = "TableAliasRepository::delete_by_name")]
+    pub async fn delete_by_name<C>(
+        &self,
+        conn: &C,
+        name: &UserTableName,
+    ) -> Result<bool, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let result = TableAliases::delete_many()
+            .filter(Column::Name.eq(name.val()))
+            .exec(conn)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,162 @@
+use crate::domain::model::stream_id::StreamId;
+use crate::domain::model::user_table_id::UserTableId;
+use crate::infrastructure::db::entity::prelude::{
+    ChangeRequestIdempotencyKeys, ChangeRequests, Commits, CurrentFiles, FileColumnStatistics,
+    FileLocks, FileMetadata, Files, Streams,
+};
+use crate::infrastructure::db::entity::{
+    change_request_idempotency_keys, change_requests, commits, current_files,
+    file_column_statistics, file_locks, file_metadata, files, streams,
+};
+use crate::infrastructure::db::repository::garbage_file_repository::GarbageFileRepository;
+use sea_orm::sea_query::{Condition, Query};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use tracing::instrument;
+
+// TableDataRepository is only for other infra repositories. Must not be used from domain.
+pub(super) struct TableDataRepository {
+    garbage_file_repository: GarbageFileRepository,
+}
+
+impl TableDataRepository {
+    pub fn new() -> Self {
+        Self {
+            garbage_file_repository: GarbageFileRepository::new(),
+        }
+    }
+
+    // Deletes the files, commits, change requests and streams of the table, or of the stream when it is given.
+    // Object paths of the files are queued in garbage_files first. Returns the number of queued paths.
+    #[instrument(skip_all, name = "TableDataRepository::delete")]
+    pub async fn delete<C>(
+        &self,
+        conn: &C,
+        table_id: &UserTableId,
+        stream_id: Option<&StreamId>,
+    ) -> Result<u64, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let garbage_count = self
+            .garbage_file_repository
+            .insert_from_files(
+                conn,
+                scope(
+                    files::Column::UserTableId,
+                    files::Column::StreamId,
+                    table_id,
+                    stream_id,
+                ),
+            )
+            .await?;
+
+        let file_ids = Query::select()
+            .column(files::Column::Id)
+            .from(Files)
+            .cond_where(scope(
+                files::Column::UserTableId,
+                files::Column::StreamId,
+                table_id,
+                stream_id,
+            ))
+            .to_owned();
+        let change_request_ids = Query::select()
+            .column(change_requests::Column::Id)
+            .from(ChangeRequests)
+            .cond_where(scope(
+                change_requests::Column::UserTableId,
+                change_requests::Column::StreamId,
+                table_id,
+                stream_id,
+            ))
+            .to_owned();
+
+        FileColumnStatistics::delete_many()
+            .filter(file_column_statistics::Column::FileId.in_subquery(file_ids.clone()))
+            .exec(conn)
+            .await?;
+        FileMetadata::delete_many()
+            .filter(file_metadata::Column::FileId.in_subquery(file_ids))
+            .exec(conn)
+            .await?;
+        CurrentFiles::delete_many()
+            .filter(scope(
+                current_files::Column::UserTableId,
+                current_files::Column::StreamId,
+                table_id,
+                stream_id,
+            ))
+            .exec(conn)
+            .await?;
+        FileLocks::delete_many()
+            .filter(scope(
+                file_locks::Column::UserTableId,
+                file_locks::Column::StreamId,
+                table_id,
+                stream_id,
+            ))
+            .exec(conn)
+            .await?;
+        Files::delete_many()
+            .filter(scope(
+                files::Column::UserTableId,
+                files::Column::StreamId,
+                table_id,
+                stream_id,
+            ))
+            .exec(conn)
+            .await?;
+        Commits::delete_many()
+            .filter(scope(
+                commits::Column::UserTableId,
+                commits::Column::StreamId,
+                table_id,
+                stream_id,
+            ))
+            .exec(conn)
+            .await?;
+        ChangeRequestIdempotencyKeys::delete_many()
+            .filter(
+                change_request_idempotency_keys::Column::ChangeRequestId
+                    .in_subquery(change_request_ids),
+            )
+            .exec(conn)
+            .await?;
+        ChangeRequests::delete_many()
+            .filter(scope(
+                change_requests::Column::UserTableId,
+                change_requests::Column::StreamId,
+                table_id,
+                stream_id,
+            ))
+            .exec(conn)
+            .await?;
+        Streams::delete_many()
+            .filter(scope(
+                streams::Column::UserTableId,
+                streams::Column::StreamId,
+                table_id,
+                stream_id,
+            ))
+            .exec(conn)
+            .await?;
+
+        Ok(garbage_count)
+    }
+}
+
+fn scope<T>(
+    table_column: T,
+    stream_column: T,
+    table_id: &UserTableId,
+    stream_id: Option<&StreamId>,
+) -> Condition
+where
+    T: ColumnTrait,
+{
+    let condition = Condition::all().add(table_column.eq(table_id.val()));
+    match stream_id {
+        Some(stream_id) => condition.add(stream_column.eq(stream_id.val())),
+        None => condition,
+    }
+}
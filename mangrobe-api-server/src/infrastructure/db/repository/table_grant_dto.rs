@@ -0,0 +1,69 @@
+use crate::domain::model::permission::Permission;
+use crate::domain::model::stream_id_range::StreamIdRange;
+use crate::domain::model::table_grant::TableGrant;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::infrastructure::db::entity::table_grants::{ActiveModel, Model};
+use crate::util::error::MangrobeError;
+use anyhow::bail;
+use sea_orm::Set;
+
+pub(super) fn build_entity_table_grant(
+    principal_id: &str,
+    table_name: Option<&UserTableName>,
+    permission: Permission,
+    stream_id_range: &StreamIdRange,
+) -> ActiveModel {
+    ActiveModel {
+        id: Default::default(),
+        principal_id: Set(principal_id.to_string()),
+        table_name: Set(table_name.map(|t| t.val())),
+        permission: Set(build_model_permission(permission)),
+        stream_id_min: Set(stream_id_range.min.as_ref().map(|v| v.val())),
+        stream_id_max: Set(stream_id_range.max.as_ref().map(|v| v.val())),
+        created_at: Default::default(),
+        updated_at: Default::default(),
+    }
+}
+
+pub(super) fn build_domain_table_grant(grant: &Model) -> Result<TableGrant, anyhow::Error> {
+    let table_name = match &grant.table_name {
+        Some(name) => match UserTableName::try_from(name.clone()) {
+            Ok(name) => Some(name),
+            Err(msg) => bail!(msg),
+        },
+        None => None,
+    };
+
+    Ok(TableGrant {
+        id: grant.id.into(),
+        principal_id: grant.principal_id.clone(),
+        table_name,
+        permission: build_domain_permission(grant.permission)?,
+        stream_id_range: StreamIdRange::new(
+            grant.stream_id_min.map(|v| v.into()),
+            grant.stream_id_max.map(|v| v.into()),
+        ),
+    })
+}
+
+fn build_domain_permission(permission: i32) -> Result<Permission, anyhow::Error> {
+    match permission {
+        0 => Ok(Permission::Read),
+        1 => Ok(Permission::Write),
+        2 => Ok(Permission::Compact),
+        3 => Ok(Permission::Admin),
+        _ => bail!(MangrobeError::UnexpectedState(format!(
+            "invalid permission at TableGrant: {}",
+            permission
+        ))),
+    }
+}
+
+fn build_model_permission(permission: Permission) -> i32 {
+    match permission {
+        Permission::Read => 0,
+        Permission::Write => 1,
+        Permission::Compact => 2,
+        Permission::Admin => 3,
+    }
+}
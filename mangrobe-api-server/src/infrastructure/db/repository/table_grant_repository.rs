@@ -0,0 +1,170 @@
+use crate::domain::model::permission::Permission;
+use crate::domain::model::stream_id_range::StreamIdRange;
+use crate::domain::model::table_grant::TableGrant;
+use crate::domain::model::table_grant_id::TableGrantId;
+use crate::domain::model::user_table_name::UserTableName;
+use crate::infrastructure::db::entity::prelude::TableGrants;
+use crate::infrastructure::db::entity::table_grants::Column;
+use crate::infrastructure::db::repository::table_grant_dto::{
+    build_domain_table_grant, build_entity_table_grant,
+};
+use sea_orm::prelude::Expr;
+use sea_orm::{ColumnTrait, Condition, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder};
+use tracing::instrument;
+
+#[derive(Clone, Copy)]
+pub struct TableGrantRepository {}
+
+impl TableGrantRepository {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    // Grants of the principal on the table, including grants on every table.
+    #[instrument(
+        skip_all,
+        name = "TableGrantRepository::find_all_by_principal_and_table"
+    )]
+    pub async fn find_all_by_principal_and_table<C>(
+        &self,
+        conn: &C,
+        principal_id: &str,
+        table_name: &UserTableName,
+    ) -> Result<Vec<TableGrant>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let grants = TableGrants::find()
+            .filter(Column::PrincipalId.eq(principal_id))
+            .filter(
+                Condition::any()
+                    .add(Column::TableName.eq(table_name.val()))
+                    .add(Column::TableName.is_null()),
+            )
+            .all(conn)
+            .await?;
+
+        grants.iter().map(build_domain_table_grant).collect()
+    }
+
+    // Grants on every table (table_name is NULL) of the principal.
+    #[instrument(skip_all, name = "TableGrantRepository::find_all_global_by_principal")]
+    pub async fn find_all_global_by_principal<C>(
+        &self,
+        conn: &C,
+        principal_id: &str,
+    ) -> Result<Vec<TableGrant>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let grants = TableGrants::find()
+            .filter(Column::PrincipalId.eq(principal_id))
+            .filter(Column::TableName.is_null())
+            .all(conn)
+            .await?;
+
+        grants.iter().map(build_domain_table_grant).collect()
+    }
+
+    #[instrument(skip_all, name = "TableGrantRepository::find_all")]
+    pub async fn find_all<C>(
+        &self,
+        conn: &C,
+        principal_id: Option<&str>,
+        table_name: Option<&UserTableName>,
+    ) -> Result<Vec<TableGrant>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let mut query = TableGrants::find();
+        if let Some(principal_id) = principal_id {
+            query = query.filter(Column::PrincipalId.eq(principal_id));
+        }
+        if let Some(table_name) = table_name {
+            query = query.filter(Column::TableName.eq(table_name.val()));
+        }
+
+        let grants = query.order_by_asc(Column::Id).all(conn).await?;
+
+        grants.iter().map(build_domain_table_grant).collect()
+    }
+
+    #[instrument(skip_all, name = "TableGrantRepository::find_by_id")]
+    pub async fn find_by_id<C>(
+        &self,
+        conn: &C,
+        id: &TableGrantId,
+    ) -> Result<Option<TableGrant>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let grant = TableGrants::find_by_id(id.val()).one(conn).await?;
+
+        grant.as_ref().map(build_domain_table_grant).transpose()
+    }
+
+    #[instrument(skip_all, name = "TableGrantRepository::insert")]
+    pub async fn insert<C>(
+        &self,
+        conn: &C,
+        principal_id: &str,
+        table_name: Option<&UserTableName>,
+        permission: Permission,
+        stream_id_range: &StreamIdRange,
+    ) -> Result<TableGrant, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let grant = build_entity_table_grant(principal_id, table_name, permission, stream_id_range);
+        let inserted = TableGrants::insert(grant).exec_with_returning(conn).await?;
+
+        build_domain_table_grant(&inserted)
+    }
+
+    #[instrument(skip_all, name = "TableGrantRepository::delete")]
+    pub async fn delete<C>(&self, conn: &C, id: &TableGrantId) -> Result<bool, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let result = TableGrants::delete_by_id(id.val()).exec(conn).await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    #[instrument(skip_all, name = "TableGrantRepository::delete_by_table_name")]
+    pub async fn delete_by_table_name<C>(
+        &self,
+        conn: &C,
+        table_name: &UserTableName,
+    ) -> Result<u64, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let result = TableGrants::delete_many()
+            .filter(Column::TableName.eq(table_name.val()))
+            .exec(conn)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+
+    // Grants follow the table when it is renamed.
+    #[instrument(skip_all, name = "TableGrantRepository::rename_table")]
+    pub async fn rename_table<C>(
+        &self,
+        conn: &C,
+        table_name: &UserTableName,
+        new_table_name: &UserTableName,
+    ) -> Result<u64, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let result = TableGrants::update_many()
+            .col_expr(Column::TableName, Expr::value(new_table_name.val()))
+            .filter(Column::TableName.eq(table_name.val()))
+            .exec(conn)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+}
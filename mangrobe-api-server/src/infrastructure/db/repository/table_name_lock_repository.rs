@@ -0,0 +1,54 @@
+use crate::domain::model::user_table_name::UserTableName;
+use ahash::RandomState;
+use sea_orm::ConnectionTrait;
+use sea_orm::DatabaseTransaction;
+use sea_orm::{DatabaseBackend, Statement};
+use std::hash::{BuildHasher, Hasher};
+use tracing::instrument;
+
+// Kx values MUST NOT be changed. When they are changed, the number for advisory lock will be changed.
+const K0: u64 = 0;
+const K1: u64 = 1;
+const K2: u64 = 2;
+const K3: u64 = 3;
+
+// The two-key form of advisory locks does not share keys with the one-key form of commit locks.
+// LOCK_CLASS MUST NOT be changed.
+const LOCK_CLASS: i32 = 1;
+
+// Table names and aliases share one namespace in two tables, so a unique index cannot protect it.
+// Claiming a name holds this lock while checking both tables and inserting.
+pub struct TableNameLockRepository {
+    hash_builder: RandomState,
+}
+
+impl TableNameLockRepository {
+    pub fn new() -> Self {
+        let hash_builder = RandomState::with_seeds(K0, K1, K2, K3);
+        Self { hash_builder }
+    }
+
+    // Acquire a lock that will be released automatically when its transaction ends.
+    #[instrument(skip_all, name = "TableNameLockRepository::acquire_xact_lock")]
+    pub async fn acquire_xact_lock(
+        &self,
+        txn: &DatabaseTransaction,
+        name: &UserTableName,
+    ) -> Result<(), anyhow::Error> {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            "SELECT pg_advisory_xact_lock($1, $2)",
+            [LOCK_CLASS.into(), self.to_lock_id(name).into()],
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    fn to_lock_id(&self, name: &UserTableName) -> i32 {
+        let mut hasher = self.hash_builder.build_hasher();
+        hasher.write(name.val().as_bytes());
+
+        hasher.finish() as i32
+    }
+}
@@ -1,13 +1,25 @@
+use crate::domain::model::table_properties::TableProperties;
 use crate::domain::model::user_table::UserTable;
 use crate::domain::model::user_table_name::UserTableName;
 use crate::infrastructure::db::entity::user_tables;
 use anyhow::bail;
+use std::collections::BTreeMap;
 
 pub(super) fn build_domain_user_table(
     table: &user_tables::Model,
 ) -> Result<UserTable, anyhow::Error> {
+    let properties: BTreeMap<String, String> = serde_json::from_value(table.properties.clone())?;
+
     match UserTableName::try_from(table.name.clone()) {
-        Ok(table_name) => Ok(UserTable::new(table.id.into(), table_name)),
+        Ok(table_name) => Ok(UserTable {
+            id: table.id.into(),
+            name: table_name,
+            created_at: table.created_at.into(),
+            dropped_at: table.dropped_at.map(|t| t.into()),
+            purge_after: table.purge_after.map(|t| t.into()),
+            aliases: vec![],
+            properties: TableProperties::new(properties),
+        }),
         Err(msg) => bail!(msg),
     }
 }
@@ -1,14 +1,25 @@
+use crate::domain::model::table_properties::TableProperties;
 use crate::domain::model::user_table::UserTable;
+use crate::domain::model::user_table_id::UserTableId;
 use crate::domain::model::user_table_name::UserTableName;
-use crate::infrastructure::db::entity::prelude::UserTables;
+use crate::infrastructure::db::entity::prelude::{TableAliases, UserTables};
+use crate::infrastructure::db::entity::table_aliases;
 use crate::infrastructure::db::entity::user_tables::{ActiveModel, Column};
+use crate::infrastructure::db::repository::table_data_repository::TableDataRepository;
 use crate::infrastructure::db::repository::user_table_dto::build_domain_user_table;
 use anyhow::bail;
-use sea_orm::{ActiveValue::Set, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, SqlErr};
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::Expr;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, SqlErr, Value,
+};
 use thiserror::Error;
+use tracing::instrument;
 
-#[derive(Clone, Copy)]
-pub struct UserTableRepository {}
+pub struct UserTableRepository {
+    table_data_repository: TableDataRepository,
+}
 
 #[derive(Error, Debug)]
 pub enum UserTableRepositoryError {
@@ -18,9 +29,12 @@ pub enum UserTableRepositoryError {
 
 impl UserTableRepository {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            table_data_repository: TableDataRepository::new(),
+        }
     }
 
+    #[instrument(skip_all, name = "UserTableRepository::find_by_name")]
     pub async fn find_by_name<C>(
         &self,
         conn: &C,
@@ -42,10 +56,48 @@ impl UserTableRepository {
         Ok(Some(table_dto))
     }
 
+    // The table that has the alias.
+    #[instrument(skip_all, name = "UserTableRepository::find_by_alias")]
+    pub async fn find_by_alias<C>(
+        &self,
+        conn: &C,
+        alias: &UserTableName,
+    ) -> Result<Option<UserTable>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let table = UserTables::find()
+            .inner_join(TableAliases)
+            .filter(table_aliases::Column::Name.eq(alias.val()))
+            .one(conn)
+            .await?;
+
+        table.as_ref().map(build_domain_user_table).transpose()
+    }
+
+    #[instrument(skip_all, name = "UserTableRepository::find_all_by_ids")]
+    pub async fn find_all_by_ids<C>(
+        &self,
+        conn: &C,
+        ids: &[UserTableId],
+    ) -> Result<Vec<UserTable>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let tables = UserTables::find()
+            .filter(Column::Id.is_in(ids.iter().map(|id| id.val())))
+            .all(conn)
+            .await?;
+
+        tables.iter().map(build_domain_user_table).collect()
+    }
+
+    #[instrument(skip_all, name = "UserTableRepository::insert")]
     pub async fn insert<C>(
         &self,
         conn: &C,
         name: &UserTableName,
+        properties: &TableProperties,
     ) -> Result<UserTable, anyhow::Error>
     where
         C: ConnectionTrait,
@@ -55,11 +107,41 @@ impl UserTableRepository {
             name: Set(name.val()),
             created_at: Default::default(),
             updated_at: Default::default(),
+            dropped_at: Default::default(),
+            purge_after: Default::default(),
+            properties: Set(serde_json::to_value(&properties.values)?),
         };
 
         let inserted = UserTables::insert(table).exec_with_returning(conn).await;
         match inserted {
-            Ok(model) => Ok(UserTable::new(model.id.into(), name.clone())),
+            Ok(model) => build_domain_user_table(&model),
+            Err(err) => {
+                if self.is_unique_constraint_violation(&err) {
+                    bail!(UserTableRepositoryError::AlreadyExists);
+                }
+                Err(err.into())
+            }
+        }
+    }
+
+    // Returns None when the table does not exist.
+    #[instrument(skip_all, name = "UserTableRepository::rename")]
+    pub async fn rename<C>(
+        &self,
+        conn: &C,
+        id: &UserTableId,
+        name: &UserTableName,
+    ) -> Result<Option<UserTable>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let updated = UserTables::update_many()
+            .col_expr(Column::Name, Expr::value(name.val()))
+            .filter(Column::Id.eq(id.val()))
+            .exec_with_returning(conn)
+            .await;
+        match updated {
+            Ok(tables) => tables.first().map(build_domain_user_table).transpose(),
             Err(err) => {
                 if self.is_unique_constraint_violation(&err) {
                     bail!(UserTableRepositoryError::AlreadyExists);
@@ -69,6 +151,169 @@ impl UserTableRepository {
         }
     }
 
+    // Locks the row until the transaction ends.
+    #[instrument(skip_all, name = "UserTableRepository::find_by_id_for_update")]
+    pub async fn find_by_id_for_update<C>(
+        &self,
+        conn: &C,
+        id: &UserTableId,
+    ) -> Result<Option<UserTable>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let table = UserTables::find_by_id(id.val())
+            .lock_exclusive()
+            .one(conn)
+            .await?;
+
+        table.as_ref().map(build_domain_user_table).transpose()
+    }
+
+    #[instrument(skip_all, name = "UserTableRepository::update_properties")]
+    pub async fn update_properties<C>(
+        &self,
+        conn: &C,
+        id: &UserTableId,
+        properties: &TableProperties,
+    ) -> Result<Option<UserTable>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let tables = UserTables::update_many()
+            .col_expr(
+                Column::Properties,
+                Expr::value(serde_json::to_value(&properties.values)?),
+            )
+            .filter(Column::Id.eq(id.val()))
+            .exec_with_returning(conn)
+            .await?;
+
+        tables.first().map(build_domain_user_table).transpose()
+    }
+
+    // Tables ordered by name. `names` None means every table.
+    #[instrument(skip_all, name = "UserTableRepository::find_all")]
+    pub async fn find_all<C>(
+        &self,
+        conn: &C,
+        names: Option<&[UserTableName]>,
+        name_after: Option<&UserTableName>,
+        include_dropped: bool,
+        limit: u64,
+    ) -> Result<Vec<UserTable>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let mut query = UserTables::find();
+        if let Some(names) = names {
+            query = query.filter(Column::Name.is_in(names.iter().map(|n| n.val())));
+        }
+        if let Some(name_after) = name_after {
+            query = query.filter(Column::Name.gt(name_after.val()));
+        }
+        if !include_dropped {
+            query = query.filter(Column::DroppedAt.is_null());
+        }
+
+        let tables = query
+            .order_by_asc(Column::Name)
+            .limit(limit)
+            .all(conn)
+            .await?;
+
+        tables.iter().map(build_domain_user_table).collect()
+    }
+
+    // Returns None when the table is already dropped.
+    #[instrument(skip_all, name = "UserTableRepository::mark_dropped")]
+    pub async fn mark_dropped<C>(
+        &self,
+        conn: &C,
+        id: &UserTableId,
+        purge_after: DateTime<Utc>,
+    ) -> Result<Option<UserTable>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let tables = UserTables::update_many()
+            .col_expr(Column::DroppedAt, Expr::current_timestamp().into())
+            .col_expr(Column::PurgeAfter, Expr::value(purge_after))
+            .filter(Column::Id.eq(id.val()))
+            .filter(Column::DroppedAt.is_null())
+            .exec_with_returning(conn)
+            .await?;
+
+        tables.first().map(build_domain_user_table).transpose()
+    }
+
+    // Returns None when the table is not dropped or its grace period has passed.
+    #[instrument(skip_all, name = "UserTableRepository::restore")]
+    pub async fn restore<C>(
+        &self,
+        conn: &C,
+        id: &UserTableId,
+    ) -> Result<Option<UserTable>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let tables = UserTables::update_many()
+            .col_expr(
+                Column::DroppedAt,
+                Expr::value(Value::ChronoDateTimeUtc(None)),
+            )
+            .col_expr(
+                Column::PurgeAfter,
+                Expr::value(Value::ChronoDateTimeUtc(None)),
+            )
+            .filter(Column::Id.eq(id.val()))
+            .filter(Column::DroppedAt.is_not_null())
+            .filter(Column::PurgeAfter.gt(Utc::now()))
+            .exec_with_returning(conn)
+            .await?;
+
+        tables.first().map(build_domain_user_table).transpose()
+    }
+
+    #[instrument(skip_all, name = "UserTableRepository::find_purgeable")]
+    pub async fn find_purgeable<C>(
+        &self,
+        conn: &C,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<UserTable>, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let tables = UserTables::find()
+            .filter(Column::DroppedAt.is_not_null())
+            .filter(Column::PurgeAfter.lte(now))
+            .order_by_asc(Column::PurgeAfter)
+            .all(conn)
+            .await?;
+
+        tables.iter().map(build_domain_user_table).collect()
+    }
+
+    // Deletes the table and every row that refers to it. Audit logs are kept.
+    // Object paths of the files are queued in garbage_files. Returns the number of queued paths.
+    #[instrument(skip_all, name = "UserTableRepository::delete_with_data")]
+    pub async fn delete_with_data<C>(
+        &self,
+        conn: &C,
+        id: &UserTableId,
+    ) -> Result<u64, anyhow::Error>
+    where
+        C: ConnectionTrait,
+    {
+        let garbage_count = self.table_data_repository.delete(conn, id, None).await?;
+        TableAliases::delete_many()
+            .filter(table_aliases::Column::UserTableId.eq(id.val()))
+            .exec(conn)
+            .await?;
+        UserTables::delete_by_id(id.val()).exec(conn).await?;
+
+        Ok(garbage_count)
+    }
+
     fn is_unique_constraint_violation(&self, err: &sea_orm::DbErr) -> bool {
         matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_)))
     }
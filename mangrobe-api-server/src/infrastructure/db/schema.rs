@@ -0,0 +1,32 @@
+use crate::util::error::MangrobeError;
+use migration::{Migrator, MigratorTrait};
+use sea_orm::DatabaseConnection;
+use tracing::info;
+
+/// Makes sure the database schema matches the migrations bundled in this binary.
+/// Pending migrations are applied when `apply` is true, otherwise startup is refused.
+pub async fn ensure_schema(db: &DatabaseConnection, apply: bool) -> Result<(), anyhow::Error> {
+    let pending = find_pending_migrations(db).await?;
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let names = pending.join(", ");
+    if !apply {
+        return Err(MangrobeError::SchemaOutdated(names).into());
+    }
+
+    info!("Applying migrations: {}", names);
+    Migrator::up(db, None).await?;
+
+    Ok(())
+}
+
+/// Fails when the database has migrations unknown to this binary, e.g. applied by a newer version.
+pub async fn find_pending_migrations(
+    db: &DatabaseConnection,
+) -> Result<Vec<String>, anyhow::Error> {
+    let pending = Migrator::get_pending_migrations(db).await?;
+
+    Ok(pending.iter().map(|m| m.name().to_string()).collect())
+}
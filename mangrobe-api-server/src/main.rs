@@ -1,67 +1,150 @@
+use crate::application::data_definition::table_purger::TablePurger;
+use crate::config::app_config::{AppConfig, TlsConfig};
+use crate::config::cli_args::CliArgs;
+use crate::grpc::access_control::access_control_service::AccessControlService;
+use crate::grpc::auth::auth_interceptor::AuthInterceptor;
 use crate::grpc::data_definition::data_definition_service::DataDefinitionService;
 use crate::grpc::data_manipulation::data_manipulation_service::DataManipulationService;
+use crate::grpc::health_checker::HealthChecker;
 use crate::grpc::information_schema::information_schema_service::InformationSchemaService;
 use crate::grpc::lock_control::lock_control_service::LockControlService;
 use crate::grpc::proto::FILE_DESCRIPTOR_SET2;
+use crate::grpc::proto::access_control_service_server::AccessControlServiceServer;
 use crate::grpc::proto::data_definition_service_server::DataDefinitionServiceServer;
 use crate::grpc::proto::data_manipulation_service_server::DataManipulationServiceServer;
 use crate::grpc::proto::information_schema_service_server::InformationSchemaServiceServer;
 use crate::grpc::proto::lock_control_service_server::LockControlServiceServer;
+use crate::grpc::rpc_metrics_layer::RpcMetricsLayer;
+use crate::grpc::rpc_tracing_layer::RpcTracingLayer;
+use crate::http::metrics_server::run_metrics_server;
 use crate::infrastructure::db::connection::connect;
+use crate::infrastructure::db::schema::ensure_schema;
+use crate::util::telemetry::init_tracing;
+use clap::Parser;
 use sea_orm::DatabaseConnection;
-use std::convert::Into;
-use std::env;
-use std::net::SocketAddr;
-use tonic::transport::Server;
+use std::fs;
+use std::sync::Arc;
+use tokio::signal::unix::{SignalKind, signal};
+use tokio::sync::oneshot;
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::{Certificate, Identity, Server, ServerTlsConfig};
+use tonic_health::ServingStatus;
+use tonic_health::server::health_reporter;
 use tonic_reflection::server::Builder;
-use tracing_subscriber::EnvFilter;
+use tracing::{error, info, warn};
 
 mod application;
+mod config;
 mod domain;
 mod grpc;
+mod http;
 mod infrastructure;
 mod util;
 
-const DEFAULT_MANGROBE_API_ADDR: &str = "[::1]:50051";
-
 #[tokio::main]
 async fn main() -> Result<(), anyhow::Error> {
-    tracing_subscriber::fmt()
-        .with_max_level(tracing::Level::DEBUG)
-        .with_env_filter(EnvFilter::new("info,sea_orm=debug"))
-        .with_test_writer()
-        .init();
+    let config = CliArgs::parse().load_config()?;
+
+    let tracer_provider = init_tracing(&config.log, &config.telemetry)?;
 
-    // TODO: omit
-    let db = connect("postgres://postgres:@127.0.0.1:5432/mangrobe-development".into()).await?;
+    let db = connect(&config.database).await?;
+    ensure_schema(&db, config.database.migrate).await?;
 
-    let addr = env::var("MANGROBE_API_ADDR")
-        .unwrap_or(DEFAULT_MANGROBE_API_ADDR.into())
-        .parse()?;
+    if let Some(addr) = config.metrics.addr {
+        let db = db.clone();
+        let metrics_config = config.metrics.clone();
+        tokio::spawn(async move {
+            if let Err(e) = run_metrics_server(addr, &metrics_config, &db).await {
+                error!(error = ?e, "metrics server stopped");
+            }
+        });
+    }
 
-    run_api_server(addr, &db).await?;
+    run_api_server(&config, &db).await?;
 
     db.close().await?;
+    if let Some(tracer_provider) = tracer_provider {
+        tracer_provider.shutdown()?;
+    }
 
     Ok(())
 }
 
-async fn run_api_server(addr: SocketAddr, db: &DatabaseConnection) -> Result<(), anyhow::Error> {
+async fn run_api_server(config: &AppConfig, db: &DatabaseConnection) -> Result<(), anyhow::Error> {
+    let addr = config.server.addr;
+    let limits = &config.limits;
     println!("Starting Mangrobe API Server at {}...", addr);
 
-    let data_manipulation_service = DataManipulationService::new(db);
-    let data_definition_service = DataDefinitionService::new(db);
+    let data_manipulation_service = DataManipulationService::new(db, config.quotas.to_policy()?);
+    let data_definition_service = DataDefinitionService::new(db, config.tables.drop_grace_period());
     let lock_control_service = LockControlService::new(db);
     let information_schema_service = InformationSchemaService::new(db);
+    let access_control_service = AccessControlService::new(db);
+
+    let table_purger = TablePurger::new(db);
+    let table_purge_task = tokio::spawn({
+        let interval = config.tables.purge_interval();
+        async move { table_purger.run(interval).await }
+    });
+
+    // Health is checked without authentication, so that probes need no credentials.
+    let (health_reporter, health_service) = health_reporter();
+    let health_checker = Arc::new(HealthChecker::new(health_reporter, db));
+    let health_check_task = tokio::spawn({
+        let health_checker = health_checker.clone();
+        let interval = config.server.health_check_interval();
+        async move { health_checker.run(interval).await }
+    });
+
+    let auth_interceptor = AuthInterceptor::new(config)?;
+    if !auth_interceptor.is_enabled() {
+        warn!("Authentication is disabled. Every request is accepted as anonymous");
+    }
 
-    Server::builder()
-        .add_service(DataManipulationServiceServer::new(
-            data_manipulation_service,
+    let mut builder = Server::builder();
+    if let Some(tls) = &config.server.tls {
+        builder = builder.tls_config(build_tls_config(tls)?)?;
+    }
+    if let Some(timeout) = limits.request_timeout() {
+        builder = builder.timeout(timeout);
+    }
+    if let Some(limit) = limits.concurrency_limit_per_connection {
+        builder = builder.concurrency_limit_per_connection(limit);
+    }
+
+    let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+    let server = builder
+        .layer(RpcTracingLayer)
+        .layer(RpcMetricsLayer)
+        .add_service(InterceptedService::new(
+            DataManipulationServiceServer::new(data_manipulation_service)
+                .max_decoding_message_size(limits.max_decoding_message_size)
+                .max_encoding_message_size(limits.max_encoding_message_size),
+            auth_interceptor.clone(),
+        ))
+        .add_service(InterceptedService::new(
+            DataDefinitionServiceServer::new(data_definition_service)
+                .max_decoding_message_size(limits.max_decoding_message_size)
+                .max_encoding_message_size(limits.max_encoding_message_size),
+            auth_interceptor.clone(),
+        ))
+        .add_service(InterceptedService::new(
+            LockControlServiceServer::new(lock_control_service)
+                .max_decoding_message_size(limits.max_decoding_message_size)
+                .max_encoding_message_size(limits.max_encoding_message_size),
+            auth_interceptor.clone(),
         ))
-        .add_service(DataDefinitionServiceServer::new(data_definition_service))
-        .add_service(LockControlServiceServer::new(lock_control_service))
-        .add_service(InformationSchemaServiceServer::new(
-            information_schema_service,
+        .add_service(InterceptedService::new(
+            InformationSchemaServiceServer::new(information_schema_service)
+                .max_decoding_message_size(limits.max_decoding_message_size)
+                .max_encoding_message_size(limits.max_encoding_message_size),
+            auth_interceptor.clone(),
+        ))
+        .add_service(InterceptedService::new(
+            AccessControlServiceServer::new(access_control_service)
+                .max_decoding_message_size(limits.max_decoding_message_size)
+                .max_encoding_message_size(limits.max_encoding_message_size),
+            auth_interceptor.clone(),
         ))
         .add_service(
             Builder::configure()
@@ -73,8 +156,56 @@ async fn run_api_server(addr: SocketAddr, db: &DatabaseConnection) -> Result<(),
                 .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET2)
                 .build_v1()?,
         )
-        .serve(addr)
-        .await?;
+        .add_service(health_service)
+        .serve_with_shutdown(addr, async {
+            let _ = shutdown_rx.await;
+        });
+    tokio::pin!(server);
+
+    tokio::select! {
+        result = &mut server => {
+            health_check_task.abort();
+            table_purge_task.abort();
+            return Ok(result?);
+        }
+        result = wait_for_shutdown_signal() => result?,
+    }
+
+    // Stops accepting requests and waits for in-flight ones, such as commits, to finish.
+    info!("Shutting down Mangrobe API Server...");
+    health_check_task.abort();
+    table_purge_task.abort();
+    health_checker.set_status(ServingStatus::NotServing).await;
+    let _ = shutdown_tx.send(());
+    match tokio::time::timeout(config.server.shutdown_timeout(), server).await {
+        Ok(result) => result?,
+        Err(_) => {
+            warn!("Timed out waiting for in-flight requests. Their transactions are rolled back")
+        }
+    }
+
+    Ok(())
+}
+
+async fn wait_for_shutdown_signal() -> Result<(), anyhow::Error> {
+    let mut sigterm = signal(SignalKind::terminate())?;
+    tokio::select! {
+        _ = sigterm.recv() => {}
+        result = tokio::signal::ctrl_c() => result?,
+    }
 
     Ok(())
 }
+
+fn build_tls_config(tls: &TlsConfig) -> Result<ServerTlsConfig, anyhow::Error> {
+    let cert = fs::read(&tls.cert_path)?;
+    let key = fs::read(&tls.key_path)?;
+
+    let mut tls_config = ServerTlsConfig::new().identity(Identity::from_pem(cert, key));
+    if let Some(client_ca_path) = &tls.client_ca_path {
+        let client_ca = fs::read(client_ca_path)?;
+        tls_config = tls_config.client_ca_root(Certificate::from_pem(client_ca));
+    }
+
+    Ok(tls_config)
+}
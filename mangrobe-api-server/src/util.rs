@@ -1 +1,4 @@
 pub mod error;
+pub mod log_format;
+pub mod metrics;
+pub mod telemetry;
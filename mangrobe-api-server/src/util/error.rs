@@ -8,6 +8,11 @@ pub enum MangrobeError {
 
     #[error("Unexpected state change found. from {0} to {1}")]
     UnexpectedStateChange(String, String),
+
+    #[error(
+        "Database schema is outdated. pending migrations=[{0}]. Restart with --migrate to apply them"
+    )]
+    SchemaOutdated(String),
 }
 
 #[derive(Error, Debug)]
@@ -19,14 +24,122 @@ pub enum ParameterError {
     Invalid(String, String),
 }
 
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Failed to load config. path='{0}', message='{1}'")]
+    Load(String, String),
+
+    #[error("Invalid config. key='{0}', message='{1}'")]
+    Invalid(String, String),
+}
+
+#[derive(Error, Debug)]
+pub enum AuthError {
+    #[error("Unauthenticated. {0}")]
+    Unauthenticated(String),
+
+    #[error("Permission denied. {0}")]
+    PermissionDenied(String),
+}
+
+impl AuthError {
+    // Reason codes are part of the API. They MUST NOT be changed.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            AuthError::Unauthenticated(_) => "UNAUTHENTICATED",
+            AuthError::PermissionDenied(_) => "PERMISSION_DENIED",
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum QuotaError {
+    #[error("Commit rate exceeded. {0}")]
+    CommitRate(String),
+
+    #[error("Too many files in a commit. {0}")]
+    FilesPerCommit(String),
+
+    #[error("Too many current files. {0}")]
+    CurrentFiles(String),
+
+    #[error("Too many current bytes. {0}")]
+    CurrentBytes(String),
+}
+
+impl QuotaError {
+    // Reason codes are part of the API. They MUST NOT be changed.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            QuotaError::CommitRate(_) => "QUOTA_COMMIT_RATE_EXCEEDED",
+            QuotaError::FilesPerCommit(_) => "QUOTA_FILES_PER_COMMIT_EXCEEDED",
+            QuotaError::CurrentFiles(_) => "QUOTA_CURRENT_FILES_EXCEEDED",
+            QuotaError::CurrentBytes(_) => "QUOTA_CURRENT_BYTES_EXCEEDED",
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum UserError {
     #[error("Invalid parameter. {0}")]
     InvalidParameterMessage(String),
 
-    #[error("Invalid lock. {0}")]
-    InvalidLockMessage(String),
-
     #[error("Already exists. {0}")]
     AlreadyExistsMessage(String),
+
+    #[error("Not found. {0}")]
+    NotFoundMessage(String),
+
+    #[error("Failed precondition. {0}")]
+    FailedPrecondition(String),
+}
+
+#[derive(Error, Debug)]
+pub enum LockError {
+    #[error("Lock conflicted. {0}")]
+    HeldByOther(String),
+
+    #[error("Lock key duplicated. {0}")]
+    DuplicatedKey(String),
+
+    #[error("Lock not acquired. {0}")]
+    NotAcquired(String),
+
+    #[error("File not locked. {0}")]
+    FileNotLocked(String),
+
+    #[error("File not found. {0}")]
+    FileNotFound(String),
+}
+
+impl LockError {
+    // Reason codes are part of the API. They MUST NOT be changed.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            LockError::HeldByOther(_) => "LOCK_HELD_BY_OTHER",
+            LockError::DuplicatedKey(_) => "LOCK_KEY_DUPLICATED",
+            LockError::NotAcquired(_) => "LOCK_NOT_ACQUIRED",
+            LockError::FileNotLocked(_) => "FILE_NOT_LOCKED",
+            LockError::FileNotFound(_) => "FILE_NOT_FOUND",
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum CommitError {
+    #[error("Commit conflicted. {0}")]
+    HeadMoved(String),
+
+    #[error("Stream sealed. {0}")]
+    StreamSealed(String),
+}
+
+impl CommitError {
+    // Reason codes are part of the API. They MUST NOT be changed.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            CommitError::HeadMoved(_) => "COMMIT_HEAD_MOVED",
+            CommitError::StreamSealed(_) => "STREAM_SEALED",
+        }
+    }
 }
@@ -0,0 +1,231 @@
+use chrono::{SecondsFormat, Utc};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::fmt;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields, FormattedFields};
+use tracing_subscriber::registry::LookupSpan;
+
+// Cuts values longer than the limit, such as SQL statements with parquet metadata bound to them.
+fn truncate(value: &str, max_length: usize) -> Cow<'_, str> {
+    if value.len() <= max_length {
+        return Cow::Borrowed(value);
+    }
+    let mut end = max_length;
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    Cow::Owned(format!(
+        "{}...[{} bytes redacted]",
+        &value[..end],
+        value.len() - end
+    ))
+}
+
+// Fields added by tracing-log are already reflected in the normalized metadata.
+fn is_log_field(field: &Field) -> bool {
+    field.name().starts_with("log.")
+}
+
+// Formats fields as `key=value` for the text and pretty formats.
+pub struct TextFields {
+    max_field_length: usize,
+}
+
+impl TextFields {
+    pub fn new(max_field_length: usize) -> Self {
+        Self { max_field_length }
+    }
+}
+
+impl<'writer> FormatFields<'writer> for TextFields {
+    fn format_fields<R: RecordFields>(&self, writer: Writer<'writer>, fields: R) -> fmt::Result {
+        let mut visitor = TextVisitor {
+            writer,
+            max_field_length: self.max_field_length,
+            is_empty: true,
+            result: Ok(()),
+        };
+        fields.record(&mut visitor);
+        visitor.result
+    }
+}
+
+struct TextVisitor<'writer> {
+    writer: Writer<'writer>,
+    max_field_length: usize,
+    is_empty: bool,
+    result: fmt::Result,
+}
+
+impl TextVisitor<'_> {
+    fn write(&mut self, field: &Field, value: &str) {
+        if self.result.is_err() || is_log_field(field) {
+            return;
+        }
+        let value = truncate(value, self.max_field_length);
+        let delimiter = if self.is_empty { "" } else { " " };
+        self.is_empty = false;
+        self.result = if field.name() == "message" {
+            write!(self.writer, "{}{}", delimiter, value)
+        } else {
+            write!(self.writer, "{}{}={}", delimiter, field.name(), value)
+        };
+    }
+}
+
+impl Visit for TextVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.write(field, value);
+        } else {
+            self.write(field, &format!("{:?}", value));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.write(field, &format!("{:?}", value));
+    }
+}
+
+// Formats span fields as a JSON object, which `JsonFormat` merges into each log line.
+pub struct JsonFields {
+    max_field_length: usize,
+}
+
+impl JsonFields {
+    pub fn new(max_field_length: usize) -> Self {
+        Self { max_field_length }
+    }
+}
+
+impl<'writer> FormatFields<'writer> for JsonFields {
+    fn format_fields<R: RecordFields>(
+        &self,
+        mut writer: Writer<'writer>,
+        fields: R,
+    ) -> fmt::Result {
+        let mut visitor = JsonVisitor::new(self.max_field_length);
+        fields.record(&mut visitor);
+        write!(writer, "{}", Value::Object(visitor.fields))
+    }
+
+    fn add_fields(
+        &self,
+        current: &'writer mut FormattedFields<Self>,
+        fields: &tracing::span::Record<'_>,
+    ) -> fmt::Result {
+        let mut visitor = JsonVisitor::new(self.max_field_length);
+        if let Ok(Value::Object(existing)) = serde_json::from_str(&current.fields) {
+            visitor.fields = existing;
+        }
+        fields.record(&mut visitor);
+        current.fields = Value::Object(visitor.fields).to_string();
+        Ok(())
+    }
+}
+
+struct JsonVisitor {
+    fields: Map<String, Value>,
+    max_field_length: usize,
+}
+
+impl JsonVisitor {
+    fn new(max_field_length: usize) -> Self {
+        Self {
+            fields: Map::new(),
+            max_field_length,
+        }
+    }
+
+    fn insert(&mut self, field: &Field, value: Value) {
+        if !is_log_field(field) {
+            self.fields.insert(field.name().to_string(), value);
+        }
+    }
+}
+
+impl Visit for JsonVisitor {
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, value.into());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        let value = truncate(value, self.max_field_length).into_owned();
+        self.insert(field, value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let value = format!("{:?}", value);
+        self.record_str(field, &value);
+    }
+}
+
+// Writes one JSON object per line with the fields of the event and its spans (e.g. request_id).
+pub struct JsonFormat {
+    max_field_length: usize,
+}
+
+impl JsonFormat {
+    pub fn new(max_field_length: usize) -> Self {
+        Self { max_field_length }
+    }
+}
+
+impl<S> FormatEvent<S, JsonFields> for JsonFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, JsonFields>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let normalized = event.normalized_metadata();
+        let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+
+        let mut line = Map::new();
+        line.insert(
+            "timestamp".into(),
+            Utc::now()
+                .to_rfc3339_opts(SecondsFormat::Micros, true)
+                .into(),
+        );
+        line.insert("level".into(), metadata.level().as_str().into());
+        line.insert("target".into(), metadata.target().into());
+
+        // Inner spans take precedence over outer ones, and the event over its spans.
+        if let Some(scope) = ctx.event_scope() {
+            let mut spans = Vec::new();
+            for span in scope.from_root() {
+                spans.push(Value::from(span.name()));
+                let extensions = span.extensions();
+                if let Some(fields) = extensions.get::<FormattedFields<JsonFields>>()
+                    && let Ok(Value::Object(fields)) = serde_json::from_str(&fields.fields)
+                {
+                    line.extend(fields);
+                }
+            }
+            line.insert("spans".into(), spans.into());
+        }
+
+        let mut visitor = JsonVisitor::new(self.max_field_length);
+        event.record(&mut visitor);
+        line.extend(visitor.fields);
+
+        writeln!(writer, "{}", Value::Object(line))
+    }
+}
@@ -0,0 +1,105 @@
+use prometheus::{
+    Encoder, Histogram, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, TextEncoder,
+    register_histogram, register_histogram_vec, register_int_counter_vec, register_int_gauge,
+    register_int_gauge_vec,
+};
+use std::sync::LazyLock;
+
+pub static RPC_DURATION_SECONDS: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "mangrobe_rpc_duration_seconds",
+        "Latency of gRPC requests.",
+        &["service", "method"]
+    )
+    .unwrap()
+});
+
+pub static RPC_REQUESTS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "mangrobe_rpc_requests_total",
+        "Number of gRPC requests by status code.",
+        &["service", "method", "code"]
+    )
+    .unwrap()
+});
+
+pub static COMMITS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "mangrobe_commits_total",
+        "Number of successful commits.",
+        &["table_name", "operation"]
+    )
+    .unwrap()
+});
+
+pub static COMMIT_LOCK_WAIT_SECONDS: LazyLock<Histogram> = LazyLock::new(|| {
+    register_histogram!(
+        "mangrobe_commit_lock_wait_seconds",
+        "Time spent waiting for the advisory lock of a stream before committing."
+    )
+    .unwrap()
+});
+
+pub static FILE_LOCK_ACQUISITIONS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "mangrobe_file_lock_acquisitions_total",
+        "Number of file lock acquisitions by result (acquired, conflicted or failed).",
+        &["table_name", "result"]
+    )
+    .unwrap()
+});
+
+pub static QUOTA_REJECTED_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "mangrobe_quota_rejected_total",
+        "Number of requests rejected by quotas.",
+        &["table_name", "quota"]
+    )
+    .unwrap()
+});
+
+// Gauges below are refreshed periodically.
+
+pub static CURRENT_FILES: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    register_int_gauge_vec!(
+        "mangrobe_current_files",
+        "Number of current files of the tables with the most files.",
+        &["table_name"]
+    )
+    .unwrap()
+});
+
+pub static CURRENT_FILES_OTHER_TABLES: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!(
+        "mangrobe_current_files_other_tables",
+        "Number of current files of the tables without their own table_name label."
+    )
+    .unwrap()
+});
+
+// Gauges below are refreshed when scraped.
+
+pub static DB_POOL_CONNECTIONS: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    register_int_gauge_vec!(
+        "mangrobe_db_pool_connections",
+        "Number of database connections by state (idle or in_use).",
+        &["state"]
+    )
+    .unwrap()
+});
+
+pub static DB_POOL_MAX_CONNECTIONS: LazyLock<IntGauge> = LazyLock::new(|| {
+    register_int_gauge!(
+        "mangrobe_db_pool_max_connections",
+        "Maximum number of database connections."
+    )
+    .unwrap()
+});
+
+// Encodes every registered metric in the Prometheus text format.
+pub fn encode_metrics() -> Result<String, anyhow::Error> {
+    let mut buffer = vec![];
+    TextEncoder::new().encode(&prometheus::gather(), &mut buffer)?;
+
+    Ok(String::from_utf8(buffer)?)
+}
@@ -0,0 +1,81 @@
+use crate::config::app_config::{LogConfig, LogFormat, TelemetryConfig};
+use crate::util::log_format::{JsonFields, JsonFormat, TextFields};
+use opentelemetry::global;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+use tracing_subscriber::layer::{Layered, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer, Registry};
+
+// Installs the global subscriber. The returned provider must be shut down to flush pending spans.
+pub fn init_tracing(
+    log: &LogConfig,
+    telemetry: &TelemetryConfig,
+) -> Result<Option<SdkTracerProvider>, anyhow::Error> {
+    // W3C trace context is extracted from incoming requests even when traces are not exported,
+    // so that trace ids in logs still match the caller's.
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let provider = match &telemetry.otlp_endpoint {
+        Some(endpoint) => Some(build_tracer_provider(endpoint, telemetry)?),
+        None => None,
+    };
+    let otel_layer = provider.as_ref().map(|provider| {
+        tracing_opentelemetry::layer().with_tracer(provider.tracer(telemetry.service_name.clone()))
+    });
+
+    tracing_subscriber::registry()
+        .with(EnvFilter::new(&log.filter))
+        .with(build_fmt_layer(log))
+        .with(otel_layer)
+        .init();
+
+    Ok(provider)
+}
+
+type FmtLayer = Box<dyn Layer<Layered<EnvFilter, Registry>> + Send + Sync>;
+
+fn build_fmt_layer(log: &LogConfig) -> FmtLayer {
+    let layer = tracing_subscriber::fmt::layer();
+    match log.format {
+        LogFormat::Text => layer
+            .fmt_fields(TextFields::new(log.max_field_length))
+            .boxed(),
+        LogFormat::Pretty => layer
+            .pretty()
+            .fmt_fields(TextFields::new(log.max_field_length))
+            .boxed(),
+        LogFormat::Json => layer
+            .fmt_fields(JsonFields::new(log.max_field_length))
+            .event_format(JsonFormat::new(log.max_field_length))
+            .boxed(),
+    }
+}
+
+fn build_tracer_provider(
+    endpoint: &str,
+    telemetry: &TelemetryConfig,
+) -> Result<SdkTracerProvider, anyhow::Error> {
+    let exporter = SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()?;
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            telemetry.sample_ratio,
+        ))))
+        .with_resource(
+            Resource::builder()
+                .with_service_name(telemetry.service_name.clone())
+                .build(),
+        )
+        .build();
+    global::set_tracer_provider(provider.clone());
+
+    Ok(provider)
+}
@@ -63,6 +63,7 @@ pub async fn add_files(
                 size: 1,
                 column_statistics: vec![],
                 file_metadata: None,
+                partition_values: Default::default(),
             })
             .collect(),
     }];
@@ -71,6 +72,7 @@ pub async fn add_files(
             stream.table_name.clone(),
             stream.stream_id,
             file_add_entries,
+            None,
         )
         .await?;
 
@@ -97,6 +99,7 @@ pub async fn compact_files(
                 .iter()
                 .map(|f| CompactFileSrcEntry {
                     path: f.to_string(),
+                    partition_time: None,
                 })
                 .collect(),
             dst_entry: Some(CompactFileDstEntry {
@@ -104,7 +107,10 @@ pub async fn compact_files(
                 size: 123,
                 column_statistics: vec![],
                 file_metadata: None,
+                partition_values: Default::default(),
+                partition_time: None,
             }),
+            dst_entries: vec![],
         }],
     }];
 
@@ -114,6 +120,7 @@ pub async fn compact_files(
             stream.table_name.clone(),
             stream.stream_id,
             compact_file_entries,
+            None,
         )
         .await?;
 
@@ -148,6 +155,7 @@ pub async fn change_files(
             stream.table_name.clone(),
             stream.stream_id,
             change_file_entries,
+            None,
         )
         .await?;
 
@@ -182,6 +190,8 @@ pub async fn lock(
             stream.table_name.clone(),
             stream.stream_id,
             acquire_file_lock_entries,
+            "api-demo".to_string(),
+            "demonstrate file locks".to_string(),
         )
         .await?;
 
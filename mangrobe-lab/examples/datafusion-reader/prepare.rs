@@ -68,6 +68,7 @@ pub async fn register_files(
             size: size as i64,
             column_statistics: vec![],
             file_metadata: None,
+            partition_values: Default::default(),
         })
     }
 
@@ -76,6 +77,7 @@ pub async fn register_files(
             stream.table_name.clone(),
             stream.stream_id,
             vec![add_file_entry],
+            None,
         )
         .await?;
 
@@ -65,6 +65,7 @@ impl Handler {
                 size: buffer_len as i64,
                 column_statistics: vec![],
                 file_metadata: None,
+                partition_values: Default::default(),
             }],
         };
 
@@ -73,6 +74,7 @@ impl Handler {
                 PROM_TABLE_NAME.to_string(),
                 PROM_STREAM_ID,
                 vec![add_file_entry],
+                None,
             )
             .await?;
 
@@ -0,0 +1,29 @@
+use mangrobe_lab::{ApiClient, Compactor, CompactorConfig};
+use std::env;
+use std::path::PathBuf;
+use tonic::transport::Endpoint;
+
+const DEFAULT_CONFIG_PATH: &str = "compactor.toml";
+
+#[tokio::main]
+async fn main() -> Result<(), anyhow::Error> {
+    let config_path = env::args()
+        .nth(1)
+        .or_else(|| env::var("MANGROBE_COMPACTOR_CONFIG").ok())
+        .unwrap_or(DEFAULT_CONFIG_PATH.into());
+    run(config_path.into()).await
+}
+
+async fn run(config_path: PathBuf) -> Result<(), anyhow::Error> {
+    let config = CompactorConfig::load(&config_path)?;
+    println!("Running compactor with {}...", config_path.display());
+
+    let conn = Endpoint::new(config.api_addr.clone())?.connect().await?;
+    let mut api_client = ApiClient::new(conn);
+    if let Some(token) = &config.bearer_token {
+        api_client = api_client.with_bearer_token(token)?;
+    }
+
+    let mut compactor = Compactor::new(api_client, config.owner_id.clone());
+    compactor.run(&config).await
+}
@@ -0,0 +1,3 @@
+pub mod compactor;
+pub mod config;
+pub mod parquet_merge;
@@ -0,0 +1,297 @@
+use crate::ApiClient;
+use crate::compaction::config::{CompactorConfig, TableCompactionConfig};
+use crate::compaction::parquet_merge::merge_parquet_files;
+use crate::create_rustfs;
+use crate::proto::{
+    CompactFileDstEntry, CompactFileEntry, CompactFileInfoEntry, CompactFileSrcEntry,
+    CompactionGroup, FileLockKey, PartitionTimeRange, PlanCompactionRequest,
+};
+use anyhow::bail;
+use object_store::ObjectStore;
+use object_store::path::Path;
+use prost_types::Timestamp;
+use std::time::{Duration, SystemTime};
+use uuid::Uuid;
+
+const LOCK_REASON: &str = "compaction";
+const PARQUET_EXTENSION: &str = ".parquet";
+
+#[derive(Debug, Clone)]
+pub struct StreamCompaction {
+    pub table_name: String,
+    pub stream_id: i64,
+    pub commit_id: String,
+    pub src_file_count: usize,
+    pub dst_paths: Vec<String>,
+}
+
+pub struct Compactor {
+    api_client: ApiClient,
+    owner_id: String,
+}
+
+impl Compactor {
+    pub fn new(api_client: ApiClient, owner_id: String) -> Self {
+        Self {
+            api_client,
+            owner_id,
+        }
+    }
+
+    // Compacts every configured table per interval. A failing table or stream does not stop the others.
+    pub async fn run(&mut self, config: &CompactorConfig) -> Result<(), anyhow::Error> {
+        loop {
+            for table in &config.tables {
+                match self.compact_table(table).await {
+                    Ok(compactions) => {
+                        for compaction in compactions {
+                            println!(
+                                "compacted {} files into {:?} (table={}, stream_id={}, commit_id={})",
+                                compaction.src_file_count,
+                                compaction.dst_paths,
+                                compaction.table_name,
+                                compaction.stream_id,
+                                compaction.commit_id
+                            );
+                        }
+                    }
+                    Err(err) => eprintln!("failed to compact '{}': {err:?}", table.table_name),
+                }
+            }
+
+            let Some(interval) = config.interval() else {
+                return Ok(());
+            };
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    pub async fn compact_table(
+        &mut self,
+        table: &TableCompactionConfig,
+    ) -> Result<Vec<StreamCompaction>, anyhow::Error> {
+        let store = create_rustfs(table.bucket_name.clone())?;
+        let stream_ids = if table.stream_ids.is_empty() {
+            self.list_stream_ids(&table.table_name).await?
+        } else {
+            table.stream_ids.clone()
+        };
+
+        let mut compactions = vec![];
+        for stream_id in stream_ids {
+            match self.compact_stream(&store, table, stream_id).await {
+                Ok(Some(compaction)) => compactions.push(compaction),
+                Ok(None) => {}
+                Err(err) => eprintln!(
+                    "failed to compact '{}' (stream_id={}): {err:?}",
+                    table.table_name, stream_id
+                ),
+            }
+        }
+
+        Ok(compactions)
+    }
+
+    // Returns None when there is nothing to compact, or every candidate is locked by another compactor.
+    pub async fn compact_stream(
+        &mut self,
+        store: &impl ObjectStore,
+        table: &TableCompactionConfig,
+        stream_id: i64,
+    ) -> Result<Option<StreamCompaction>, anyhow::Error> {
+        let lock_key = Uuid::now_v7();
+        let plan = self
+            .api_client
+            .plan_compaction(PlanCompactionRequest {
+                table_name: table.table_name.clone(),
+                stream_id,
+                target_file_size: table.target_file_size,
+                min_files: table.min_files,
+                partition_time_range: partition_time_range(table)?,
+                max_groups: table.max_groups,
+                file_lock_key: Some(FileLockKey {
+                    key: lock_key.into(),
+                }),
+                ttl_sec: table.lock_ttl_sec,
+                owner_id: self.owner_id.clone(),
+                reason: LOCK_REASON.to_string(),
+            })
+            .await?
+            .into_inner();
+        if !plan.locked {
+            return Ok(None);
+        }
+
+        let mut dst_paths = vec![];
+        let compact_file_entries =
+            match rewrite_groups(store, table, stream_id, &plan.groups, &mut dst_paths).await {
+                Ok(entries) => entries,
+                Err(err) => {
+                    self.release_lock(lock_key).await;
+                    delete_paths(store, &dst_paths).await;
+                    return Err(err);
+                }
+            };
+        if compact_file_entries.is_empty() {
+            self.release_lock(lock_key).await;
+            return Ok(None);
+        }
+        let src_file_count = compact_file_entries
+            .iter()
+            .flat_map(|entry| &entry.file_info_entries)
+            .map(|info| info.src_entries.len())
+            .sum();
+
+        let result = self
+            .api_client
+            .compact_files(
+                lock_key,
+                table.table_name.clone(),
+                stream_id,
+                compact_file_entries,
+                None,
+            )
+            .await;
+        // Files skipped by rewrite_groups are still locked by the key.
+        self.release_lock(lock_key).await;
+        let response = match result {
+            Ok(response) => response.into_inner(),
+            Err(err) => {
+                // The commit may have been applied even when the response is lost, so the written
+                // files are kept unless the server rejected the request.
+                if err.is_rejected() {
+                    delete_paths(store, &dst_paths).await;
+                }
+                return Err(err.into());
+            }
+        };
+
+        Ok(Some(StreamCompaction {
+            table_name: table.table_name.clone(),
+            stream_id,
+            commit_id: response.commit_id,
+            src_file_count,
+            dst_paths: dst_paths.iter().map(|path| path.to_string()).collect(),
+        }))
+    }
+
+    async fn list_stream_ids(&self, table_name: &str) -> Result<Vec<i64>, anyhow::Error> {
+        let mut stream_ids = vec![];
+        let mut pagination_token = None;
+        loop {
+            let response = self
+                .api_client
+                .list_streams(table_name.to_string(), pagination_token)
+                .await?
+                .into_inner();
+            stream_ids.extend(response.streams.iter().map(|stream| stream.stream_id));
+
+            match response.pagination {
+                Some(pagination) if !pagination.next_token.is_empty() => {
+                    pagination_token = Some(pagination.next_token)
+                }
+                _ => return Ok(stream_ids),
+            }
+        }
+    }
+
+    // The lock expires by itself, so a failed release is only reported.
+    async fn release_lock(&mut self, lock_key: Uuid) {
+        if let Err(err) = self.api_client.release_lock(lock_key).await {
+            eprintln!("failed to release lock {}: {err:?}", lock_key);
+        }
+    }
+}
+
+// Pushes every uploaded path to dst_paths, so that they can be deleted on failure.
+async fn rewrite_groups(
+    store: &impl ObjectStore,
+    table: &TableCompactionConfig,
+    stream_id: i64,
+    groups: &[CompactionGroup],
+    dst_paths: &mut Vec<Path>,
+) -> Result<Vec<CompactFileEntry>, anyhow::Error> {
+    let mut compact_file_entries = vec![];
+    for group in groups {
+        // Only Parquet files can be merged. Groups left with a single file are not worth rewriting.
+        let files: Vec<_> = group
+            .files
+            .iter()
+            .filter(|file| file.path.ends_with(PARQUET_EXTENSION))
+            .collect();
+        if files.len() < 2 {
+            continue;
+        }
+
+        let mut sources = vec![];
+        for file in &files {
+            let source = store.get(&Path::parse(&file.path)?).await?.bytes().await?;
+            sources.push(source);
+        }
+        let merged = merge_parquet_files(sources)?;
+
+        let dst_path = Path::parse(format!(
+            "{}/{}/{}/{}{}",
+            table.output_prefix,
+            table.table_name,
+            stream_id,
+            Uuid::now_v7(),
+            PARQUET_EXTENSION
+        ))?;
+        let size = merged.len() as i64;
+        store.put(&dst_path, merged.into()).await?;
+        dst_paths.push(dst_path.clone());
+
+        compact_file_entries.push(CompactFileEntry {
+            partition_time: group.partition_time,
+            file_info_entries: vec![CompactFileInfoEntry {
+                src_entries: files
+                    .iter()
+                    .map(|file| CompactFileSrcEntry {
+                        path: file.path.clone(),
+                        partition_time: None,
+                    })
+                    .collect(),
+                dst_entry: Some(CompactFileDstEntry {
+                    path: dst_path.to_string(),
+                    size,
+                    // Stored values such as "<column>_bucket" are accepted as they are.
+                    partition_values: group.partition_values.clone(),
+                    ..Default::default()
+                }),
+                dst_entries: vec![],
+            }],
+        });
+    }
+
+    Ok(compact_file_entries)
+}
+
+async fn delete_paths(store: &impl ObjectStore, paths: &[Path]) {
+    for path in paths {
+        if let Err(err) = store.delete(path).await {
+            eprintln!("failed to delete {}: {err:?}", path);
+        }
+    }
+}
+
+fn partition_time_range(
+    table: &TableCompactionConfig,
+) -> Result<Option<PartitionTimeRange>, anyhow::Error> {
+    let Some(min_partition_age_sec) = table.min_partition_age_sec else {
+        return Ok(None);
+    };
+
+    let Some(max) = SystemTime::now().checked_sub(Duration::from_secs(min_partition_age_sec))
+    else {
+        bail!(
+            "min_partition_age_sec of '{}' is out of range: {}",
+            table.table_name,
+            min_partition_age_sec
+        );
+    };
+    Ok(Some(PartitionTimeRange {
+        min: None,
+        max: Some(Timestamp::from(max)),
+    }))
+}
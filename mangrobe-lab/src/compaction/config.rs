@@ -0,0 +1,121 @@
+use anyhow::bail;
+use serde::Deserialize;
+use std::path::Path;
+use std::time::Duration;
+
+const DEFAULT_MANGROBE_API_ADDR: &str = "http://[::1]:50051";
+const DEFAULT_OWNER_ID: &str = "mangrobe-compactor";
+const DEFAULT_INTERVAL_SEC: u64 = 60;
+const DEFAULT_BUCKET_NAME: &str = "mangrobe-development";
+const DEFAULT_OUTPUT_PREFIX: &str = "compacted";
+const DEFAULT_TARGET_FILE_SIZE: i64 = 128 * 1024 * 1024;
+const DEFAULT_MIN_FILES: i64 = 2;
+const DEFAULT_MAX_GROUPS: i64 = 10;
+const DEFAULT_LOCK_TTL_SEC: i64 = 600;
+// About 100 years, the same bound as the duration properties of the API server.
+const MAX_PARTITION_AGE_SEC: u64 = 100 * 366 * 24 * 60 * 60;
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct CompactorConfig {
+    pub api_addr: String,
+    pub bearer_token: Option<String>,
+    // Shown as the owner of the file locks.
+    pub owner_id: String,
+    // Runs once and exits when 0.
+    pub interval_sec: u64,
+    pub tables: Vec<TableCompactionConfig>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct TableCompactionConfig {
+    pub table_name: String,
+    // Every stream of the table when empty.
+    pub stream_ids: Vec<i64>,
+    pub bucket_name: String,
+    // Compacted files are written under <output_prefix>/<table_name>/<stream_id>/.
+    pub output_prefix: String,
+    pub target_file_size: i64,
+    pub min_files: i64,
+    // Maximum number of groups compacted by a single commit.
+    pub max_groups: i64,
+    // Must be long enough to download, merge and upload max_groups groups.
+    pub lock_ttl_sec: i64,
+    // Leaves recent partitions alone, since writers may still be adding files to them.
+    pub min_partition_age_sec: Option<u64>,
+}
+
+impl Default for CompactorConfig {
+    fn default() -> Self {
+        Self {
+            api_addr: DEFAULT_MANGROBE_API_ADDR.to_string(),
+            bearer_token: None,
+            owner_id: DEFAULT_OWNER_ID.to_string(),
+            interval_sec: DEFAULT_INTERVAL_SEC,
+            tables: vec![],
+        }
+    }
+}
+
+impl Default for TableCompactionConfig {
+    fn default() -> Self {
+        Self {
+            table_name: String::new(),
+            stream_ids: vec![],
+            bucket_name: DEFAULT_BUCKET_NAME.to_string(),
+            output_prefix: DEFAULT_OUTPUT_PREFIX.to_string(),
+            target_file_size: DEFAULT_TARGET_FILE_SIZE,
+            min_files: DEFAULT_MIN_FILES,
+            max_groups: DEFAULT_MAX_GROUPS,
+            lock_ttl_sec: DEFAULT_LOCK_TTL_SEC,
+            min_partition_age_sec: None,
+        }
+    }
+}
+
+impl CompactorConfig {
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        let content = std::fs::read_to_string(path)?;
+        let config: Self = toml::from_str(&content)?;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    pub fn interval(&self) -> Option<Duration> {
+        (self.interval_sec > 0).then(|| Duration::from_secs(self.interval_sec))
+    }
+
+    fn validate(&self) -> Result<(), anyhow::Error> {
+        if self.tables.is_empty() {
+            bail!("no tables to compact");
+        }
+        for table in &self.tables {
+            if table.table_name.is_empty() {
+                bail!("table_name is required");
+            }
+            if table.target_file_size <= 0 {
+                bail!(
+                    "target_file_size of '{}' must be positive",
+                    table.table_name
+                );
+            }
+            if table.lock_ttl_sec <= 0 {
+                bail!("lock_ttl_sec of '{}' must be positive", table.table_name);
+            }
+            if table
+                .min_partition_age_sec
+                .is_some_and(|age| age > MAX_PARTITION_AGE_SEC)
+            {
+                bail!(
+                    "min_partition_age_sec of '{}' must be up to {}",
+                    table.table_name,
+                    MAX_PARTITION_AGE_SEC
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
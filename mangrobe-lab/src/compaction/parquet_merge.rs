@@ -0,0 +1,119 @@
+use anyhow::bail;
+use arrow_array::RecordBatchReader;
+use arrow_schema::SchemaRef;
+use bytes::Bytes;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::file::properties::WriterProperties;
+
+// Concatenates the rows of Parquet files into a single Parquet file, in the order of the sources.
+pub fn merge_parquet_files(sources: Vec<Bytes>) -> Result<Vec<u8>, anyhow::Error> {
+    let mut writer: Option<(SchemaRef, ArrowWriter<Vec<u8>>)> = None;
+
+    for source in sources {
+        let reader = ParquetRecordBatchReaderBuilder::try_new(source)?.build()?;
+        let schema = reader.schema();
+
+        let (first_schema, writer) = match &mut writer {
+            Some(writer) => writer,
+            None => writer.insert((
+                schema.clone(),
+                ArrowWriter::try_new(
+                    Vec::new(),
+                    schema.clone(),
+                    Some(WriterProperties::builder().build()),
+                )?,
+            )),
+        };
+        // Metadata may differ between writers, so only the columns have to match.
+        if first_schema.fields() != schema.fields() {
+            bail!("schema mismatch: {:?} vs {:?}", first_schema, schema);
+        }
+
+        for batch in reader {
+            writer.write(&batch?)?;
+        }
+    }
+
+    let Some((_, writer)) = writer else {
+        bail!("no parquet files to merge");
+    };
+    Ok(writer.into_inner()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Array, Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn write(schema: Schema, columns: Vec<Arc<dyn Array>>) -> Bytes {
+        let batch = RecordBatch::try_new(Arc::new(schema), columns).unwrap();
+        let mut writer = ArrowWriter::try_new(Vec::new(), batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        Bytes::from(writer.into_inner().unwrap())
+    }
+
+    fn ints(values: Vec<i64>) -> Bytes {
+        let schema = Schema::new(vec![Field::new("v", DataType::Int64, false)]);
+        write(schema, vec![Arc::new(Int64Array::from(values))])
+    }
+
+    fn read_ints(merged: Vec<u8>) -> Vec<i64> {
+        let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(merged))
+            .unwrap()
+            .build()
+            .unwrap();
+        reader
+            .flat_map(|batch| {
+                let batch = batch.unwrap();
+                let column = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int64Array>()
+                    .unwrap();
+                column.values().to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn concatenates_rows_in_order() {
+        let merged = merge_parquet_files(vec![ints(vec![1, 2]), ints(vec![3]), ints(vec![])]);
+
+        assert_eq!(read_ints(merged.unwrap()), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn rejects_schema_mismatch() {
+        let strings = write(
+            Schema::new(vec![Field::new("v", DataType::Utf8, false)]),
+            vec![Arc::new(StringArray::from(vec!["a"]))],
+        );
+        let renamed = write(
+            Schema::new(vec![Field::new("w", DataType::Int64, false)]),
+            vec![Arc::new(Int64Array::from(vec![1]))],
+        );
+
+        assert!(merge_parquet_files(vec![ints(vec![1]), strings]).is_err());
+        assert!(merge_parquet_files(vec![ints(vec![1]), renamed]).is_err());
+    }
+
+    #[test]
+    fn ignores_schema_metadata() {
+        let schema = Schema::new(vec![Field::new("v", DataType::Int64, false)])
+            .with_metadata(HashMap::from([("writer".to_string(), "other".to_string())]));
+        let with_metadata = write(schema, vec![Arc::new(Int64Array::from(vec![2]))]);
+
+        let merged = merge_parquet_files(vec![ints(vec![1]), with_metadata]);
+        assert_eq!(read_ints(merged.unwrap()), vec![1, 2]);
+    }
+
+    #[test]
+    fn rejects_empty_and_invalid_sources() {
+        assert!(merge_parquet_files(vec![]).is_err());
+        assert!(merge_parquet_files(vec![Bytes::from_static(b"not parquet")]).is_err());
+    }
+}
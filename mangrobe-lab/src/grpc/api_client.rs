@@ -1,23 +1,36 @@
+use crate::grpc::api_error::ApiError;
 use crate::grpc::proto::data_manipulation_service_client::DataManipulationServiceClient;
+use crate::grpc::proto::information_schema_service_client::InformationSchemaServiceClient;
 use crate::grpc::proto::lock_control_service_client::LockControlServiceClient;
 use crate::grpc::proto::{
     AcquireFileLockEntry, AcquireFileLockRequest, AcquireFileLockResponse, AddFileEntry,
     AddFilesRequest, AddFilesResponse, ChangeFileEntry, ChangeFilesRequest, ChangeFilesResponse,
     CompactFileEntry, CompactFilesRequest, CompactFilesResponse, FileLockKey,
-    GetCurrentStateRequest, GetCurrentStateResponse, IdempotencyKey, ReleaseFileLockRequest,
-    ReleaseFileLockResponse,
+    GetCurrentStateRequest, GetCurrentStateResponse, IdempotencyKey, ListStreamsRequest,
+    ListStreamsResponse, PaginationRequest, PlanCompactionRequest, PlanCompactionResponse,
+    ReleaseFileLockRequest, ReleaseFileLockResponse,
 };
 use crate::proto::data_definition_service_client::DataDefinitionServiceClient;
 use crate::proto::{CreateTableRequest, CreateTableResponse};
-use tonic::Response;
+use opentelemetry::propagation::Injector;
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry::{Context, global};
+use tonic::metadata::errors::InvalidMetadataValue;
+use tonic::metadata::{Ascii, MetadataKey, MetadataMap, MetadataValue};
 use tonic::transport::Channel;
+use tonic::{Request, Response};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 use uuid::Uuid;
 
+const AUTHORIZATION_HEADER: &str = "authorization";
+
 #[derive(Debug, Clone)]
 pub struct ApiClient {
     data_manipulation_service_client: DataManipulationServiceClient<Channel>,
     data_definition_service_client: DataDefinitionServiceClient<Channel>,
     lock_control_service_client: LockControlServiceClient<Channel>,
+    information_schema_service_client: InformationSchemaServiceClient<Channel>,
+    authorization: Option<MetadataValue<Ascii>>,
 }
 
 impl ApiClient {
@@ -25,44 +38,75 @@ impl ApiClient {
         let data_manipulation_service_client = DataManipulationServiceClient::new(channel.clone());
         let data_definition_service_client = DataDefinitionServiceClient::new(channel.clone());
         let lock_control_service_client = LockControlServiceClient::new(channel.clone());
+        let information_schema_service_client =
+            InformationSchemaServiceClient::new(channel.clone());
 
         Self {
             data_manipulation_service_client,
             data_definition_service_client,
             lock_control_service_client,
+            information_schema_service_client,
+            authorization: None,
+        }
+    }
+
+    /// Sends `authorization: Bearer <token>` with every request.
+    pub fn with_bearer_token(mut self, token: &str) -> Result<Self, InvalidMetadataValue> {
+        self.authorization = Some(format!("Bearer {}", token).parse()?);
+        Ok(self)
+    }
+
+    fn build_request<T>(&self, message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        if let Some(authorization) = &self.authorization {
+            request
+                .metadata_mut()
+                .insert(AUTHORIZATION_HEADER, authorization.clone());
         }
+        // No-op unless the application installs a propagator, e.g. TraceContextPropagator.
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(
+                &current_trace_context(),
+                &mut MetadataInjector(request.metadata_mut()),
+            )
+        });
+        request
     }
 
     pub async fn create_table(
         &self,
         table_name: String,
         skip_if_exists: bool,
-    ) -> Result<Response<CreateTableResponse>, tonic::Status> {
-        let request = tonic::Request::new(CreateTableRequest {
+    ) -> Result<Response<CreateTableResponse>, ApiError> {
+        let request = self.build_request(CreateTableRequest {
             table_name,
             skip_if_exists,
+            properties: Default::default(),
         });
 
         self.data_definition_service_client
             .clone()
             .create_table(request)
             .await
+            .map_err(ApiError::from)
     }
 
     pub async fn fetch_current_state(
         &self,
         table_name: String,
         stream_id: i64,
-    ) -> Result<Response<GetCurrentStateResponse>, tonic::Status> {
-        let request = tonic::Request::new(GetCurrentStateRequest {
+    ) -> Result<Response<GetCurrentStateResponse>, ApiError> {
+        let request = self.build_request(GetCurrentStateRequest {
             table_name,
             stream_id,
+            partition_filter: Default::default(),
         });
 
         self.data_manipulation_service_client
             .clone()
             .get_current_state(request)
             .await
+            .map_err(ApiError::from)
     }
 
     pub async fn add_files(
@@ -70,20 +114,23 @@ impl ApiClient {
         table_name: String,
         stream_id: i64,
         add_file_entries: Vec<AddFileEntry>,
-    ) -> Result<Response<AddFilesResponse>, tonic::Status> {
-        let request = tonic::Request::new(AddFilesRequest {
+        expected_commit_id: Option<String>,
+    ) -> Result<Response<AddFilesResponse>, ApiError> {
+        let request = self.build_request(AddFilesRequest {
             idempotency_key: Some(IdempotencyKey {
                 key: Uuid::now_v7().into(),
             }),
             table_name,
             stream_id,
             add_file_entries,
+            expected_commit_id,
         });
 
         self.data_manipulation_service_client
             .clone()
             .add_files(request)
             .await
+            .map_err(ApiError::from)
     }
 
     pub async fn change_files(
@@ -92,19 +139,22 @@ impl ApiClient {
         table_name: String,
         stream_id: i64,
         change_file_entries: Vec<ChangeFileEntry>,
-    ) -> Result<Response<ChangeFilesResponse>, tonic::Status> {
-        let request = tonic::Request::new(ChangeFilesRequest {
+        expected_commit_id: Option<String>,
+    ) -> Result<Response<ChangeFilesResponse>, ApiError> {
+        let request = self.build_request(ChangeFilesRequest {
             file_lock_key: Some(FileLockKey {
                 key: txn_key.into(),
             }),
             table_name,
             stream_id,
             change_file_entries,
+            expected_commit_id,
         });
 
         self.data_manipulation_service_client
             .change_files(request)
             .await
+            .map_err(ApiError::from)
     }
 
     pub async fn compact_files(
@@ -113,19 +163,22 @@ impl ApiClient {
         table_name: String,
         stream_id: i64,
         compact_file_entries: Vec<CompactFileEntry>,
-    ) -> Result<Response<CompactFilesResponse>, tonic::Status> {
-        let request = tonic::Request::new(CompactFilesRequest {
+        expected_commit_id: Option<String>,
+    ) -> Result<Response<CompactFilesResponse>, ApiError> {
+        let request = self.build_request(CompactFilesRequest {
             file_lock_key: Some(FileLockKey {
                 key: txn_key.into(),
             }),
             table_name,
             stream_id,
             compact_file_entries,
+            expected_commit_id,
         });
 
         self.data_manipulation_service_client
             .compact_files(request)
             .await
+            .map_err(ApiError::from)
     }
 
     pub async fn acquire_lock(
@@ -134,8 +187,10 @@ impl ApiClient {
         table_name: String,
         stream_id: i64,
         acquire_file_lock_entries: Vec<AcquireFileLockEntry>,
-    ) -> Result<Response<AcquireFileLockResponse>, tonic::Status> {
-        let request = tonic::Request::new(AcquireFileLockRequest {
+        owner_id: String,
+        reason: String,
+    ) -> Result<Response<AcquireFileLockResponse>, ApiError> {
+        let request = self.build_request(AcquireFileLockRequest {
             file_lock_key: Some(FileLockKey {
                 key: txn_key.into(),
             }),
@@ -143,18 +198,35 @@ impl ApiClient {
             table_name,
             stream_id,
             acquire_file_lock_entries,
+            owner_id,
+            reason,
+            partition_filter: Default::default(),
         });
 
         self.lock_control_service_client
             .acquire_file_lock(request)
             .await
+            .map_err(ApiError::from)
+    }
+
+    // Locks the files of the returned groups when the request has file_lock_key.
+    pub async fn plan_compaction(
+        &mut self,
+        request: PlanCompactionRequest,
+    ) -> Result<Response<PlanCompactionResponse>, ApiError> {
+        let request = self.build_request(request);
+
+        self.lock_control_service_client
+            .plan_compaction(request)
+            .await
+            .map_err(ApiError::from)
     }
 
     pub async fn release_lock(
         &mut self,
         txn_key: Uuid,
-    ) -> Result<Response<ReleaseFileLockResponse>, tonic::Status> {
-        let request = tonic::Request::new(ReleaseFileLockRequest {
+    ) -> Result<Response<ReleaseFileLockResponse>, ApiError> {
+        let request = self.build_request(ReleaseFileLockRequest {
             file_lock_key: Some(FileLockKey {
                 key: txn_key.into(),
             }),
@@ -163,5 +235,50 @@ impl ApiClient {
         self.lock_control_service_client
             .release_file_lock(request)
             .await
+            .map_err(ApiError::from)
+    }
+
+    pub async fn list_streams(
+        &self,
+        table_name: String,
+        pagination_token: Option<String>,
+    ) -> Result<Response<ListStreamsResponse>, ApiError> {
+        let request = self.build_request(ListStreamsRequest {
+            pagination: Some(PaginationRequest {
+                size: 0,
+                token: pagination_token,
+            }),
+            table_name,
+            ..Default::default()
+        });
+
+        self.information_schema_service_client
+            .clone()
+            .list_streams(request)
+            .await
+            .map_err(ApiError::from)
+    }
+}
+
+// Prefers the span of `tracing` when tracing-opentelemetry is installed.
+fn current_trace_context() -> Context {
+    let context = tracing::Span::current().context();
+    if context.span().span_context().is_valid() {
+        context
+    } else {
+        Context::current()
+    }
+}
+
+struct MetadataInjector<'a>(&'a mut MetadataMap);
+
+impl Injector for MetadataInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(key), Ok(value)) = (
+            MetadataKey::from_bytes(key.as_bytes()),
+            MetadataValue::try_from(value),
+        ) {
+            self.0.insert(key, value);
+        }
     }
 }
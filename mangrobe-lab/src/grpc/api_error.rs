@@ -0,0 +1,125 @@
+use thiserror::Error;
+use tonic::{Code, Status};
+use tonic_types::StatusExt;
+
+const ERROR_DOMAIN: &str = "mangrobe";
+const COMMIT_HEAD_MOVED_REASON: &str = "COMMIT_HEAD_MOVED";
+const QUOTA_COMMIT_RATE_EXCEEDED_REASON: &str = "QUOTA_COMMIT_RATE_EXCEEDED";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockErrorReason {
+    HeldByOther,
+    DuplicatedKey,
+    NotAcquired,
+    FileNotLocked,
+    FileNotFound,
+}
+
+impl LockErrorReason {
+    fn parse(reason: &str) -> Option<Self> {
+        match reason {
+            "LOCK_HELD_BY_OTHER" => Some(Self::HeldByOther),
+            "LOCK_KEY_DUPLICATED" => Some(Self::DuplicatedKey),
+            "LOCK_NOT_ACQUIRED" => Some(Self::NotAcquired),
+            "FILE_NOT_LOCKED" => Some(Self::FileNotLocked),
+            "FILE_NOT_FOUND" => Some(Self::FileNotFound),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ApiError {
+    // Files are locked by someone else. Retrying later may succeed.
+    #[error("lock conflicted ({reason:?}): {message}")]
+    LockConflicted {
+        reason: LockErrorReason,
+        message: String,
+    },
+
+    // The lock is not usable anymore, or its key is already used. Acquire a new lock with a new key before retrying.
+    #[error("lock unavailable ({reason:?}): {message}")]
+    LockUnavailable {
+        reason: LockErrorReason,
+        message: String,
+    },
+
+    // The stream has moved from the expected commit. Re-read the state before retrying.
+    #[error("commit conflicted: {message}")]
+    CommitConflicted { message: String },
+
+    // A quota of the table or stream is exhausted. Only the commit rate recovers by itself.
+    #[error("quota exceeded ({reason}): {message}")]
+    QuotaExceeded { reason: String, message: String },
+
+    // Some files do not exist in their partitions anymore. Re-read the state before retrying.
+    #[error("file not found: {message}")]
+    FileNotFound { message: String },
+
+    #[error(transparent)]
+    Status(Status),
+}
+
+impl ApiError {
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ApiError::LockConflicted { .. } => true,
+            ApiError::QuotaExceeded { reason, .. } => reason == QUOTA_COMMIT_RATE_EXCEEDED_REASON,
+            _ => false,
+        }
+    }
+
+    // The server refused the request, so it has certainly not been applied. Transport errors and
+    // timeouts are not, as the request may have been applied before the response was lost.
+    pub fn is_rejected(&self) -> bool {
+        match self {
+            ApiError::Status(status) => matches!(
+                status.code(),
+                Code::InvalidArgument
+                    | Code::FailedPrecondition
+                    | Code::NotFound
+                    | Code::PermissionDenied
+                    | Code::Unauthenticated
+            ),
+            _ => true,
+        }
+    }
+}
+
+impl From<Status> for ApiError {
+    fn from(status: Status) -> Self {
+        let Some(error_info) = status
+            .get_details_error_info()
+            .filter(|info| info.domain == ERROR_DOMAIN)
+        else {
+            return ApiError::Status(status);
+        };
+
+        if error_info.reason == COMMIT_HEAD_MOVED_REASON {
+            return ApiError::CommitConflicted {
+                message: status.message().to_string(),
+            };
+        }
+
+        if status.code() == Code::ResourceExhausted {
+            return ApiError::QuotaExceeded {
+                reason: error_info.reason,
+                message: status.message().to_string(),
+            };
+        }
+
+        let Some(reason) = LockErrorReason::parse(&error_info.reason) else {
+            return ApiError::Status(status);
+        };
+
+        let message = status.message().to_string();
+        match status.code() {
+            Code::Aborted => ApiError::LockConflicted { reason, message },
+            Code::FailedPrecondition | Code::AlreadyExists => {
+                ApiError::LockUnavailable { reason, message }
+            }
+            Code::NotFound => ApiError::FileNotFound { message },
+            _ => ApiError::Status(status),
+        }
+    }
+}
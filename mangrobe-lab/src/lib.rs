@@ -1,9 +1,14 @@
+mod compaction;
 mod grpc;
 mod infrastructure;
 mod prometheus;
 mod stream;
 
+pub use compaction::compactor::{Compactor, StreamCompaction};
+pub use compaction::config::{CompactorConfig, TableCompactionConfig};
+pub use compaction::parquet_merge::merge_parquet_files;
 pub use grpc::api_client::ApiClient;
+pub use grpc::api_error::{ApiError, LockErrorReason};
 pub use grpc::proto;
 pub use infrastructure::s3::store::create_bucket_if_not_exists;
 pub use infrastructure::s3::store::create_rustfs;